@@ -1,6 +1,7 @@
 use anchor_client::solana_sdk::pubkey::Pubkey;
 use anchor_client::Cluster;
 use clap::*;
+use farming::EmissionStep;
 
 #[derive(Parser, Debug)]
 pub struct ConfigOverride {
@@ -61,6 +62,31 @@ pub struct PoolArgs {
     pub reward_b_mint: Pubkey,
 }
 
+#[derive(ArgEnum, Clone, Copy, Debug)]
+pub enum FundModeArg {
+    Reset,
+    TopUp,
+    Extend,
+}
+
+#[derive(ArgEnum, Clone, Copy, Debug)]
+pub enum EmissionCurveArg {
+    Flat,
+    LinearDecay,
+    Stepwise,
+}
+
+/// Parses an emission step given as `start_offset:weight`
+fn parse_emission_step(step: &str) -> Result<EmissionStep, String> {
+    let (start_offset, weight) = step
+        .split_once(':')
+        .ok_or_else(|| format!("expected start_offset:weight, got {}", step))?;
+    Ok(EmissionStep {
+        start_offset: start_offset.parse().map_err(|e| format!("{}", e))?,
+        weight: weight.parse().map_err(|e| format!("{}", e))?,
+    })
+}
+
 #[derive(Parser, Debug)]
 pub enum CliCommand {
     /// Initialize pool
@@ -73,6 +99,26 @@ pub enum CliCommand {
         reward_b_mint: Pubkey,
         reward_duration: u64,
     },
+    /// Program upgrade authority initializes the global config
+    InitGlobalConfig {
+        /// Protocol fee on claimed rewards in basis points
+        #[clap(long)]
+        protocol_fee_bps: u16,
+        /// Owner of the token accounts receiving the protocol fee
+        #[clap(long)]
+        treasury: Pubkey,
+    },
+    /// Protocol admin updates the global config
+    UpdateGlobalConfig {
+        #[clap(long)]
+        admin: Pubkey,
+        #[clap(long)]
+        protocol_fee_bps: u16,
+        #[clap(long)]
+        treasury: Pubkey,
+    },
+    /// Show global config
+    ShowGlobalConfig {},
     /// User enables staking
     CreateUser {
         #[clap(long)]
@@ -102,6 +148,40 @@ pub enum CliCommand {
         #[clap(long)]
         spt_amount: u64,
     },
+    /// User requests unstake, starting the unbonding period
+    RequestWithdraw {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        spt_amount: u64,
+    },
+    /// User unstakes tokens whose unbonding period is over
+    CompleteWithdraw {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Admin sets the reward duration used by the next funding
+    SetRewardDuration {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        reward_duration: u64,
+    },
+    /// Admin sets the unbonding period of the pool, at most 30 days
+    SetUnbondingPeriod {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        unbonding_period: u64,
+    },
+    /// Admin sets the time stake must stay in the pool before rewards can be claimed, at most 30 days
+    SetMinStakeDuration {
+        #[clap(long)]
+        pool: Pubkey,
+        /// Seconds. Zero disables it
+        #[clap(long)]
+        min_stake_duration: u64,
+    },
     /// Admin adds a wallet as funder
     Authorize {
         #[clap(long)]
@@ -120,21 +200,202 @@ pub enum CliCommand {
     Fund {
         #[clap(long)]
         pool: Pubkey,
-        #[clap(long)]
+        #[clap(long, default_value_t = 0)]
         amount_a: u64,
-        #[clap(long)]
+        #[clap(long, default_value_t = 0)]
         amount_b: u64,
+        /// reset: restart a full reward duration from now.
+        /// top-up: keep the end time and raise the rate.
+        /// extend: push the end time by --extend-seconds at the current rate
+        #[clap(long, arg_enum, default_value_t = FundModeArg::Reset)]
+        mode: FundModeArg,
+        /// Seconds added to the reward period in extend mode
+        #[clap(long, default_value_t = 0)]
+        extend_seconds: u64,
+    },
+    /// Admin ends the running campaign and gets back unemitted rewards
+    EndCampaign {
+        #[clap(long)]
+        pool: Pubkey,
     },
-    /// User claims pending rewards
+    /// Admin sets the shape of the emission of the next fundings, between campaigns
+    SetEmissionSchedule {
+        #[clap(long)]
+        pool: Pubkey,
+        /// flat: constant rate.
+        /// linear-decay: rate moving from --start-weight to --end-weight.
+        /// stepwise: rate of each --step until the next one
+        #[clap(long, arg_enum)]
+        curve: EmissionCurveArg,
+        /// Relative rate at the start of the reward period in linear-decay
+        #[clap(long, default_value_t = 0)]
+        start_weight: u16,
+        /// Relative rate at the end of the reward period in linear-decay
+        #[clap(long, default_value_t = 0)]
+        end_weight: u16,
+        /// Step as start_offset:weight, the offset in seconds from the start of the reward period.
+        /// Repeat for each step, the first one at offset 0
+        #[clap(long = "step", parse(try_from_str = parse_emission_step))]
+        steps: Vec<EmissionStep>,
+    },
+    /// User or claim delegate claims pending rewards
     Claim {
         #[clap(long)]
         pool: Pubkey,
+        /// Owner of the user stake account. Default to the wallet
+        #[clap(long)]
+        owner: Option<Pubkey>,
+    },
+    /// User claims pending rewards of all their stake accounts
+    ClaimAll {},
+    /// User transfers the whole staked balance to another wallet
+    TransferPosition {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        recipient: Pubkey,
+    },
+    /// User sets the keeper allowed to compound on their behalf
+    SetCompoundKeeper {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        keeper: Pubkey,
+    },
+    /// Admin sets the share of claimed rewards accrued to referrers, up to 1000 bps
+    SetReferralFee {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        referral_fee_bps: u16,
+    },
+    /// Referrer creates its referral rewards account in the pool
+    CreateReferrer {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// User records the referrer it was referred by, once
+    SetReferrer {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        referrer: Pubkey,
+    },
+    /// Anyone restores the referrer of a recreated user account
+    RestoreReferrer {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        owner: Pubkey,
+    },
+    /// Referrer claims its accrued referral rewards
+    ClaimReferral {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// User sets the delegate allowed to claim on their behalf and where rewards are paid
+    SetClaimDelegate {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        delegate: Pubkey,
+        /// Token account reward A must be paid to. Unset by default
+        #[clap(long)]
+        reward_a_destination: Option<Pubkey>,
+        /// Token account reward B must be paid to. Unset by default
+        #[clap(long)]
+        reward_b_destination: Option<Pubkey>,
+    },
+    /// User or keeper restakes rewards having the staking mint
+    Compound {
+        #[clap(long)]
+        pool: Pubkey,
+        /// Owner of the user stake account. Default to the wallet
+        #[clap(long)]
+        owner: Option<Pubkey>,
+    },
+    /// Admin pays rewards with the native mint in native SOL instead of wSOL. Only before the first funding
+    EnableNativeReward {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        reward_a: bool,
+        #[clap(long)]
+        reward_b: bool,
+    },
+    /// Admin sets the boost given to holders of a mint or collection. A zero multiplier disables it
+    SetBoostConfig {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        boost_mint: Option<Pubkey>,
+        #[clap(long)]
+        boost_collection: Option<Pubkey>,
+        /// Multiplier of the stake in basis points, 15000 for 1.5x
+        #[clap(long)]
+        multiplier_bps: u16,
+    },
+    /// User proves holding the boost token to get the pool boost
+    RefreshBoost {
+        #[clap(long)]
+        pool: Pubkey,
+        /// Mint of the held boost token
+        #[clap(long)]
+        mint: Pubkey,
+    },
+    /// Remove the boost of a user who no longer holds the boost token
+    StripBoost {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        owner: Pubkey,
+    },
+    /// Realm authority binds a realm and governing token mint to the pool used as voting power
+    CreateRegistrar {
+        #[clap(long)]
+        realm: Pubkey,
+        #[clap(long)]
+        governance_program_id: Pubkey,
+        #[clap(long)]
+        governing_token_mint: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+        /// Seconds the stake of a voter stays locked after its voter weight is updated
+        #[clap(long)]
+        vote_lock_duration: u64,
+    },
+    /// User creates its voter weight record
+    CreateVoterWeightRecord {
+        #[clap(long)]
+        registrar: Pubkey,
+    },
+    /// User updates its voter weight record from its stake, locking the stake
+    UpdateVoterWeightRecord {
+        #[clap(long)]
+        registrar: Pubkey,
     },
-    /// Admin closes a user stake account
+    /// Admin or funder funds the pool with the rewards sent directly to the reward vaults
+    SyncRewards {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Admin withdraws tokens above what the pool owes from the staking and reward vaults
+    WithdrawExtra {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// User closes its stake account
     CloseUser {
         #[clap(long)]
         pool: Pubkey,
     },
+    /// Close an abandoned empty user account after the campaign, refunding the rent to its owner
+    CloseDormantUser {
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        owner: Pubkey,
+    },
     /// Admin closes the pool
     ClosePool {
         #[clap(long)]
@@ -151,6 +412,103 @@ pub enum CliCommand {
         pool: Pubkey,
     },
 
+    /// Add a pool created before the registry existed to the registry
+    RegisterPool {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Show all pools of a staking mint
+    ShowRegistry {
+        #[clap(long)]
+        staking_mint: Pubkey,
+    },
+    /// Remove a pool from the registry of a staking mint, by the protocol admin
+    PrunePoolRegistry {
+        #[clap(long)]
+        staking_mint: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Initialize a stake hub holding the stake of the pools attached to it
+    InitStakeHub {
+        #[clap(long)]
+        staking_mint: Pubkey,
+    },
+    /// Admin attaches a pool without stake to the stake hub. The wallet must be the authority of both
+    AttachPool {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Admin detaches a closed pool, or a pool whose campaign ended and that no user is joined to anymore
+    DetachPool {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Admin removes a user from a hub pool whose campaign ended, so that the pool can be detached
+    RemoveHubPoolMember {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+        #[clap(long)]
+        owner: Pubkey,
+    },
+    /// User enables staking in the stake hub
+    CreateHubUser {
+        #[clap(long)]
+        stake_hub: Pubkey,
+    },
+    /// User earns the rewards of a pool attached to the stake hub
+    JoinHubPool {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// User stops earning the rewards of a pool attached to the stake hub
+    LeaveHubPool {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// User stakes in the stake hub
+    HubDeposit {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        amount: u64,
+    },
+    /// User unstakes from the stake hub
+    HubWithdraw {
+        #[clap(long)]
+        stake_hub: Pubkey,
+        #[clap(long)]
+        amount: u64,
+    },
+    /// Show the stake hub and the stake of the wallet in it
+    ShowStakeHub {
+        #[clap(long)]
+        stake_hub: Pubkey,
+    },
+    /// Grow a pool account created by a previous program version
+    MigratePool {
+        #[clap(long)]
+        pool: Pubkey,
+    },
+    /// Grow a user stake account created by a previous program version
+    MigrateUser {
+        #[clap(long)]
+        pool: Pubkey,
+        /// Owner of the user stake account. Default to the wallet
+        #[clap(long)]
+        owner: Option<Pubkey>,
+    },
+
     CheckFunderAllPool {},
     MigrateFarmingRate {},
 }
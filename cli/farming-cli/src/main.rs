@@ -5,9 +5,12 @@ use crate::args::*;
 use crate::utils::*;
 use anchor_client::anchor_lang::InstructionData;
 use anchor_client::anchor_lang::ToAccountMetas;
+use anchor_client::solana_client::rpc_filter::{Memcmp, RpcFilterType};
 use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
 use anchor_client::solana_sdk::compute_budget::ComputeBudgetInstruction;
+use anchor_client::solana_sdk::program_pack::Pack;
 use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::Signature;
 use anchor_client::solana_sdk::signer::keypair::*;
 use anchor_client::solana_sdk::signer::Signer;
 use anchor_client::{Client, Program};
@@ -15,11 +18,30 @@ use anchor_spl::token::spl_token;
 use anyhow::Ok;
 use anyhow::Result;
 use clap::*;
+use farming::pool::funding_emission;
+use farming::EmissionSchedule;
+use farming::FundMode;
+use farming::HubUser;
 use farming::Pool;
+use farming::PoolRegistry;
+use farming::Registrar;
+use farming::StakeHub;
+use farming::User;
 use solana_program::instruction::Instruction;
+use spl_associated_token_account::get_associated_token_address;
 use std::ops::Deref;
 use std::rc::Rc;
 use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Offset of the owner in the user account, after the discriminator and the pool
+const USER_OWNER_OFFSET: usize = 8 + 32;
+
+/// Pools claimed per claim all transaction, bounded by the transaction size
+const CLAIM_ALL_CHUNK_SIZE: usize = 3;
+
+/// Compute units requested per claimed pool
+const CLAIM_COMPUTE_UNITS: u32 = 100_000;
 
 fn main() -> Result<()> {
     let opts = Opts::parse();
@@ -58,6 +80,29 @@ fn main() -> Result<()> {
                 reward_duration,
             )?;
         }
+        CliCommand::InitGlobalConfig {
+            protocol_fee_bps,
+            treasury,
+        } => {
+            initialize_global_config(&program, priority_fee, &payer, protocol_fee_bps, &treasury)?;
+        }
+        CliCommand::UpdateGlobalConfig {
+            admin,
+            protocol_fee_bps,
+            treasury,
+        } => {
+            update_global_config(
+                &program,
+                priority_fee,
+                &payer,
+                &admin,
+                protocol_fee_bps,
+                &treasury,
+            )?;
+        }
+        CliCommand::ShowGlobalConfig {} => {
+            show_global_config(&program)?;
+        }
         CliCommand::CreateUser { pool } => {
             create_user(&program, priority_fee, &payer, &pool)?;
         }
@@ -73,6 +118,30 @@ fn main() -> Result<()> {
         CliCommand::Withdraw { pool, spt_amount } => {
             unstake(&program, priority_fee, &payer, &pool, spt_amount)?;
         }
+        CliCommand::RequestWithdraw { pool, spt_amount } => {
+            request_withdraw(&program, priority_fee, &payer, &pool, spt_amount)?;
+        }
+        CliCommand::CompleteWithdraw { pool } => {
+            complete_withdraw(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::SetRewardDuration {
+            pool,
+            reward_duration,
+        } => {
+            set_reward_duration(&program, priority_fee, &payer, &pool, reward_duration)?;
+        }
+        CliCommand::SetUnbondingPeriod {
+            pool,
+            unbonding_period,
+        } => {
+            set_unbonding_period(&program, priority_fee, &payer, &pool, unbonding_period)?;
+        }
+        CliCommand::SetMinStakeDuration {
+            pool,
+            min_stake_duration,
+        } => {
+            set_min_stake_duration(&program, priority_fee, &payer, &pool, min_stake_duration)?;
+        }
         CliCommand::Authorize { pool, funder } => {
             authorize_funder(&program, priority_fee, &payer, &pool, &funder)?;
         }
@@ -83,15 +152,154 @@ fn main() -> Result<()> {
             pool,
             amount_a,
             amount_b,
+            mode,
+            extend_seconds,
+        } => {
+            let mode = match mode {
+                FundModeArg::Reset => FundMode::Reset { amount_a, amount_b },
+                FundModeArg::TopUp => FundMode::TopUp { amount_a, amount_b },
+                FundModeArg::Extend => FundMode::Extend {
+                    seconds: extend_seconds,
+                },
+            };
+            fund(&program, priority_fee, &payer, &pool, mode)?;
+        }
+        CliCommand::EndCampaign { pool } => {
+            end_campaign(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::SetEmissionSchedule {
+            pool,
+            curve,
+            start_weight,
+            end_weight,
+            steps,
+        } => {
+            let schedule = match curve {
+                EmissionCurveArg::Flat => EmissionSchedule::Flat,
+                EmissionCurveArg::LinearDecay => EmissionSchedule::LinearDecay {
+                    start_weight,
+                    end_weight,
+                },
+                EmissionCurveArg::Stepwise => EmissionSchedule::Stepwise { steps },
+            };
+            set_emission_schedule(&program, priority_fee, &payer, &pool, schedule)?;
+        }
+        CliCommand::Claim { pool, owner } => {
+            let owner = owner.unwrap_or(payer.pubkey());
+            claim(&program, priority_fee, &payer, &pool, &owner)?;
+        }
+        CliCommand::ClaimAll {} => {
+            claim_all(&program, priority_fee, &payer)?;
+        }
+        CliCommand::TransferPosition { pool, recipient } => {
+            transfer_position(&program, priority_fee, &payer, &pool, &recipient)?;
+        }
+        CliCommand::SetCompoundKeeper { pool, keeper } => {
+            set_compound_keeper(&program, priority_fee, &payer, &pool, &keeper)?;
+        }
+        CliCommand::SetReferralFee {
+            pool,
+            referral_fee_bps,
+        } => {
+            set_referral_fee(&program, priority_fee, &payer, &pool, referral_fee_bps)?;
+        }
+        CliCommand::CreateReferrer { pool } => {
+            create_referrer(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::SetReferrer { pool, referrer } => {
+            set_referrer(&program, priority_fee, &payer, &pool, &referrer)?;
+        }
+        CliCommand::RestoreReferrer { pool, owner } => {
+            restore_referrer(&program, priority_fee, &payer, &pool, &owner)?;
+        }
+        CliCommand::ClaimReferral { pool } => {
+            claim_referral(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::SetClaimDelegate {
+            pool,
+            delegate,
+            reward_a_destination,
+            reward_b_destination,
+        } => {
+            set_claim_delegate(
+                &program,
+                priority_fee,
+                &payer,
+                &pool,
+                &delegate,
+                &reward_a_destination.unwrap_or_default(),
+                &reward_b_destination.unwrap_or_default(),
+            )?;
+        }
+        CliCommand::Compound { pool, owner } => {
+            let owner = owner.unwrap_or(payer.pubkey());
+            compound(&program, priority_fee, &payer, &pool, &owner)?;
+        }
+        CliCommand::EnableNativeReward {
+            pool,
+            reward_a,
+            reward_b,
+        } => {
+            enable_native_reward(&program, priority_fee, &payer, &pool, reward_a, reward_b)?;
+        }
+        CliCommand::SetBoostConfig {
+            pool,
+            boost_mint,
+            boost_collection,
+            multiplier_bps,
+        } => {
+            set_boost_config(
+                &program,
+                priority_fee,
+                &payer,
+                &pool,
+                &boost_mint.unwrap_or_default(),
+                &boost_collection.unwrap_or_default(),
+                multiplier_bps,
+            )?;
+        }
+        CliCommand::RefreshBoost { pool, mint } => {
+            refresh_boost(&program, priority_fee, &payer, &pool, &mint)?;
+        }
+        CliCommand::StripBoost { pool, owner } => {
+            strip_boost(&program, priority_fee, &payer, &pool, &owner)?;
+        }
+        CliCommand::CreateRegistrar {
+            realm,
+            governance_program_id,
+            governing_token_mint,
+            pool,
+            vote_lock_duration,
         } => {
-            fund(&program, priority_fee, &payer, &pool, amount_a, amount_b)?;
+            create_registrar(
+                &program,
+                priority_fee,
+                &payer,
+                &realm,
+                &governance_program_id,
+                &governing_token_mint,
+                &pool,
+                vote_lock_duration,
+            )?;
         }
-        CliCommand::Claim { pool } => {
-            claim(&program, priority_fee, &payer, &pool)?;
+        CliCommand::CreateVoterWeightRecord { registrar } => {
+            create_voter_weight_record(&program, priority_fee, &payer, &registrar)?;
+        }
+        CliCommand::UpdateVoterWeightRecord { registrar } => {
+            update_voter_weight_record(&program, priority_fee, &payer, &registrar)?;
         }
         CliCommand::CloseUser { pool } => {
             close_user(&program, priority_fee, &payer, &pool)?;
         }
+        CliCommand::SyncRewards { pool } => {
+            sync_rewards(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::WithdrawExtra { pool } => {
+            withdraw_extra(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::CloseDormantUser { pool, owner } => {
+            close_dormant_user(&program, priority_fee, &payer, &pool, &owner)?;
+        }
         CliCommand::ClosePool { pool } => {
             close_pool(&program, priority_fee, &payer, &pool)?;
         }
@@ -101,6 +309,57 @@ fn main() -> Result<()> {
         CliCommand::StakeInfo { pool } => {
             stake_info(&program, &pool, &payer.pubkey())?;
         }
+        CliCommand::RegisterPool { pool } => {
+            register_pool(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::ShowRegistry { staking_mint } => {
+            show_registry(&program, &staking_mint)?;
+        }
+        CliCommand::PrunePoolRegistry { staking_mint, pool } => {
+            prune_pool_registry(&program, priority_fee, &payer, &staking_mint, &pool)?;
+        }
+        CliCommand::InitStakeHub { staking_mint } => {
+            let base = opts.config_override.base;
+            initialize_stake_hub(&program, priority_fee, base, &payer, &staking_mint)?;
+        }
+        CliCommand::AttachPool { stake_hub, pool } => {
+            attach_pool(&program, priority_fee, &payer, &stake_hub, &pool)?;
+        }
+        CliCommand::DetachPool { stake_hub, pool } => {
+            detach_pool(&program, priority_fee, &payer, &stake_hub, &pool)?;
+        }
+        CliCommand::RemoveHubPoolMember {
+            stake_hub,
+            pool,
+            owner,
+        } => {
+            remove_hub_pool_member(&program, priority_fee, &payer, &stake_hub, &pool, &owner)?;
+        }
+        CliCommand::CreateHubUser { stake_hub } => {
+            create_hub_user(&program, priority_fee, &payer, &stake_hub)?;
+        }
+        CliCommand::JoinHubPool { stake_hub, pool } => {
+            change_hub_pool_membership(&program, priority_fee, &payer, &stake_hub, &pool, true)?;
+        }
+        CliCommand::LeaveHubPool { stake_hub, pool } => {
+            change_hub_pool_membership(&program, priority_fee, &payer, &stake_hub, &pool, false)?;
+        }
+        CliCommand::HubDeposit { stake_hub, amount } => {
+            hub_stake(&program, priority_fee, &payer, &stake_hub, amount, true)?;
+        }
+        CliCommand::HubWithdraw { stake_hub, amount } => {
+            hub_stake(&program, priority_fee, &payer, &stake_hub, amount, false)?;
+        }
+        CliCommand::ShowStakeHub { stake_hub } => {
+            show_stake_hub(&program, &stake_hub, &payer.pubkey())?;
+        }
+        CliCommand::MigratePool { pool } => {
+            migrate_pool(&program, priority_fee, &payer, &pool)?;
+        }
+        CliCommand::MigrateUser { pool, owner } => {
+            let owner = owner.unwrap_or(payer.pubkey());
+            migrate_user(&program, priority_fee, &payer, &pool, &owner)?;
+        }
         CliCommand::CheckFunderAllPool {} => {
             check_funder_all_pool(&program)?;
         }
@@ -142,7 +401,19 @@ fn initialize_pool<C: Deref<Target = impl Signer> + Clone>(
     } = get_vault_pdas(&program.id(), &pool_pda.pubkey);
     let (staking_vault_pubkey, _) = staking_vault;
     let (reward_a_vault_pubkey, _) = reward_a_vault;
-    let (reward_b_vault_pubkey, _) = reward_b_vault;
+    // Single reward pools have no reward B vault
+    let reward_b_vault = if reward_a_mint == reward_b_mint {
+        None
+    } else {
+        let (reward_b_vault_pubkey, _) = reward_b_vault;
+        Some(reward_b_vault_pubkey)
+    };
+    // Pools created before the global config is initialized charge no protocol fee
+    let (global_config_pubkey, _) = get_global_config_pda(&program.id());
+    let global_config = get_global_config(program)
+        .ok()
+        .map(|_| global_config_pubkey);
+    let (pool_registry, _) = get_pool_registry_pda(&program.id(), staking_mint);
 
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
@@ -159,9 +430,11 @@ fn initialize_pool<C: Deref<Target = impl Signer> + Clone>(
             reward_a_mint: *reward_a_mint,
             reward_a_vault: reward_a_vault_pubkey,
             reward_b_mint: *reward_b_mint,
-            reward_b_vault: reward_b_vault_pubkey,
+            reward_b_vault,
             authority: authority.pubkey(),
             base: base_pubkey,
+            global_config,
+            pool_registry,
             system_program: solana_program::system_program::ID,
             token_program: spl_token::ID,
             rent: solana_program::sysvar::rent::ID,
@@ -180,6 +453,101 @@ fn initialize_pool<C: Deref<Target = impl Signer> + Clone>(
     Ok(())
 }
 
+pub fn initialize_global_config<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    admin: &Keypair,
+    protocol_fee_bps: u16,
+    treasury: &Pubkey,
+) -> Result<()> {
+    let (global_config, _) = get_global_config_pda(&program.id());
+    let (program_data, _) = Pubkey::find_program_address(
+        &[program.id().as_ref()],
+        &solana_program::bpf_loader_upgradeable::ID,
+    );
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::InitializeGlobalConfig {
+            global_config,
+            admin: admin.pubkey(),
+            program: program.id(),
+            program_data,
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::InitializeGlobalConfig {
+            protocol_fee_bps,
+            treasury: *treasury,
+        }
+        .data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(admin);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn update_global_config<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    admin: &Keypair,
+    new_admin: &Pubkey,
+    protocol_fee_bps: u16,
+    treasury: &Pubkey,
+) -> Result<()> {
+    let (global_config, _) = get_global_config_pda(&program.id());
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::UpdateGlobalConfig {
+            global_config,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::UpdateGlobalConfig {
+            admin: *new_admin,
+            protocol_fee_bps,
+            treasury: *treasury,
+        }
+        .data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(admin);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn show_global_config<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+) -> Result<()> {
+    let global_config = get_global_config(program)?;
+    println!("global config data {:#?}", global_config);
+    Ok(())
+}
+
 pub fn create_user<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
@@ -368,13 +736,18 @@ pub fn unstake<C: Deref<Target = impl Signer> + Clone>(
     Ok(())
 }
 
-pub fn authorize_funder<C: Deref<Target = impl Signer> + Clone>(
+pub fn request_withdraw<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
-    authority: &Keypair,
-    pool: &Pubkey,
-    funder_to_add: &Pubkey,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+    spt_amount: u64,
 ) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+    let stake_from_account = get_or_create_ata(program, &owner.pubkey(), &pool.staking_mint)?;
+
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
@@ -383,34 +756,40 @@ pub fn authorize_funder<C: Deref<Target = impl Signer> + Clone>(
     }
     instructions.push(Instruction {
         program_id: program.id(),
-        accounts: farming::accounts::FunderChange {
-            pool: *pool,
-            authority: authority.pubkey(),
+        accounts: farming::accounts::Deposit {
+            pool: *pool_pda,
+            staking_vault: pool.staking_vault,
+            stake_from_account,
+            user: user_pubkey,
+            owner: owner.pubkey(),
+            token_program: spl_token::ID,
         }
         .to_account_metas(None),
-        data: farming::instruction::AuthorizeFunder {
-            funder_to_add: *funder_to_add,
-        }
-        .data(),
+        data: farming::instruction::RequestWithdraw { spt_amount }.data(),
     });
     let builder = program.request();
     let builder = instructions
         .into_iter()
         .fold(builder, |bld, ix| bld.instruction(ix));
-    let builder = builder.signer(authority);
+    let builder = builder.signer(owner);
 
     let signature = builder.send()?;
     println!("Signature {:?}", signature);
+
     Ok(())
 }
 
-pub fn deauthorize_funder<C: Deref<Target = impl Signer> + Clone>(
+pub fn complete_withdraw<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
-    authority: &Keypair,
-    pool: &Pubkey,
-    funder_to_remove: &Pubkey,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
 ) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+    let stake_from_account = get_or_create_ata(program, &owner.pubkey(), &pool.staking_mint)?;
+
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
@@ -419,39 +798,36 @@ pub fn deauthorize_funder<C: Deref<Target = impl Signer> + Clone>(
     }
     instructions.push(Instruction {
         program_id: program.id(),
-        accounts: farming::accounts::FunderChange {
-            pool: *pool,
-            authority: authority.pubkey(),
+        accounts: farming::accounts::Deposit {
+            pool: *pool_pda,
+            staking_vault: pool.staking_vault,
+            stake_from_account,
+            user: user_pubkey,
+            owner: owner.pubkey(),
+            token_program: spl_token::ID,
         }
         .to_account_metas(None),
-        data: farming::instruction::DeauthorizeFunder {
-            funder_to_remove: *funder_to_remove,
-        }
-        .data(),
+        data: farming::instruction::CompleteWithdraw {}.data(),
     });
     let builder = program.request();
     let builder = instructions
         .into_iter()
         .fold(builder, |bld, ix| bld.instruction(ix));
-    let builder = builder.signer(authority);
+    let builder = builder.signer(owner);
 
     let signature = builder.send()?;
     println!("Signature {:?}", signature);
+
     Ok(())
 }
 
-pub fn fund<C: Deref<Target = impl Signer> + Clone>(
+pub fn set_reward_duration<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
-    funder: &Keypair,
-    pool_pda: &Pubkey,
-    amount_a: u64,
-    amount_b: u64,
+    authority: &Keypair,
+    pool: &Pubkey,
+    reward_duration: u64,
 ) -> Result<()> {
-    let pool = get_pool(program, *pool_pda)?;
-    let from_a = get_or_create_ata(&program, &funder.pubkey(), &pool.reward_a_mint)?;
-    let from_b = get_or_create_ata(&program, &funder.pubkey(), &pool.reward_b_mint)?;
-
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
@@ -460,43 +836,31 @@ pub fn fund<C: Deref<Target = impl Signer> + Clone>(
     }
     instructions.push(Instruction {
         program_id: program.id(),
-        accounts: farming::accounts::Fund {
-            pool: *pool_pda,
-            staking_vault: pool.staking_vault,
-            reward_a_vault: pool.reward_a_vault,
-            reward_b_vault: pool.reward_b_vault,
-            funder: funder.pubkey(),
-            from_a,
-            from_b,
-            token_program: spl_token::ID,
+        accounts: farming::accounts::SetRewardDuration {
+            pool: *pool,
+            authority: authority.pubkey(),
         }
         .to_account_metas(None),
-        data: farming::instruction::Fund { amount_a, amount_b }.data(),
+        data: farming::instruction::SetRewardDuration { reward_duration }.data(),
     });
     let builder = program.request();
     let builder = instructions
         .into_iter()
         .fold(builder, |bld, ix| bld.instruction(ix));
-    let builder = builder.signer(funder);
+    let builder = builder.signer(authority);
 
     let signature = builder.send()?;
     println!("Signature {:?}", signature);
     Ok(())
 }
 
-pub fn claim<C: Deref<Target = impl Signer> + Clone>(
+pub fn set_unbonding_period<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
-    owner: &Keypair,
-    pool_pda: &Pubkey,
+    authority: &Keypair,
+    pool: &Pubkey,
+    unbonding_period: u64,
 ) -> Result<()> {
-    let pool = get_pool(program, *pool_pda)?;
-    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
-    let (user_pubkey, _) = user;
-
-    let reward_a_account = get_or_create_ata(&program, &owner.pubkey(), &pool.reward_a_mint)?;
-    let reward_b_account = get_or_create_ata(&program, &owner.pubkey(), &pool.reward_b_mint)?;
-
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
@@ -505,40 +869,64 @@ pub fn claim<C: Deref<Target = impl Signer> + Clone>(
     }
     instructions.push(Instruction {
         program_id: program.id(),
-        accounts: farming::accounts::ClaimReward {
-            pool: *pool_pda,
-            staking_vault: pool.staking_vault,
-            reward_a_vault: pool.reward_a_vault,
-            reward_b_vault: pool.reward_b_vault,
-            user: user_pubkey,
-            owner: owner.pubkey(),
-            reward_a_account,
-            reward_b_account,
-            token_program: spl_token::ID,
+        accounts: farming::accounts::SetUnbondingPeriod {
+            pool: *pool,
+            authority: authority.pubkey(),
         }
         .to_account_metas(None),
-        data: farming::instruction::Claim {}.data(),
+        data: farming::instruction::SetUnbondingPeriod { unbonding_period }.data(),
     });
     let builder = program.request();
     let builder = instructions
         .into_iter()
         .fold(builder, |bld, ix| bld.instruction(ix));
-    let builder = builder.signer(owner);
+    let builder = builder.signer(authority);
 
     let signature = builder.send()?;
     println!("Signature {:?}", signature);
     Ok(())
 }
 
-pub fn close_user<C: Deref<Target = impl Signer> + Clone>(
+pub fn set_min_stake_duration<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
-    owner: &Keypair,
-    pool_pda: &Pubkey,
+    authority: &Keypair,
+    pool: &Pubkey,
+    min_stake_duration: u64,
 ) -> Result<()> {
-    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
-    let (user_pubkey, _) = user;
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetMinStakeDuration {
+            pool: *pool,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetMinStakeDuration { min_stake_duration }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
 
+pub fn authorize_funder<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool: &Pubkey,
+    funder_to_add: &Pubkey,
+) -> Result<()> {
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
         instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
@@ -547,35 +935,88 @@ pub fn close_user<C: Deref<Target = impl Signer> + Clone>(
     }
     instructions.push(Instruction {
         program_id: program.id(),
-        accounts: farming::accounts::CloseUser {
-            pool: *pool_pda,
-            user: user_pubkey,
-            owner: owner.pubkey(),
+        accounts: farming::accounts::FunderChange {
+            pool: *pool,
+            authority: authority.pubkey(),
         }
         .to_account_metas(None),
-        data: farming::instruction::CloseUser {}.data(),
+        data: farming::instruction::AuthorizeFunder {
+            funder_to_add: *funder_to_add,
+        }
+        .data(),
     });
     let builder = program.request();
     let builder = instructions
         .into_iter()
         .fold(builder, |bld, ix| bld.instruction(ix));
-    let builder = builder.signer(owner);
+    let builder = builder.signer(authority);
 
     let signature = builder.send()?;
     println!("Signature {:?}", signature);
     Ok(())
 }
 
-pub fn close_pool<C: Deref<Target = impl Signer> + Clone>(
+pub fn deauthorize_funder<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
     priority_fee: Option<u64>,
     authority: &Keypair,
+    pool: &Pubkey,
+    funder_to_remove: &Pubkey,
+) -> Result<()> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::FunderChange {
+            pool: *pool,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::DeauthorizeFunder {
+            funder_to_remove: *funder_to_remove,
+        }
+        .data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn fund<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    funder: &Keypair,
     pool_pda: &Pubkey,
+    mode: FundMode,
 ) -> Result<()> {
     let pool = get_pool(program, *pool_pda)?;
-    let staking_refundee = get_or_create_ata(&program, &authority.pubkey(), &pool.staking_mint)?;
-    let reward_a_refundee = get_or_create_ata(&program, &authority.pubkey(), &pool.reward_a_mint)?;
-    let reward_b_refundee = get_or_create_ata(&program, &authority.pubkey(), &pool.reward_b_mint)?;
+    warn_undistributable_funding(&pool, mode)?;
+    let from_a = get_reward_token_account(
+        program,
+        &funder.pubkey(),
+        &pool.reward_a_mint,
+        !pool.reward_a_native,
+    )?;
+    let from_b = get_reward_token_account(
+        program,
+        &funder.pubkey(),
+        &pool.reward_b_mint,
+        !pool.reward_b_native && !pool.is_single_reward(),
+    )?;
+    let NativeRewardAccounts {
+        native_vault,
+        system_program,
+    } = get_native_reward_accounts(&program.id(), pool_pda, &pool);
 
     let mut instructions = vec![];
     if let Some(priority_fee) = priority_fee {
@@ -585,20 +1026,158 @@ pub fn close_pool<C: Deref<Target = impl Signer> + Clone>(
     }
     instructions.push(Instruction {
         program_id: program.id(),
-        accounts: farming::accounts::ClosePool {
-            refundee: authority.pubkey(),
-            staking_refundee,
-            reward_a_refundee,
-            reward_b_refundee,
+        accounts: farming::accounts::Fund {
             pool: *pool_pda,
-            authority: authority.pubkey(),
             staking_vault: pool.staking_vault,
             reward_a_vault: pool.reward_a_vault,
             reward_b_vault: pool.reward_b_vault,
+            funder: funder.pubkey(),
+            from_a,
+            from_b,
             token_program: spl_token::ID,
+            native_vault,
+            system_program,
         }
         .to_account_metas(None),
-        data: farming::instruction::ClosePool {}.data(),
+        data: farming::instruction::FundWithMode { mode }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(funder);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+/// Warn when part of the funding would not be emitted by the resulting reward rate
+fn warn_undistributable_funding(pool: &Pool, mode: FundMode) -> Result<()> {
+    // Emission schedules emit the whole funding
+    if pool.has_emission_schedule() {
+        return Ok(());
+    }
+    let (amount_a, amount_b, duration) = match mode {
+        FundMode::Reset { amount_a, amount_b } => (amount_a, amount_b, pool.reward_duration),
+        FundMode::TopUp { amount_a, amount_b } => {
+            let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+            (
+                amount_a,
+                amount_b,
+                pool.reward_duration_end.saturating_sub(now),
+            )
+        }
+        // Extension amounts are derived from the current rates
+        FundMode::Extend { .. } => return Ok(()),
+    };
+
+    for (reward, amount) in [("A", amount_a), ("B", amount_b)] {
+        if amount == 0 {
+            continue;
+        }
+        let emitted = funding_emission(amount, duration, pool.get_precision()).unwrap_or(0);
+        if emitted == 0 {
+            println!(
+                "Warning: funding {} of reward {} emits nothing over {} seconds, the transaction will fail",
+                amount, reward, duration
+            );
+        } else if emitted < amount {
+            println!(
+                "Warning: {} of reward {} funding cannot be distributed over {} seconds",
+                amount - emitted,
+                reward,
+                duration
+            );
+        }
+    }
+    Ok(())
+}
+
+pub fn sync_rewards<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    funder: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let NativeRewardAccounts { native_vault, .. } =
+        get_native_reward_accounts(&program.id(), pool_pda, &pool);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SyncRewards {
+            pool: *pool_pda,
+            reward_a_vault: pool.reward_a_vault,
+            reward_b_vault: pool.reward_b_vault,
+            funder: funder.pubkey(),
+            native_vault,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SyncRewards {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(funder);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn end_campaign<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let refund_a_account = get_reward_token_account(
+        program,
+        &authority.pubkey(),
+        &pool.reward_a_mint,
+        !pool.reward_a_native,
+    )?;
+    let refund_b_account = get_reward_token_account(
+        program,
+        &authority.pubkey(),
+        &pool.reward_b_mint,
+        !pool.reward_b_native && !pool.is_single_reward(),
+    )?;
+    let NativeRewardAccounts {
+        native_vault,
+        system_program,
+    } = get_native_reward_accounts(&program.id(), pool_pda, &pool);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::EndCampaign {
+            pool: *pool_pda,
+            reward_a_vault: pool.reward_a_vault,
+            reward_b_vault: pool.reward_b_vault,
+            refund_a_account,
+            refund_b_account,
+            authority: authority.pubkey(),
+            token_program: spl_token::ID,
+            native_vault,
+            system_program,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::EndCampaign {}.data(),
     });
     let builder = program.request();
     let builder = instructions
@@ -611,44 +1190,1617 @@ pub fn close_pool<C: Deref<Target = impl Signer> + Clone>(
     Ok(())
 }
 
-pub fn show_info<C: Deref<Target = impl Signer> + Clone>(
+pub fn claim<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
+    priority_fee: Option<u64>,
+    signer: &Keypair,
     pool_pda: &Pubkey,
+    owner: &Pubkey,
 ) -> Result<()> {
     let pool = get_pool(program, *pool_pda)?;
-    println!("pool data {:#?}", pool);
-    println!("pool_pubkey {:#?}", pool_pda);
-    println!("user_stake_count {:#?}", pool.user_stake_count);
-    println!("staking_vault {:#?}", pool.staking_vault);
+    let delegate = if signer.pubkey() == *owner {
+        None
+    } else {
+        Some(signer.pubkey())
+    };
+    let claim_accounts = get_claim_accounts(program, pool_pda, &pool, owner, delegate)?;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: get_claim_account_metas(&claim_accounts),
+        data: farming::instruction::Claim {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(signer);
 
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
     Ok(())
 }
 
-pub fn stake_info<C: Deref<Target = impl Signer> + Clone>(
+pub fn claim_all<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+) -> Result<()> {
+    let users: Vec<(Pubkey, User)> = program.accounts::<User>(vec![RpcFilterType::Memcmp(
+        Memcmp::new_base58_encoded(USER_OWNER_OFFSET, owner.pubkey().as_ref()),
+    )])?;
+
+    let mut claims = vec![];
+    for (_, user) in users.iter() {
+        if user.balance_staked == 0
+            && user.reward_a_per_token_pending == 0
+            && user.reward_b_per_token_pending == 0
+        {
+            continue;
+        }
+        let pool = get_pool(program, user.pool)?;
+        claims.push(get_claim_accounts(
+            program,
+            &user.pool,
+            &pool,
+            &owner.pubkey(),
+            None,
+        )?);
+    }
+    if claims.is_empty() {
+        println!("No position to claim");
+        return Ok(());
+    }
+
+    // A claim_many transaction claims every pool of the chunk or none, so the pools of a failed
+    // chunk are retried one by one and only the failing ones are left unclaimed
+    let mut failed = vec![];
+    for chunk in claims.chunks(CLAIM_ALL_CHUNK_SIZE) {
+        match send_claim_many(program, priority_fee, owner, chunk) {
+            Result::Ok(signature) => {
+                for claim in chunk {
+                    println!("Claimed pool {}", claim.pool);
+                }
+                println!("Signature {:?}", signature);
+            }
+            Err(err) if chunk.len() > 1 => {
+                println!(
+                    "Claim of {} pools failed, claiming them one by one: {}",
+                    chunk.len(),
+                    err
+                );
+                for claim in chunk {
+                    match send_claim_many(program, priority_fee, owner, std::slice::from_ref(claim))
+                    {
+                        Result::Ok(signature) => {
+                            println!("Claimed pool {}", claim.pool);
+                            println!("Signature {:?}", signature);
+                        }
+                        Err(err) => {
+                            println!("Failed to claim pool {}: {}", claim.pool, err);
+                            failed.push(claim.pool);
+                        }
+                    }
+                }
+            }
+            Err(err) => {
+                println!("Failed to claim pool {}: {}", chunk[0].pool, err);
+                failed.push(chunk[0].pool);
+            }
+        }
+    }
+    if !failed.is_empty() {
+        return Err(anyhow::anyhow!(
+            "{} pools were not claimed: {:?}",
+            failed.len(),
+            failed
+        ));
+    }
+    Ok(())
+}
+
+/// Claims the given pools in a single claim_many transaction, all or nothing
+fn send_claim_many<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    claims: &[farming::accounts::ClaimReward],
+) -> Result<Signature> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(
+        CLAIM_COMPUTE_UNITS * claims.len() as u32,
+    ));
+    let mut accounts = farming::accounts::ClaimMany {
+        caller: owner.pubkey(),
+    }
+    .to_account_metas(None);
+    for claim in claims {
+        accounts.extend(claim.to_account_metas(None));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts,
+        data: farming::instruction::ClaimMany {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    Ok(signature)
+}
+
+pub fn transfer_position<C: Deref<Target = impl Signer> + Clone>(
     program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
     pool_pda: &Pubkey,
-    user: &Pubkey,
+    recipient: &Pubkey,
 ) -> Result<()> {
-    let UserPDA { user } = get_user_pda(pool_pda, &user, &program.id());
-    let (user_pubkey, _) = user;
-    let user = get_user(&program, user_pubkey)?;
-    println!("balance_staked {:#?}", user.balance_staked);
-    println!(
-        "reward_a_per_token_complete {:#?}",
-        user.reward_a_per_token_complete
-    );
-    println!(
-        "reward_a_per_token_pending {:#?}",
-        user.reward_a_per_token_pending
-    );
-    println!(
-        "reward_b_per_token_complete {:#?}",
+    let pool = get_pool(program, *pool_pda)?;
+    let UserPDA {
+        user: recipient_user,
+    } = get_user_pda(pool_pda, recipient, &program.id());
+    let (recipient_user_pubkey, _) = recipient_user;
+    let claim = get_claim_accounts(program, pool_pda, &pool, &owner.pubkey(), None)?;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::TransferPosition {
+            recipient: *recipient,
+            recipient_user: recipient_user_pubkey,
+            payer: owner.pubkey(),
+            system_program: solana_program::system_program::ID,
+            claim,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::TransferPosition {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn set_compound_keeper<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+    keeper: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetCompoundKeeper {
+            pool: *pool_pda,
+            user: user_pubkey,
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetCompoundKeeper { keeper: *keeper }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn set_emission_schedule<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool: &Pubkey,
+    schedule: EmissionSchedule,
+) -> Result<()> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetEmissionSchedule {
+            pool: *pool,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetEmissionSchedule { schedule }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn set_referral_fee<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool: &Pubkey,
+    referral_fee_bps: u16,
+) -> Result<()> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetReferralFee {
+            pool: *pool,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetReferralFee { referral_fee_bps }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn create_referrer<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool: &Pubkey,
+) -> Result<()> {
+    let (referrer, _) = get_referrer_pda(&program.id(), pool, &owner.pubkey());
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::CreateReferrer {
+            pool: *pool,
+            referrer,
+            owner: owner.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateReferrer {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn set_referrer<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+    referrer_wallet: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+    let (referrer, _) = get_referrer_pda(&program.id(), pool_pda, referrer_wallet);
+    let (referral, _) = get_referral_pda(&program.id(), pool_pda, &owner.pubkey());
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetReferrer {
+            pool: *pool_pda,
+            user: user_pubkey,
+            referrer,
+            referral,
+            owner: owner.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetReferrer {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn restore_referrer<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    payer: &Keypair,
+    pool_pda: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+    let (referral, _) = get_referral_pda(&program.id(), pool_pda, owner);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::RestoreReferrer {
+            user: user_pubkey,
+            referral,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::RestoreReferrer {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(payer);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn claim_referral<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let (referrer, _) = get_referrer_pda(&program.id(), pool_pda, &owner.pubkey());
+
+    let reward_a_account = get_reward_token_account(
+        program,
+        &owner.pubkey(),
+        &pool.reward_a_mint,
+        !pool.reward_a_native,
+    )?;
+    let reward_b_account = get_reward_token_account(
+        program,
+        &owner.pubkey(),
+        &pool.reward_b_mint,
+        !pool.reward_b_native && !pool.is_single_reward(),
+    )?;
+    let NativeRewardAccounts {
+        native_vault,
+        system_program,
+    } = get_native_reward_accounts(&program.id(), pool_pda, &pool);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::ClaimReferralRewards {
+            pool: *pool_pda,
+            reward_a_vault: pool.reward_a_vault,
+            reward_b_vault: get_reward_b_vault(&pool),
+            referrer,
+            owner: owner.pubkey(),
+            reward_a_account,
+            reward_b_account,
+            token_program: spl_token::ID,
+            native_vault,
+            system_program,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::ClaimReferralRewards {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn set_claim_delegate<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+    delegate: &Pubkey,
+    reward_a_destination: &Pubkey,
+    reward_b_destination: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetClaimDelegate {
+            pool: *pool_pda,
+            user: user_pubkey,
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetClaimDelegate {
+            delegate: *delegate,
+            reward_a_destination: *reward_a_destination,
+            reward_b_destination: *reward_b_destination,
+        }
+        .data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn enable_native_reward<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool_pda: &Pubkey,
+    reward_a: bool,
+    reward_b: bool,
+) -> Result<()> {
+    let (native_vault, _bump) = get_native_reward_vault_pda(&program.id(), pool_pda);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::EnableNativeReward {
+            pool: *pool_pda,
+            native_vault,
+            authority: authority.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::EnableNativeReward { reward_a, reward_b }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Native reward vault {}", native_vault);
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn set_boost_config<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool_pda: &Pubkey,
+    boost_mint: &Pubkey,
+    boost_collection: &Pubkey,
+    boost_multiplier_bps: u16,
+) -> Result<()> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::SetBoostConfig {
+            pool: *pool_pda,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetBoostConfig {
+            boost_mint: *boost_mint,
+            boost_collection: *boost_collection,
+            boost_multiplier_bps,
+        }
+        .data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn refresh_boost<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+    mint: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+    let boost_token_account = get_associated_token_address(&owner.pubkey(), mint);
+    let boost_metadata = if pool.boost_collection != Pubkey::default() {
+        Some(get_metadata_pda(mint))
+    } else {
+        None
+    };
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::RefreshBoost {
+            pool: *pool_pda,
+            user: user_pubkey,
+            owner: owner.pubkey(),
+            boost_token_account,
+            boost_metadata,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::RefreshBoost {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn strip_boost<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    payer: &Keypair,
+    pool_pda: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+    let user = get_user(program, user_pubkey)?;
+    let pool = get_pool(program, *pool_pda)?;
+    // The metadata of the held mint proves a collection boost, unless the token account is gone
+    let boost_metadata = if pool.boost_collection != Pubkey::default() {
+        program
+            .rpc()
+            .get_account(&user.boost_token_account)
+            .ok()
+            .and_then(|account| spl_token::state::Account::unpack(&account.data).ok())
+            .map(|token_account| get_metadata_pda(&token_account.mint))
+    } else {
+        None
+    };
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::StripBoost {
+            pool: *pool_pda,
+            user: user_pubkey,
+            boost_token_account: user.boost_token_account,
+            boost_metadata,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::StripBoost {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(payer);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn create_registrar<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    realm_authority: &Keypair,
+    realm: &Pubkey,
+    governance_program_id: &Pubkey,
+    governing_token_mint: &Pubkey,
+    pool_pda: &Pubkey,
+    vote_lock_duration: u64,
+) -> Result<()> {
+    let (registrar, _bump) = get_registrar_pda(&program.id(), realm, governing_token_mint);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::CreateRegistrar {
+            registrar,
+            realm: *realm,
+            governance_program_id: *governance_program_id,
+            governing_token_mint: *governing_token_mint,
+            realm_authority: realm_authority.pubkey(),
+            pool: *pool_pda,
+            payer: realm_authority.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateRegistrar { vote_lock_duration }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(realm_authority);
+
+    let signature = builder.send()?;
+    println!("Registrar {}", registrar);
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn create_voter_weight_record<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    registrar: &Pubkey,
+) -> Result<()> {
+    let (voter_weight_record, _bump) =
+        get_voter_weight_record_pda(&program.id(), registrar, &owner.pubkey());
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::CreateVoterWeightRecord {
+            registrar: *registrar,
+            voter_weight_record,
+            governing_token_owner: owner.pubkey(),
+            payer: owner.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateVoterWeightRecord {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Voter weight record {}", voter_weight_record);
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn update_voter_weight_record<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    registrar_pda: &Pubkey,
+) -> Result<()> {
+    let registrar: Registrar = program.account(*registrar_pda)?;
+    let (voter_weight_record, _bump) =
+        get_voter_weight_record_pda(&program.id(), registrar_pda, &owner.pubkey());
+    let UserPDA { user } = get_user_pda(&registrar.pool, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::UpdateVoterWeightRecord {
+            registrar: *registrar_pda,
+            voter_weight_record,
+            user: user_pubkey,
+            governing_token_owner: owner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::UpdateVoterWeightRecord {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn compound<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool_pda: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+    let user = get_user(program, user_pubkey)?;
+    let ProtocolFeeAccounts {
+        global_config,
+        treasury_a_account,
+        treasury_b_account,
+        ..
+    } = get_protocol_fee_accounts(program, &pool)?;
+    let referrer = if user.referrer == Pubkey::default() {
+        None
+    } else {
+        let (referrer, _) = get_referrer_pda(&program.id(), pool_pda, &user.referrer);
+        Some(referrer)
+    };
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::Compound {
+            pool: *pool_pda,
+            staking_vault: pool.staking_vault,
+            reward_a_vault: pool.reward_a_vault,
+            reward_b_vault: pool.reward_b_vault,
+            user: user_pubkey,
+            authority: authority.pubkey(),
+            token_program: spl_token::ID,
+            global_config,
+            treasury_a_account,
+            treasury_b_account,
+            referrer,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::Compound {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn close_user<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::CloseUser {
+            pool: *pool_pda,
+            user: user_pubkey,
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CloseUser {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn withdraw_extra<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    // The staking vault surplus can only be withdrawn after the campaign
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    if pool.reward_duration_end < now {
+        let withdraw_to_account =
+            get_or_create_ata(program, &authority.pubkey(), &pool.staking_mint)?;
+        instructions.push(Instruction {
+            program_id: program.id(),
+            accounts: farming::accounts::WithdrawExtraToken {
+                pool: *pool_pda,
+                staking_vault: pool.staking_vault,
+                withdraw_to_account,
+                authority: authority.pubkey(),
+                token_program: spl_token::ID,
+            }
+            .to_account_metas(None),
+            data: farming::instruction::WithdrawExtraToken {}.data(),
+        });
+    } else {
+        println!("Campaign is running, skip the staking vault");
+    }
+    if pool.reward_obligations_tracked {
+        let withdraw_a_account =
+            get_or_create_ata(program, &authority.pubkey(), &pool.reward_a_mint)?;
+        let withdraw_b_account =
+            get_or_create_ata(program, &authority.pubkey(), &pool.reward_b_mint)?;
+        let NativeRewardAccounts {
+            native_vault,
+            system_program,
+        } = get_native_reward_accounts(&program.id(), pool_pda, &pool);
+        instructions.push(Instruction {
+            program_id: program.id(),
+            accounts: farming::accounts::WithdrawExtraReward {
+                pool: *pool_pda,
+                reward_a_vault: pool.reward_a_vault,
+                reward_b_vault: pool.reward_b_vault,
+                withdraw_a_account: Some(withdraw_a_account),
+                withdraw_b_account: Some(withdraw_b_account),
+                authority: authority.pubkey(),
+                token_program: spl_token::ID,
+                native_vault,
+                system_program,
+            }
+            .to_account_metas(None),
+            data: farming::instruction::WithdrawExtraReward {}.data(),
+        });
+    } else {
+        println!("Pool does not track reward obligations, skip the reward vaults");
+    }
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn close_dormant_user<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    payer: &Keypair,
+    pool_pda: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::CloseDormantUser {
+            pool: *pool_pda,
+            user: user_pubkey,
+            owner: *owner,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CloseDormantUser {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(payer);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn close_pool<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let staking_refundee = get_or_create_ata(&program, &authority.pubkey(), &pool.staking_mint)?;
+    let reward_a_refundee = get_or_create_ata(&program, &authority.pubkey(), &pool.reward_a_mint)?;
+    let (reward_b_vault, reward_b_refundee) = if pool.has_reward_b_vault() {
+        let reward_b_refundee =
+            get_or_create_ata(&program, &authority.pubkey(), &pool.reward_b_mint)?;
+        (Some(pool.reward_b_vault), Some(reward_b_refundee))
+    } else {
+        (None, None)
+    };
+    let pool_registry = if pool.registered {
+        let (pool_registry, _) = get_pool_registry_pda(&program.id(), &pool.staking_mint);
+        Some(pool_registry)
+    } else {
+        None
+    };
+    let NativeRewardAccounts {
+        native_vault,
+        system_program,
+    } = get_native_reward_accounts(&program.id(), pool_pda, &pool);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::ClosePool {
+            refundee: authority.pubkey(),
+            staking_refundee,
+            reward_a_refundee,
+            reward_b_refundee,
+            pool: *pool_pda,
+            authority: authority.pubkey(),
+            staking_vault: pool.staking_vault,
+            reward_a_vault: pool.reward_a_vault,
+            reward_b_vault,
+            token_program: spl_token::ID,
+            pool_registry,
+            native_vault,
+            system_program,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::ClosePool {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn show_info<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    println!("pool data {:#?}", pool);
+    println!("pool_pubkey {:#?}", pool_pda);
+    println!("user_stake_count {:#?}", pool.user_stake_count);
+    println!("staking_vault {:#?}", pool.staking_vault);
+
+    Ok(())
+}
+
+pub fn stake_info<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    pool_pda: &Pubkey,
+    user: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, &user, &program.id());
+    let (user_pubkey, _) = user;
+    let user = get_user(&program, user_pubkey)?;
+    println!("balance_staked {:#?}", user.balance_staked);
+    println!(
+        "reward_a_per_token_complete {:#?}",
+        user.reward_a_per_token_complete
+    );
+    println!(
+        "reward_a_per_token_pending {:#?}",
+        user.reward_a_per_token_pending
+    );
+    println!(
+        "reward_b_per_token_complete {:#?}",
         user.reward_b_per_token_complete
     );
     println!(
         "reward_b_per_token_pending {:#?}",
         user.reward_b_per_token_pending
     );
+    println!("balance_unbonding {:#?}", user.balance_unbonding);
+    println!("unbonding_end {:#?}", user.unbonding_end);
+    println!("unbonding_period {:#?}", user.unbonding_period);
+    println!("min_stake_duration {:#?}", user.min_stake_duration);
+    println!("referral_fee_bps {:#?}", user.referral_fee_bps);
+    println!("boost_multiplier_bps {:#?}", user.boost_multiplier_bps);
+    println!("boost_stake {:#?}", user.boost_stake);
+    println!("stake_seconds {:#?}", user.stake_seconds);
+    println!(
+        "stake_seconds_updated_at {:#?}",
+        user.stake_seconds_updated_at
+    );
+    println!("last_deposit_at {:#?}", user.last_deposit_at);
+    println!("referrer {:#?}", user.referrer);
+    println!("vote_locked_until {:#?}", user.vote_locked_until);
+    let pool = get_pool(program, *pool_pda)?;
+    let now = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    println!(
+        "seconds_until_claimable {:#?}",
+        pool.rewards_claimable_at(&user).saturating_sub(now)
+    );
+    Ok(())
+}
+
+pub fn register_pool<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    payer: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+    let (pool_registry, _) = get_pool_registry_pda(&program.id(), &pool.staking_mint);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::RegisterPool {
+            pool: *pool_pda,
+            pool_registry,
+            payer: payer.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::RegisterPool {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(payer);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn prune_pool_registry<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    admin: &Keypair,
+    staking_mint: &Pubkey,
+    pool: &Pubkey,
+) -> Result<()> {
+    let (global_config, _) = get_global_config_pda(&program.id());
+    let (pool_registry, _) = get_pool_registry_pda(&program.id(), staking_mint);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::PrunePoolRegistry {
+            global_config,
+            pool_registry,
+            admin: admin.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::PrunePoolRegistry { pool: *pool }.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(admin);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn show_registry<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    staking_mint: &Pubkey,
+) -> Result<()> {
+    let (pool_registry_pubkey, _) = get_pool_registry_pda(&program.id(), staking_mint);
+    let pool_registry: PoolRegistry = program.account(pool_registry_pubkey)?;
+    println!("pool_registry {:#?}", pool_registry_pubkey);
+    println!("len pool {}", pool_registry.pools.len());
+    for pool in pool_registry.pools.iter() {
+        println!("{}", pool);
+    }
+    Ok(())
+}
+
+fn initialize_stake_hub<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    base_location: String,
+    authority: &Keypair,
+    staking_mint: &Pubkey,
+) -> Result<()> {
+    let base_keypair = read_keypair_file(base_location).expect("base keypair file not found");
+    let base_pubkey = base_keypair.pubkey();
+    let (stake_hub, _) = get_stake_hub_pda(&program.id(), staking_mint, &base_pubkey);
+    let (staking_vault, _) = get_hub_staking_vault_pda(&program.id(), &stake_hub);
+
+    println!("stake hub address {}", stake_hub);
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::InitializeStakeHub {
+            stake_hub,
+            staking_mint: *staking_mint,
+            staking_vault,
+            authority: authority.pubkey(),
+            base: base_pubkey,
+            system_program: solana_program::system_program::ID,
+            token_program: spl_token::ID,
+            rent: solana_program::sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::InitializeStakeHub {}.data(),
+    });
+
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority).signer(&base_keypair);
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn attach_pool<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    stake_hub: &Pubkey,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let pool = get_pool(program, *pool_pda)?;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::AttachPool {
+            stake_hub: *stake_hub,
+            authority: authority.pubkey(),
+            pool: *pool_pda,
+            staking_vault: pool.staking_vault,
+            pool_authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::AttachPool {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn detach_pool<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    stake_hub: &Pubkey,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::DetachPool {
+            stake_hub: *stake_hub,
+            authority: authority.pubkey(),
+            pool: *pool_pda,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::DetachPool {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn remove_hub_pool_member<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    authority: &Keypair,
+    stake_hub: &Pubkey,
+    pool_pda: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let (hub_user, _) = get_hub_user_pda(&program.id(), stake_hub, owner);
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::RemoveHubPoolMember {
+            stake_hub: *stake_hub,
+            authority: authority.pubkey(),
+            hub_user,
+            pool: *pool_pda,
+            user: user_pubkey,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::RemoveHubPoolMember {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(authority);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn create_hub_user<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    stake_hub: &Pubkey,
+) -> Result<()> {
+    let (hub_user, _) = get_hub_user_pda(&program.id(), stake_hub, &owner.pubkey());
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::CreateHubUser {
+            stake_hub: *stake_hub,
+            hub_user,
+            owner: owner.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateHubUser {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn change_hub_pool_membership<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    stake_hub: &Pubkey,
+    pool_pda: &Pubkey,
+    join: bool,
+) -> Result<()> {
+    let (hub_user, _) = get_hub_user_pda(&program.id(), stake_hub, &owner.pubkey());
+    let UserPDA { user } = get_user_pda(pool_pda, &owner.pubkey(), &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    let accounts = farming::accounts::HubPoolMembership {
+        stake_hub: *stake_hub,
+        hub_user,
+        pool: *pool_pda,
+        user: user_pubkey,
+        owner: owner.pubkey(),
+    }
+    .to_account_metas(None);
+    let data = if join {
+        farming::instruction::JoinHubPool {}.data()
+    } else {
+        farming::instruction::LeaveHubPool {}.data()
+    };
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts,
+        data,
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn hub_stake<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    owner: &Keypair,
+    stake_hub_pubkey: &Pubkey,
+    amount: u64,
+    deposit: bool,
+) -> Result<()> {
+    let stake_hub: StakeHub = program.account(*stake_hub_pubkey)?;
+    let (hub_user_pubkey, _) = get_hub_user_pda(&program.id(), stake_hub_pubkey, &owner.pubkey());
+    let hub_user: HubUser = program.account(hub_user_pubkey)?;
+    let stake_from_account = get_or_create_ata(program, &owner.pubkey(), &stake_hub.staking_mint)?;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    let mut accounts = farming::accounts::HubStake {
+        stake_hub: *stake_hub_pubkey,
+        staking_vault: stake_hub.staking_vault,
+        hub_user: hub_user_pubkey,
+        owner: owner.pubkey(),
+        stake_from_account,
+        token_program: spl_token::ID,
+    }
+    .to_account_metas(None);
+    accounts.extend(get_joined_hub_pool_metas(&program.id(), &hub_user));
+    let data = if deposit {
+        farming::instruction::HubDeposit { amount }.data()
+    } else {
+        farming::instruction::HubWithdraw { amount }.data()
+    };
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts,
+        data,
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(owner);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn show_stake_hub<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    stake_hub_pubkey: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let stake_hub: StakeHub = program.account(*stake_hub_pubkey)?;
+    println!("stake hub data {:#?}", stake_hub);
+    let (hub_user_pubkey, _) = get_hub_user_pda(&program.id(), stake_hub_pubkey, owner);
+    if let std::result::Result::Ok(hub_user) = program.account::<HubUser>(hub_user_pubkey) {
+        println!("hub user data {:#?}", hub_user);
+    }
+    Ok(())
+}
+
+pub fn migrate_pool<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    payer: &Keypair,
+    pool_pda: &Pubkey,
+) -> Result<()> {
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::MigratePool {
+            pool: *pool_pda,
+            payer: payer.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::MigratePool {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(payer);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
+    Ok(())
+}
+
+pub fn migrate_user<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    priority_fee: Option<u64>,
+    payer: &Keypair,
+    pool_pda: &Pubkey,
+    owner: &Pubkey,
+) -> Result<()> {
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+
+    let mut instructions = vec![];
+    if let Some(priority_fee) = priority_fee {
+        instructions.push(ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee,
+        ));
+    }
+    instructions.push(Instruction {
+        program_id: program.id(),
+        accounts: farming::accounts::MigrateUser {
+            user: user_pubkey,
+            payer: payer.pubkey(),
+            system_program: solana_program::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::MigrateUser {}.data(),
+    });
+    let builder = program.request();
+    let builder = instructions
+        .into_iter()
+        .fold(builder, |bld, ix| bld.instruction(ix));
+    let builder = builder.signer(payer);
+
+    let signature = builder.send()?;
+    println!("Signature {:?}", signature);
     Ok(())
 }
 
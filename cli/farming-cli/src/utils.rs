@@ -1,7 +1,9 @@
+use anchor_client::anchor_lang::ToAccountMetas;
 use anchor_client::{
-    solana_sdk::{pubkey::Pubkey, signer::Signer},
+    solana_sdk::{instruction::AccountMeta, pubkey::Pubkey, signer::Signer},
     Program,
 };
+use anchor_spl::token::spl_token;
 use anyhow::Result;
 use spl_associated_token_account::{create_associated_token_account, get_associated_token_address};
 use std::ops::Deref;
@@ -23,7 +25,7 @@ pub fn get_or_create_ata<C: Deref<Target = impl Signer> + Clone>(
     wallet_address: &Pubkey,
     token_mint: &Pubkey,
 ) -> Result<Pubkey> {
-    let ata_account = get_associated_token_address(&program.payer(), token_mint);
+    let ata_account = get_associated_token_address(wallet_address, token_mint);
     let ata_account_info = program.rpc().get_account(&ata_account);
     if ata_account_info.is_err() {
         println!("Create ATA {:?} for Mint {:?}", ata_account, token_mint);
@@ -104,3 +106,311 @@ pub fn get_vault_pdas(program_id: &Pubkey, pool_pubkey: &Pubkey) -> VaultPDAs {
         reward_b_vault: (reward_b_vault_pubkey, reward_b_vault_bump),
     }
 }
+
+pub fn get_global_config_pda(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[farming::GLOBAL_CONFIG_SEED], program_id)
+}
+
+pub fn get_global_config<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+) -> Result<farming::GlobalConfig> {
+    let (global_config_pubkey, _) = get_global_config_pda(&program.id());
+    Ok(program.account(global_config_pubkey)?)
+}
+
+pub struct ProtocolFeeAccounts {
+    pub global_config: Option<Pubkey>,
+    pub treasury_a_account: Option<Pubkey>,
+    pub treasury_b_account: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+}
+
+/// Accounts required to pay the protocol fee on claim, none when the pool has no fee
+pub fn get_protocol_fee_accounts<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    pool: &farming::pool::Pool,
+) -> Result<ProtocolFeeAccounts> {
+    if pool.protocol_fee_bps == 0 {
+        return Ok(ProtocolFeeAccounts {
+            global_config: None,
+            treasury_a_account: None,
+            treasury_b_account: None,
+            treasury: None,
+        });
+    }
+    let (global_config_pubkey, _) = get_global_config_pda(&program.id());
+    let global_config = get_global_config(program)?;
+    let treasury_a_account = get_reward_token_account(
+        program,
+        &global_config.treasury,
+        &pool.reward_a_mint,
+        !pool.reward_a_native,
+    )?;
+    let treasury_b_account = get_reward_token_account(
+        program,
+        &global_config.treasury,
+        &pool.reward_b_mint,
+        !pool.reward_b_native && !pool.is_single_reward(),
+    )?;
+    let treasury = if pool.reward_a_native || pool.reward_b_native {
+        Some(global_config.treasury)
+    } else {
+        None
+    };
+    Ok(ProtocolFeeAccounts {
+        global_config: Some(global_config_pubkey),
+        treasury_a_account,
+        treasury_b_account,
+        treasury,
+    })
+}
+
+/// ATA of the reward mint, none when the reward is not paid in tokens, being native SOL or unused
+pub fn get_reward_token_account<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    wallet_address: &Pubkey,
+    reward_mint: &Pubkey,
+    token_reward: bool,
+) -> Result<Option<Pubkey>> {
+    if !token_reward {
+        return Ok(None);
+    }
+    Ok(Some(get_or_create_ata(
+        program,
+        wallet_address,
+        reward_mint,
+    )?))
+}
+
+/// Token account a reward is claimed to, the destination registered by the user if any
+pub fn get_reward_destination<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    owner: &Pubkey,
+    reward_mint: &Pubkey,
+    token_reward: bool,
+    registered: &Pubkey,
+) -> Result<Option<Pubkey>> {
+    if token_reward && *registered != Pubkey::default() {
+        return Ok(Some(*registered));
+    }
+    get_reward_token_account(program, owner, reward_mint, token_reward)
+}
+
+/// Accounts claiming the rewards of owner in a pool, signed by the owner or by the delegate
+pub fn get_claim_accounts<C: Deref<Target = impl Signer> + Clone>(
+    program: &Program<C>,
+    pool_pda: &Pubkey,
+    pool: &farming::pool::Pool,
+    owner: &Pubkey,
+    delegate: Option<Pubkey>,
+) -> Result<farming::accounts::ClaimReward> {
+    let UserPDA { user } = get_user_pda(pool_pda, owner, &program.id());
+    let (user_pubkey, _) = user;
+    let user = get_user(program, user_pubkey)?;
+
+    let reward_a_account = get_reward_destination(
+        program,
+        owner,
+        &pool.reward_a_mint,
+        !pool.reward_a_native,
+        &user.reward_a_destination,
+    )?;
+    let reward_b_account = get_reward_destination(
+        program,
+        owner,
+        &pool.reward_b_mint,
+        !pool.reward_b_native && !pool.is_single_reward(),
+        &user.reward_b_destination,
+    )?;
+    let ProtocolFeeAccounts {
+        global_config,
+        treasury_a_account,
+        treasury_b_account,
+        treasury,
+    } = get_protocol_fee_accounts(program, pool)?;
+    let NativeRewardAccounts {
+        native_vault,
+        system_program,
+    } = get_native_reward_accounts(&program.id(), pool_pda, pool);
+    let referrer = if user.referrer == Pubkey::default() {
+        None
+    } else {
+        let (referrer, _) = get_referrer_pda(&program.id(), pool_pda, &user.referrer);
+        Some(referrer)
+    };
+
+    Ok(farming::accounts::ClaimReward {
+        pool: *pool_pda,
+        staking_vault: pool.staking_vault,
+        reward_a_vault: pool.reward_a_vault,
+        reward_b_vault: get_reward_b_vault(pool),
+        user: user_pubkey,
+        owner: *owner,
+        reward_a_account,
+        reward_b_account,
+        token_program: spl_token::ID,
+        global_config,
+        treasury_a_account,
+        treasury_b_account,
+        native_vault,
+        treasury,
+        system_program,
+        delegate,
+        referrer,
+    })
+}
+
+/// Account metas of a claim. The owner is an unchecked account, so it is marked as signer unless the delegate signs
+pub fn get_claim_account_metas(claim: &farming::accounts::ClaimReward) -> Vec<AccountMeta> {
+    let mut metas = claim.to_account_metas(None);
+    if claim.delegate.is_none() {
+        for meta in metas.iter_mut().filter(|meta| meta.pubkey == claim.owner) {
+            meta.is_signer = true;
+        }
+    }
+    metas
+}
+
+/// Reward B vault to claim from, none when reward B is native SOL or unused
+pub fn get_reward_b_vault(pool: &farming::pool::Pool) -> Option<Pubkey> {
+    if pool.reward_b_native || pool.is_single_reward() {
+        None
+    } else {
+        Some(pool.reward_b_vault)
+    }
+}
+
+pub fn get_native_reward_vault_pda(program_id: &Pubkey, pool: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[farming::NATIVE_REWARD_VAULT_SEED, pool.as_ref()],
+        program_id,
+    )
+}
+
+pub struct NativeRewardAccounts {
+    pub native_vault: Option<Pubkey>,
+    pub system_program: Option<Pubkey>,
+}
+
+/// Accounts required to move native rewards, none when the pool has no native reward
+pub fn get_native_reward_accounts(
+    program_id: &Pubkey,
+    pool_pubkey: &Pubkey,
+    pool: &farming::pool::Pool,
+) -> NativeRewardAccounts {
+    if !pool.reward_a_native && !pool.reward_b_native {
+        return NativeRewardAccounts {
+            native_vault: None,
+            system_program: None,
+        };
+    }
+    let (native_vault, _) = get_native_reward_vault_pda(program_id, pool_pubkey);
+    NativeRewardAccounts {
+        native_vault: Some(native_vault),
+        system_program: Some(solana_program::system_program::ID),
+    }
+}
+
+pub fn get_pool_registry_pda(program_id: &Pubkey, staking_mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[farming::POOL_REGISTRY_SEED, staking_mint.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_stake_hub_pda(
+    program_id: &Pubkey,
+    staking_mint: &Pubkey,
+    base: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            farming::STAKE_HUB_SEED,
+            staking_mint.as_ref(),
+            base.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_hub_staking_vault_pda(program_id: &Pubkey, stake_hub: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"staking", stake_hub.as_ref()], program_id)
+}
+
+pub fn get_hub_user_pda(program_id: &Pubkey, stake_hub: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[farming::HUB_USER_SEED, stake_hub.as_ref(), owner.as_ref()],
+        program_id,
+    )
+}
+
+/// Pool and user accounts of every pool joined by the hub user, in slot order
+pub fn get_joined_hub_pool_metas(
+    program_id: &Pubkey,
+    hub_user: &farming::HubUser,
+) -> Vec<AccountMeta> {
+    let mut metas = vec![];
+    for pool in hub_user.joined_pools.iter() {
+        if *pool == Pubkey::default() {
+            continue;
+        }
+        let UserPDA { user } = get_user_pda(pool, &hub_user.owner, program_id);
+        let (user_pubkey, _) = user;
+        metas.push(AccountMeta::new(*pool, false));
+        metas.push(AccountMeta::new(user_pubkey, false));
+    }
+    metas
+}
+
+pub fn get_referrer_pda(program_id: &Pubkey, pool: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[farming::REFERRER_SEED, pool.as_ref(), owner.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_referral_pda(program_id: &Pubkey, pool: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[farming::REFERRAL_SEED, pool.as_ref(), owner.as_ref()],
+        program_id,
+    )
+}
+
+pub fn get_registrar_pda(
+    program_id: &Pubkey,
+    realm: &Pubkey,
+    governing_token_mint: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            farming::REGISTRAR_SEED,
+            realm.as_ref(),
+            governing_token_mint.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_voter_weight_record_pda(
+    program_id: &Pubkey,
+    registrar: &Pubkey,
+    owner: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            farming::VOTER_WEIGHT_RECORD_SEED,
+            registrar.as_ref(),
+            owner.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
+    let metadata_program_id = farming::metadata::TOKEN_METADATA_PROGRAM_ID;
+    let (metadata, _bump) = Pubkey::find_program_address(
+        &[b"metadata", metadata_program_id.as_ref(), mint.as_ref()],
+        &metadata_program_id,
+    );
+    metadata
+}
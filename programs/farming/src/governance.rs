@@ -0,0 +1,107 @@
+use crate::metadata::Reader;
+use anchor_lang::prelude::*;
+
+/// Account type of realms created by spl-governance v1
+const REALM_V1_ACCOUNT_TYPE: u8 = 1;
+/// Account type of realms created by spl-governance v2 and later
+const REALM_V2_ACCOUNT_TYPE: u8 = 16;
+
+/// Fields of an spl-governance realm needed by the voter weight plugin
+#[derive(Debug, PartialEq, Eq)]
+pub struct RealmInfo {
+    /// Community token mint
+    pub community_mint: Pubkey,
+    /// Council token mint
+    pub council_mint: Option<Pubkey>,
+    /// Realm authority
+    pub authority: Option<Pubkey>,
+}
+
+impl RealmInfo {
+    /// Whether the mint is the community or the council mint of the realm
+    pub fn is_governing_token_mint(&self, mint: &Pubkey) -> bool {
+        self.community_mint == *mint || self.council_mint == Some(*mint)
+    }
+}
+
+/// Read the realm fields from spl-governance realm account data
+pub fn parse_realm(data: &[u8]) -> Option<RealmInfo> {
+    let mut reader = Reader::new(data);
+    let account_type = reader.u8()?;
+    if account_type != REALM_V1_ACCOUNT_TYPE && account_type != REALM_V2_ACCOUNT_TYPE {
+        return None;
+    }
+    let community_mint = reader.pubkey()?;
+    // config legacy flags, reserved, min community weight to create governance,
+    // community mint max voter weight source
+    reader.take(1 + 1 + 6 + 8 + 1 + 8)?;
+    let council_mint = reader.option_pubkey()?;
+    // reserved, legacy voting proposal count
+    reader.take(6 + 2)?;
+    let authority = reader.option_pubkey()?;
+    Some(RealmInfo {
+        community_mint,
+        council_mint,
+        authority,
+    })
+}
+
+#[cfg(test)]
+mod governance_test {
+    use super::*;
+
+    fn push_option_pubkey(data: &mut Vec<u8>, key: Option<Pubkey>) {
+        match key {
+            Some(key) => {
+                data.push(1);
+                data.extend_from_slice(key.as_ref());
+            }
+            None => data.push(0),
+        }
+    }
+
+    fn realm_data(
+        community_mint: &Pubkey,
+        council_mint: Option<Pubkey>,
+        authority: Option<Pubkey>,
+    ) -> Vec<u8> {
+        let mut data = vec![REALM_V2_ACCOUNT_TYPE];
+        data.extend_from_slice(community_mint.as_ref());
+        // legacy flags, reserved, min community weight to create governance
+        data.extend_from_slice(&[0u8; 1 + 1 + 6 + 8]);
+        // max voter weight source
+        data.push(0);
+        data.extend_from_slice(&10_000_000_000u64.to_le_bytes());
+        push_option_pubkey(&mut data, council_mint);
+        // reserved, legacy voting proposal count
+        data.extend_from_slice(&[0u8; 6 + 2]);
+        push_option_pubkey(&mut data, authority);
+        // name
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(b"DAO!");
+        data
+    }
+
+    #[test]
+    fn test_parse_realm() {
+        let community_mint = Pubkey::new_unique();
+        let council_mint = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+
+        let data = realm_data(&community_mint, Some(council_mint), Some(authority));
+        let realm = parse_realm(&data).unwrap();
+        assert_eq!(realm.authority, Some(authority));
+        assert!(realm.is_governing_token_mint(&community_mint));
+        assert!(realm.is_governing_token_mint(&council_mint));
+        assert!(!realm.is_governing_token_mint(&authority));
+
+        let data = realm_data(&community_mint, None, None);
+        let realm = parse_realm(&data).unwrap();
+        assert_eq!(realm.council_mint, None);
+        assert_eq!(realm.authority, None);
+
+        let mut data = realm_data(&community_mint, None, Some(authority));
+        data[0] = 2;
+        assert_eq!(parse_realm(&data), None);
+    }
+}
@@ -12,17 +12,99 @@ use std::fmt::Debug;
 use crate::pool::*;
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::{clock, sysvar};
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
 use anchor_spl::token::spl_token;
 use anchor_spl::token::{self, Mint, Token, TokenAccount};
+use std::collections::{BTreeMap, BTreeSet};
 use std::convert::TryFrom;
 
 /// Export for pool implementation
 pub mod pool;
 
+/// Token metadata reader for boost collections
+pub mod metadata;
+
+/// spl-governance realm reader for the voter weight plugin
+pub mod governance;
+
 declare_id!("FarmuwXPWXvefWUeqFAa5w6rifLkq5X6E8bimYvrhCB1");
 
+/// Precision of pools created before the precision was stored per pool
 const PRECISION: u128 = 1_000_000_000;
 
+/// Pool account size, discriminator + content + buffer
+pub const POOL_SPACE: usize = 8 + 1_000;
+
+/// User account size, discriminator + content + buffer
+pub const USER_SPACE: usize = 8 + 500;
+
+/// Stake hub account size, discriminator + content + buffer
+pub const STAKE_HUB_SPACE: usize = 8 + 300;
+
+/// Hub user account size, discriminator + content + buffer
+pub const HUB_USER_SPACE: usize = 8 + 300;
+
+/// Stake checkpoint account size, discriminator + content + buffer
+pub const STAKE_CHECKPOINT_SPACE: usize = 8 + 150;
+
+/// Maximum boost multiplier, in basis points
+pub const MAX_BOOST_MULTIPLIER_BPS: u16 = 50_000;
+
+/// Seed of the global config PDA
+pub const GLOBAL_CONFIG_SEED: &[u8] = b"global_config";
+
+/// Seed prefix of the pool registry PDA of a staking mint
+pub const POOL_REGISTRY_SEED: &[u8] = b"pool_registry";
+
+/// Maximum number of pools listed in the registry of a staking mint
+pub const MAX_REGISTRY_POOLS: usize = 64;
+
+/// Seed prefix of the voter weight registrar PDA of a realm and governing token mint
+pub const REGISTRAR_SEED: &[u8] = b"registrar";
+
+/// Seed prefix of the voter weight record PDA of a registrar and governing token owner
+pub const VOTER_WEIGHT_RECORD_SEED: &[u8] = b"voter-weight-record";
+
+/// Seed prefix of the PDA holding the native SOL rewards of a pool
+pub const NATIVE_REWARD_VAULT_SEED: &[u8] = b"native_reward";
+
+/// Seed prefix of the stake hub PDA of a staking mint and base
+pub const STAKE_HUB_SEED: &[u8] = b"stake_hub";
+
+/// Seed prefix of the hub user PDA of a stake hub and owner
+pub const HUB_USER_SEED: &[u8] = b"hub_user";
+
+/// Maximum number of reward pools attached to a stake hub
+pub const MAX_HUB_POOLS: usize = 4;
+
+/// Maximum protocol fee on claimed rewards, in basis points
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+/// Seed prefix of the referrer PDA of a pool and referrer wallet
+pub const REFERRER_SEED: &[u8] = b"referrer";
+
+/// Seed prefix of the referral PDA of a pool and user wallet
+pub const REFERRAL_SEED: &[u8] = b"referral";
+
+/// Seed prefix of the stake checkpoint PDA of a user, authority and checkpoint id
+pub const STAKE_CHECKPOINT_SEED: &[u8] = b"stake_checkpoint";
+
+/// Maximum referrer share of claimed rewards, in basis points
+pub const MAX_REFERRAL_FEE_BPS: u16 = 1_000;
+
+/// Maximum number of steps of a stepwise emission schedule
+pub const MAX_EMISSION_STEPS: usize = 8;
+
+/// Maximum number of pools claimed by one claim many instruction, bounded by the compute limit
+pub const MAX_CLAIM_MANY_POOLS: usize = 8;
+
+/// Maximum unbonding period, 30 days
+pub const MAX_UNBONDING_PERIOD: u64 = 2_592_000;
+
+/// Maximum time stake must stay in a pool before its rewards can be claimed, 30 days
+pub const MAX_MIN_STAKE_DURATION: u64 = 2_592_000;
+
 /// Updates the pool with the total reward per token that is due stakers
 /// Using the calculator specific to that pool version which uses the reward
 /// rate on the pool.
@@ -30,35 +112,567 @@ const PRECISION: u128 = 1_000_000_000;
 /// A new user to the pool has their completed set to current amount due
 /// such that they start earning from that point. Hence "complete" is a
 /// bit misleading - it does not mean actually earned.
+#[allow(clippy::result_large_err)]
 pub fn update_rewards(
     pool: &mut Box<Account<Pool>>,
     user: Option<&mut Box<Account<User>>>,
     total_staked: u64,
 ) -> Result<()> {
     let last_time_reward_applicable = last_time_reward_applicable(pool.reward_duration_end);
+    let current_time: u64 = clock::Clock::get()?
+        .unix_timestamp
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+
+    pool.stake_seconds = accumulate_stake_seconds(
+        pool.stake_seconds,
+        pool.total_staked,
+        pool.stake_seconds_updated_at,
+        current_time,
+    )
+    .ok_or(ErrorCode::MathOverflow)?;
+    pool.stake_seconds_updated_at = current_time;
+
+    if total_staked == 0 {
+        // Rewards emitted while nothing is staked are assigned to no one and no longer owed
+        let (unassigned_a, unassigned_b) =
+            emission_between(pool, pool.last_update_time, last_time_reward_applicable)
+                .ok_or(ErrorCode::MathOverflow)?;
+        pool.reward_a_obligation = pool.reward_a_obligation.saturating_sub(unassigned_a);
+        pool.reward_b_obligation = pool.reward_b_obligation.saturating_sub(unassigned_b);
+    }
 
-    let (reward_a, reward_b) = reward_per_token(pool, total_staked, last_time_reward_applicable);
+    let ((reward_a, remainder_a), (reward_b, remainder_b)) =
+        reward_per_token(pool, total_staked, last_time_reward_applicable);
 
     pool.reward_a_per_token_stored = reward_a;
-    if pool.reward_a_vault != pool.reward_b_vault {
+    pool.reward_a_per_token_remainder = remainder_a;
+    if !pool.is_single_reward() {
         pool.reward_b_per_token_stored = reward_b;
+        pool.reward_b_per_token_remainder = remainder_b;
     }
 
     pool.last_update_time = last_time_reward_applicable;
 
     if let Some(u) = user {
-        let (a, b) = user_earned_amount(pool, u);
+        u.stake_seconds = accumulate_stake_seconds(
+            u.stake_seconds,
+            u.balance_staked,
+            u.stake_seconds_updated_at,
+            current_time,
+        )
+        .ok_or(ErrorCode::MathOverflow)?;
+        u.stake_seconds_updated_at = current_time;
+
+        let ((a, earned_remainder_a), (b, earned_remainder_b)) = user_earned_amount(pool, u);
 
         u.reward_a_per_token_pending = a;
+        u.reward_a_earned_remainder = earned_remainder_a;
         u.reward_a_per_token_complete = pool.reward_a_per_token_stored;
 
         u.reward_b_per_token_pending = b;
+        u.reward_b_earned_remainder = earned_remainder_b;
         u.reward_b_per_token_complete = pool.reward_b_per_token_stored;
     }
 
     Ok(())
 }
 
+/// Recomputes the boost stake of the user from its balance and boost multiplier.
+/// Rewards must be updated before, with the previous stake.
+#[allow(clippy::result_large_err)]
+pub fn sync_boost_stake(
+    pool: &mut Box<Account<Pool>>,
+    user: &mut Box<Account<User>>,
+) -> Result<()> {
+    let boost_stake = boost_stake(user.balance_staked, user.boost_multiplier_bps)
+        .ok_or(ErrorCode::MathOverflow)?;
+    pool.total_boost_stake = pool
+        .total_boost_stake
+        .checked_sub(user.boost_stake)
+        .and_then(|total| total.checked_add(boost_stake))
+        .ok_or(ErrorCode::MathOverflow)?;
+    user.boost_stake = boost_stake;
+    Ok(())
+}
+
+/// Whether the token account holds the boost mint or a token of the boost collection of the pool
+fn is_boost_token(
+    pool: &Pool,
+    token_account: &TokenAccount,
+    boost_metadata: &Option<UncheckedAccount>,
+) -> bool {
+    let holds_mint = pool.boost_mint != Pubkey::default() && token_account.mint == pool.boost_mint;
+    let holds_collection = pool.boost_collection != Pubkey::default()
+        && boost_metadata
+            .as_ref()
+            .and_then(|metadata| metadata::verified_collection(metadata, &token_account.mint))
+            == Some(pool.boost_collection);
+    token_account.amount > 0 && (holds_mint || holds_collection)
+}
+
+/// Updates the reward rates and the end of the reward period of the pool funded with the amounts using the given [FundMode].
+/// Returns the parts of the amounts the rates cannot distribute.
+#[allow(clippy::result_large_err)]
+fn apply_funding(
+    pool: &mut Box<Account<Pool>>,
+    mode: FundMode,
+    amount_a: u64,
+    amount_b: u64,
+    current_time: u64,
+) -> Result<(u64, u64)> {
+    update_rewards(pool, None, pool.get_total_effective_stake())?;
+
+    let (old_reward_a_rate, old_reward_b_rate) =
+        (pool.get_reward_a_rate(), pool.get_reward_b_rate());
+    let ((leftover_a, _), (leftover_b, _)) = leftover_rewards(pool, current_time);
+
+    let (reward_a_rate, reward_b_rate) = match mode {
+        FundMode::Reset { .. } => rate_after_funding(pool, amount_a, amount_b)?,
+        FundMode::TopUp { .. } => rate_after_top_up(pool, amount_a, amount_b, current_time)
+            .ok_or(ErrorCode::MathOverflow)?,
+        FundMode::Extend { .. } => (pool.get_reward_a_rate(), pool.get_reward_b_rate()),
+    };
+
+    // Part of the funding the rates actually emit, the rest stays in the vaults as dust
+    let (distributed_a, distributed_b) = match mode {
+        FundMode::Reset { .. } if pool.has_emission_schedule() => {
+            let precision = pool.get_precision();
+            (
+                pool.reward_a_scheduled
+                    .checked_div(precision)
+                    .and_then(|amount| u64::try_from(amount).ok())
+                    .ok_or(ErrorCode::MathOverflow)?
+                    .saturating_sub(leftover_a),
+                pool.reward_b_scheduled
+                    .checked_div(precision)
+                    .and_then(|amount| u64::try_from(amount).ok())
+                    .ok_or(ErrorCode::MathOverflow)?
+                    .saturating_sub(leftover_b),
+            )
+        }
+        FundMode::Reset { .. } => (
+            emission_amount(pool, reward_a_rate, pool.reward_duration)
+                .ok_or(ErrorCode::MathOverflow)?
+                .saturating_sub(leftover_a),
+            emission_amount(pool, reward_b_rate, pool.reward_duration)
+                .ok_or(ErrorCode::MathOverflow)?
+                .saturating_sub(leftover_b),
+        ),
+        FundMode::TopUp { .. } => {
+            let remaining_seconds = pool.reward_duration_end.saturating_sub(current_time);
+            (
+                reward_a_rate
+                    .checked_sub(old_reward_a_rate)
+                    .and_then(|rate| emission_amount(pool, rate, remaining_seconds))
+                    .ok_or(ErrorCode::MathOverflow)?,
+                reward_b_rate
+                    .checked_sub(old_reward_b_rate)
+                    .and_then(|rate| emission_amount(pool, rate, remaining_seconds))
+                    .ok_or(ErrorCode::MathOverflow)?,
+            )
+        }
+        FundMode::Extend { seconds } => (
+            emission_amount(pool, reward_a_rate, seconds).ok_or(ErrorCode::MathOverflow)?,
+            emission_amount(pool, reward_b_rate, seconds).ok_or(ErrorCode::MathOverflow)?,
+        ),
+    };
+    if (amount_a > 0 && distributed_a == 0) || (amount_b > 0 && distributed_b == 0) {
+        return Err(ErrorCode::FundingTooSmall.into());
+    }
+    let undistributable_a = amount_a.saturating_sub(distributed_a);
+    let undistributable_b = amount_b.saturating_sub(distributed_b);
+    pool.reward_a_obligation = pool
+        .reward_a_obligation
+        .checked_add(amount_a)
+        .ok_or(ErrorCode::MathOverflow)?;
+    pool.reward_b_obligation = pool
+        .reward_b_obligation
+        .checked_add(amount_b)
+        .ok_or(ErrorCode::MathOverflow)?;
+    pool.reward_a_rate_u128 = reward_a_rate;
+    pool.reward_b_rate_u128 = reward_b_rate;
+
+    // this is to avoid breaking old integrator
+    if let Ok(reward_rate) = u64::try_from(reward_a_rate) {
+        pool._reward_a_rate = reward_rate;
+    }
+    if let Ok(reward_rate) = u64::try_from(reward_b_rate) {
+        pool._reward_b_rate = reward_rate;
+    }
+
+    pool.last_update_time = current_time;
+    if let FundMode::Reset { .. } = mode {
+        pool.reward_period_start = current_time;
+    }
+    pool.reward_duration_end = match mode {
+        FundMode::Reset { .. } => current_time.checked_add(pool.reward_duration).unwrap(),
+        FundMode::TopUp { .. } => pool.reward_duration_end,
+        FundMode::Extend { seconds } => pool
+            .reward_duration_end
+            .checked_add(seconds)
+            .ok_or(ErrorCode::MathOverflow)?,
+    };
+    // The whole schedule must be computable for the rewards to be emitted
+    if pool.has_emission_schedule() {
+        emission_between(pool, pool.reward_period_start, pool.reward_duration_end)
+            .ok_or(ErrorCode::MathOverflow)?;
+    }
+    Ok((undistributable_a, undistributable_b))
+}
+
+/// Transfers the protocol fee share of a claimed reward amount from the reward vault to the treasury.
+/// Returns the fee amount.
+#[allow(clippy::too_many_arguments)]
+#[allow(clippy::result_large_err)]
+fn pay_protocol_fee<'info>(
+    pool: &Account<'info, Pool>,
+    global_config: &Option<Box<Account<'info, GlobalConfig>>>,
+    reward_vault: &Account<'info, TokenAccount>,
+    treasury_account: &Option<Box<Account<'info, TokenAccount>>>,
+    token_program: &Program<'info, Token>,
+    pool_signer: &[&[&[u8]]],
+    reward_amount: u64,
+) -> Result<u64> {
+    let fee = protocol_fee(reward_amount, pool.protocol_fee_bps).ok_or(ErrorCode::MathOverflow)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+    let global_config = global_config
+        .as_ref()
+        .ok_or(ErrorCode::MissingTreasuryAccount)?;
+    let treasury_account = treasury_account
+        .as_ref()
+        .ok_or(ErrorCode::MissingTreasuryAccount)?;
+    if treasury_account.owner != global_config.treasury
+        || treasury_account.mint != reward_vault.mint
+    {
+        return Err(ErrorCode::InvalidTreasuryAccount.into());
+    }
+
+    let cpi_ctx = CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        token::Transfer {
+            from: reward_vault.to_account_info(),
+            to: treasury_account.to_account_info(),
+            authority: pool.to_account_info(),
+        },
+        pool_signer,
+    );
+    token::transfer(cpi_ctx, fee)?;
+    Ok(fee)
+}
+
+/// Lamports of the native reward vault available as rewards, above its rent exempt minimum
+#[allow(clippy::result_large_err)]
+fn native_reward_balance(native_vault: &Option<SystemAccount>) -> Result<u64> {
+    let native_vault = native_vault
+        .as_ref()
+        .ok_or(ErrorCode::MissingNativeRewardAccounts)?;
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    Ok(native_vault.lamports().saturating_sub(rent_exempt_minimum))
+}
+
+/// Transfers lamports from the funder to the native reward vault
+#[allow(clippy::result_large_err)]
+fn fund_native_reward<'info>(
+    funder: &Signer<'info>,
+    native_vault: &Option<SystemAccount<'info>>,
+    system_program: &Option<Program<'info, System>>,
+    amount: u64,
+) -> Result<()> {
+    let native_vault = native_vault
+        .as_ref()
+        .ok_or(ErrorCode::MissingNativeRewardAccounts)?;
+    let system_program = system_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingNativeRewardAccounts)?;
+    if !funder.is_writable {
+        return Err(ErrorCode::NativeRewardAccountNotWritable.into());
+    }
+    let cpi_ctx = CpiContext::new(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: funder.to_account_info(),
+            to: native_vault.to_account_info(),
+        },
+    );
+    system_program::transfer(cpi_ctx, amount)
+}
+
+/// Transfers lamports out of the native reward vault, signed by the vault PDA
+#[allow(clippy::result_large_err)]
+fn transfer_native_reward<'info>(
+    pool: &Account<'info, Pool>,
+    native_vault: &Option<SystemAccount<'info>>,
+    system_program: &Option<Program<'info, System>>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let native_vault = native_vault
+        .as_ref()
+        .ok_or(ErrorCode::MissingNativeRewardAccounts)?;
+    let system_program = system_program
+        .as_ref()
+        .ok_or(ErrorCode::MissingNativeRewardAccounts)?;
+    if !to.is_writable {
+        return Err(ErrorCode::NativeRewardAccountNotWritable.into());
+    }
+    let pool_key = pool.key();
+    let seeds = &[
+        NATIVE_REWARD_VAULT_SEED,
+        pool_key.as_ref(),
+        &[pool.native_vault_bump],
+    ];
+    let native_vault_signer = &[&seeds[..]];
+    let cpi_ctx = CpiContext::new_with_signer(
+        system_program.to_account_info(),
+        system_program::Transfer {
+            from: native_vault.to_account_info(),
+            to: to.clone(),
+        },
+        native_vault_signer,
+    );
+    system_program::transfer(cpi_ctx, amount)
+}
+
+/// Transfers the protocol fee share of a claimed native reward from the native reward vault to the treasury.
+/// Returns the fee amount.
+#[allow(clippy::result_large_err)]
+fn pay_native_protocol_fee<'info>(
+    pool: &Account<'info, Pool>,
+    global_config: &Option<Box<Account<'info, GlobalConfig>>>,
+    native_vault: &Option<SystemAccount<'info>>,
+    treasury: &Option<SystemAccount<'info>>,
+    system_program: &Option<Program<'info, System>>,
+    reward_amount: u64,
+) -> Result<u64> {
+    let fee = protocol_fee(reward_amount, pool.protocol_fee_bps).ok_or(ErrorCode::MathOverflow)?;
+    if fee == 0 {
+        return Ok(0);
+    }
+    let global_config = global_config
+        .as_ref()
+        .ok_or(ErrorCode::MissingTreasuryAccount)?;
+    let treasury = treasury.as_ref().ok_or(ErrorCode::MissingTreasuryAccount)?;
+    if treasury.key() != global_config.treasury {
+        return Err(ErrorCode::InvalidTreasuryAccount.into());
+    }
+    transfer_native_reward(
+        pool,
+        native_vault,
+        system_program,
+        &treasury.to_account_info(),
+        fee,
+    )?;
+    Ok(fee)
+}
+
+/// Adds the pool to the registry of its staking mint, growing the registry account to fit it.
+/// Returns whether the pool is listed, which is not the case when the registry is full.
+#[allow(clippy::result_large_err)]
+fn add_pool_to_registry<'info>(
+    registry: &mut Account<'info, PoolRegistry>,
+    pool: Pubkey,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<bool> {
+    if registry.pools.contains(&pool) {
+        return Ok(true);
+    }
+    if registry.pools.len() >= MAX_REGISTRY_POOLS {
+        return Ok(false);
+    }
+    let new_len = registry
+        .pools
+        .len()
+        .checked_add(1)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let new_space = PoolRegistry::space(new_len);
+    let registry_info = registry.to_account_info();
+    let lamports_needed = Rent::get()?
+        .minimum_balance(new_space)
+        .saturating_sub(registry_info.lamports());
+    if lamports_needed > 0 {
+        let cpi_ctx = CpiContext::new(
+            system_program.to_account_info(),
+            system_program::Transfer {
+                from: payer.to_account_info(),
+                to: registry_info.clone(),
+            },
+        );
+        system_program::transfer(cpi_ctx, lamports_needed)?;
+    }
+    registry_info.realloc(new_space, false)?;
+    registry.pools.push(pool);
+    Ok(true)
+}
+
+/// Removes the pool from the registry of its staking mint, shrinking the registry account and refunding the rent excess
+#[allow(clippy::result_large_err)]
+fn remove_pool_from_registry<'info>(
+    registry: &mut Account<'info, PoolRegistry>,
+    pool: Pubkey,
+    refundee: &AccountInfo<'info>,
+) -> Result<()> {
+    let idx = match registry.pools.iter().position(|x| *x == pool) {
+        Some(idx) => idx,
+        None => return Ok(()),
+    };
+    registry.pools.swap_remove(idx);
+    let new_space = PoolRegistry::space(registry.pools.len());
+    let registry_info = registry.to_account_info();
+    registry_info.realloc(new_space, false)?;
+
+    let excess = registry_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(new_space));
+    if excess > 0 {
+        let registry_lamports = registry_info
+            .lamports()
+            .checked_sub(excess)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let refundee_lamports = refundee
+            .lamports()
+            .checked_add(excess)
+            .ok_or(ErrorCode::MathOverflow)?;
+        **registry_info.try_borrow_mut_lamports()? = registry_lamports;
+        **refundee.try_borrow_mut_lamports()? = refundee_lamports;
+    }
+    Ok(())
+}
+
+/// Forfeits the pending rewards of a user withdrawing before the minimum stake duration of the pool.
+/// They stay in the reward vaults as surplus, no longer owed. Rewards must be updated before.
+#[allow(clippy::result_large_err)]
+fn forfeit_early_rewards(pool: &mut Pool, user: &mut User, current_time: u64) -> Result<()> {
+    if current_time >= pool.rewards_claimable_at(user) {
+        return Ok(());
+    }
+    let amount_a = user.reward_a_per_token_pending;
+    let amount_b = user.reward_b_per_token_pending;
+    if amount_a == 0 && amount_b == 0 {
+        return Ok(());
+    }
+    user.reward_a_per_token_pending = 0;
+    user.reward_b_per_token_pending = 0;
+    pool.reward_a_obligation = pool.reward_a_obligation.saturating_sub(amount_a);
+    pool.reward_b_obligation = pool.reward_b_obligation.saturating_sub(amount_b);
+    emit!(EventForfeitRewards {
+        owner: user.owner,
+        amount_a,
+        amount_b,
+    });
+    Ok(())
+}
+
+/// Replaces the hub balance of the user mirrored in a pool attached to the stake hub.
+/// Rewards are updated with the previous balance first.
+#[allow(clippy::result_large_err)]
+fn mirror_hub_balance(
+    pool: &mut Box<Account<Pool>>,
+    user: &mut Box<Account<User>>,
+    previous_balance: u64,
+    balance: u64,
+) -> Result<()> {
+    update_rewards(pool, Some(user), pool.get_total_effective_stake())?;
+    let current_time: u64 = clock::Clock::get()?
+        .unix_timestamp
+        .try_into()
+        .map_err(|_| ErrorCode::MathOverflow)?;
+    if balance > previous_balance {
+        user.last_deposit_at = current_time;
+        user.min_stake_duration = pool.min_stake_duration;
+        user.referral_fee_bps = pool.referral_fee_bps;
+    } else {
+        if user.is_vote_locked(current_time) {
+            return Err(ErrorCode::StakeVoteLocked.into());
+        }
+        forfeit_early_rewards(pool, user, current_time)?;
+    }
+    user.balance_staked = user
+        .balance_staked
+        .checked_sub(previous_balance)
+        .and_then(|staked| staked.checked_add(balance))
+        .ok_or(ErrorCode::MathOverflow)?;
+    pool.total_staked = pool
+        .total_staked
+        .checked_sub(previous_balance)
+        .and_then(|staked| staked.checked_add(balance))
+        .ok_or(ErrorCode::MathOverflow)?;
+    sync_boost_stake(pool, user)
+}
+
+/// Mirrors the new hub balance of the user in every pool it joined.
+/// Accounts hold the pool and user accounts of each joined pool, in slot order.
+/// Pools or users closed since joining are left.
+#[allow(clippy::result_large_err)]
+fn mirror_hub_balance_in_joined_pools(
+    program_id: &Pubkey,
+    stake_hub: &StakeHub,
+    hub_user: &mut HubUser,
+    accounts: &[AccountInfo],
+    previous_balance: u64,
+    balance: u64,
+) -> Result<()> {
+    let mut accounts = accounts.iter();
+    for slot in 0..MAX_HUB_POOLS {
+        let joined_pool = hub_user.joined_pools[slot];
+        if joined_pool == Pubkey::default() {
+            continue;
+        }
+        let (pool_info, user_info) = match (accounts.next(), accounts.next()) {
+            (Some(pool_info), Some(user_info)) => (pool_info, user_info),
+            _ => return Err(ErrorCode::InvalidHubPoolAccounts.into()),
+        };
+        let (user_address, _bump) = Pubkey::find_program_address(
+            &[hub_user.owner.as_ref(), pool_info.key.as_ref()],
+            program_id,
+        );
+        if *pool_info.key != joined_pool || *user_info.key != user_address {
+            return Err(ErrorCode::InvalidHubPoolAccounts.into());
+        }
+        // Closing and detaching require no stake, so a closed or detached pool no longer mirrors any balance
+        if pool_info.data_is_empty()
+            || user_info.data_is_empty()
+            || stake_hub.pools[slot] != joined_pool
+        {
+            hub_user.joined_pools[slot] = Pubkey::default();
+            continue;
+        }
+        let mut pool = Box::new(Account::<Pool>::try_from(pool_info)?);
+        let mut user = Box::new(Account::<User>::try_from(user_info)?);
+        mirror_hub_balance(&mut pool, &mut user, previous_balance, balance)?;
+        pool.exit(program_id)?;
+        user.exit(program_id)?;
+    }
+    if accounts.next().is_some() {
+        return Err(ErrorCode::InvalidHubPoolAccounts.into());
+    }
+    Ok(())
+}
+
+/// Stops mirroring the hub balance of the user in the pool of the given hub slot
+#[allow(clippy::result_large_err)]
+fn leave_hub_pool_slot(
+    hub_user: &mut HubUser,
+    pool: &mut Box<Account<Pool>>,
+    user: &mut Box<Account<User>>,
+    slot: usize,
+) -> Result<()> {
+    if hub_user.joined_pools[slot] != pool.key() {
+        return Err(ErrorCode::HubPoolNotJoined.into());
+    }
+    mirror_hub_balance(pool, user, hub_user.balance_staked, 0)?;
+    hub_user.joined_pools[slot] = Pubkey::default();
+    emit!(EventLeaveHubPool {
+        owner: hub_user.owner,
+        pool: pool.key(),
+        amount: hub_user.balance_staked,
+    });
+    Ok(())
+}
+
 /// The min of current time and reward duration end, such that after the pool reward
 /// period ends, this always returns the pool end time
 fn last_time_reward_applicable(reward_duration_end: u64) -> u64 {
@@ -68,10 +682,12 @@ fn last_time_reward_applicable(reward_duration_end: u64) -> u64 {
 
 /// Dual farming program
 #[program]
+#[allow(clippy::result_large_err)]
 pub mod farming {
     use super::*;
     pub const MIN_DURATION: u64 = 1;
-    /// Initializes a new pool. Able to create pool with single reward by passing the same Mint account for reward_a_mint and reward_a_mint
+    /// Initializes a new pool. Able to create pool with single reward by passing the same Mint account for reward_a_mint and reward_b_mint,
+    /// in which case the reward B vault must be omitted and the reward A vault is recorded as reward B vault.
     pub fn initialize_pool(ctx: Context<InitializePool>, reward_duration: u64) -> Result<()> {
         if reward_duration < MIN_DURATION {
             return Err(ErrorCode::DurationTooShort.into());
@@ -84,8 +700,33 @@ pub mod farming {
         pool.reward_a_mint = ctx.accounts.reward_a_mint.key();
         pool.reward_a_vault = ctx.accounts.reward_a_vault.key();
         pool.reward_b_mint = ctx.accounts.reward_b_mint.key();
-        pool.reward_b_vault = ctx.accounts.reward_b_vault.key();
+        if pool.reward_a_mint == pool.reward_b_mint {
+            if ctx.accounts.reward_b_vault.is_some() {
+                return Err(ErrorCode::UnexpectedRewardVault.into());
+            }
+            pool.reward_mode = RewardMode::Single;
+            pool.reward_b_vault = pool.reward_a_vault;
+        } else {
+            pool.reward_mode = RewardMode::Dual;
+            pool.reward_b_vault = ctx
+                .accounts
+                .reward_b_vault
+                .as_ref()
+                .ok_or(ErrorCode::MissingRewardVault)?
+                .key();
+        }
         pool.reward_duration = reward_duration;
+        pool.seed_reward_duration = reward_duration;
+        pool.protocol_fee_bps = ctx
+            .accounts
+            .global_config
+            .as_ref()
+            .map_or(0, |global_config| global_config.protocol_fee_bps);
+        pool.precision = pool::precision_from_decimals(
+            ctx.accounts.staking_mint.decimals,
+            ctx.accounts.reward_a_mint.decimals,
+            ctx.accounts.reward_b_mint.decimals,
+        );
         pool.total_staked = 0;
         pool.reward_duration_end = 0;
         pool.last_update_time = 0;
@@ -93,8 +734,104 @@ pub mod farming {
         pool.reward_b_per_token_stored = 0;
         pool.user_stake_count = 0;
         pool.base_key = ctx.accounts.base.key();
+        pool.unbonding_period = 0;
+        pool.total_unbonding = 0;
+        pool.total_boost_stake = 0;
+        pool.reward_obligations_tracked = true;
         // Unwrap here is safe as long as the key matches the account in the context
         pool.pool_bump = *ctx.bumps.get("pool").unwrap();
+
+        let pool_registry = &mut ctx.accounts.pool_registry;
+        if pool_registry.staking_mint == Pubkey::default() {
+            pool_registry.staking_mint = ctx.accounts.staking_mint.key();
+            pool_registry.bump = *ctx.bumps.get("pool_registry").unwrap();
+        }
+        // A full registry does not block the pool creation, the pool is just not listed
+        ctx.accounts.pool.registered = add_pool_to_registry(
+            pool_registry,
+            ctx.accounts.pool.key(),
+            &ctx.accounts.authority,
+            &ctx.accounts.system_program,
+        )?;
+        Ok(())
+    }
+
+    /// Adds a pool created before the registry existed to the registry of its staking mint. Anyone can call this.
+    pub fn register_pool(ctx: Context<RegisterPool>) -> Result<()> {
+        let pool_registry = &mut ctx.accounts.pool_registry;
+        if pool_registry.staking_mint == Pubkey::default() {
+            pool_registry.staking_mint = ctx.accounts.pool.staking_mint;
+            pool_registry.bump = *ctx.bumps.get("pool_registry").unwrap();
+        }
+        let registered = add_pool_to_registry(
+            pool_registry,
+            ctx.accounts.pool.key(),
+            &ctx.accounts.payer,
+            &ctx.accounts.system_program,
+        )?;
+        if !registered {
+            return Err(ErrorCode::PoolRegistryFull.into());
+        }
+        ctx.accounts.pool.registered = true;
+        Ok(())
+    }
+
+    /// Removes a pool from the registry of a staking mint. Only the protocol admin is able to do it.
+    /// The pool stays flagged as registered, so it is not able to register again.
+    pub fn prune_pool_registry(ctx: Context<PrunePoolRegistry>, pool: Pubkey) -> Result<()> {
+        let pool_registry = &mut ctx.accounts.pool_registry;
+        if !pool_registry.pools.contains(&pool) {
+            return Err(ErrorCode::PoolNotInRegistry.into());
+        }
+        remove_pool_from_registry(pool_registry, pool, &ctx.accounts.admin.to_account_info())?;
+        emit!(EventPrunePoolRegistry {
+            staking_mint: pool_registry.staking_mint,
+            pool,
+        });
+        Ok(())
+    }
+
+    /// Initializes the program global config. Only the program upgrade authority is able to do it.
+    pub fn initialize_global_config(
+        ctx: Context<InitializeGlobalConfig>,
+        protocol_fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        if protocol_fee_bps > MAX_PROTOCOL_FEE_BPS {
+            return Err(ErrorCode::InvalidProtocolFee.into());
+        }
+        let global_config = &mut ctx.accounts.global_config;
+        global_config.admin = ctx.accounts.admin.key();
+        global_config.protocol_fee_bps = protocol_fee_bps;
+        global_config.treasury = treasury;
+        global_config.bump = *ctx.bumps.get("global_config").unwrap();
+        emit!(EventUpdateGlobalConfig {
+            admin: global_config.admin,
+            protocol_fee_bps,
+            treasury,
+        });
+        Ok(())
+    }
+
+    /// Update the program global config. Fee changes only apply to pools created afterward.
+    pub fn update_global_config(
+        ctx: Context<UpdateGlobalConfig>,
+        admin: Pubkey,
+        protocol_fee_bps: u16,
+        treasury: Pubkey,
+    ) -> Result<()> {
+        if protocol_fee_bps > MAX_PROTOCOL_FEE_BPS {
+            return Err(ErrorCode::InvalidProtocolFee.into());
+        }
+        let global_config = &mut ctx.accounts.global_config;
+        global_config.admin = admin;
+        global_config.protocol_fee_bps = protocol_fee_bps;
+        global_config.treasury = treasury;
+        emit!(EventUpdateGlobalConfig {
+            admin,
+            protocol_fee_bps,
+            treasury,
+        });
         Ok(())
     }
 
@@ -109,6 +846,24 @@ pub mod farming {
         user.reward_b_per_token_pending = 0;
         user.balance_staked = 0;
         user.nonce = *ctx.bumps.get("user").unwrap();
+        user.compound_keeper = Pubkey::default();
+        user.balance_unbonding = 0;
+        user.unbonding_end = 0;
+        user.reward_a_earned_remainder = 0;
+        user.reward_b_earned_remainder = 0;
+        user.boost_multiplier_bps = 0;
+        user.boost_stake = 0;
+        user.boost_token_account = Pubkey::default();
+        user.stake_seconds = 0;
+        user.stake_seconds_updated_at = 0;
+        user.claim_delegate = Pubkey::default();
+        user.reward_a_destination = Pubkey::default();
+        user.reward_b_destination = Pubkey::default();
+        user.last_deposit_at = 0;
+        user.referrer = Pubkey::default();
+        user.unbonding_period = 0;
+        user.min_stake_duration = 0;
+        user.referral_fee_bps = 0;
 
         let pool = &mut ctx.accounts.pool;
         pool.user_stake_count = pool.user_stake_count.checked_add(1).unwrap();
@@ -140,14 +895,23 @@ pub mod farming {
             return Err(ErrorCode::PoolPaused.into());
         }
         let user_opt = Some(&mut ctx.accounts.user);
-        update_rewards(pool, user_opt, pool.total_staked).unwrap();
+        update_rewards(pool, user_opt, pool.get_total_effective_stake()).unwrap();
 
+        ctx.accounts.user.last_deposit_at = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        // The whole stake of the user is bound to the unbonding period, minimum stake duration and referral fee in force at its last deposit
+        ctx.accounts.user.unbonding_period = pool.unbonding_period;
+        ctx.accounts.user.min_stake_duration = pool.min_stake_duration;
+        ctx.accounts.user.referral_fee_bps = pool.referral_fee_bps;
         ctx.accounts.user.balance_staked = ctx
             .accounts
             .user
             .balance_staked
             .checked_add(amount)
             .unwrap();
+        sync_boost_stake(pool, &mut ctx.accounts.user)?;
 
         // Transfer tokens into the stake vault.
         {
@@ -176,23 +940,36 @@ pub mod farming {
         }
 
         let pool = &mut ctx.accounts.pool;
+        if pool.get_unbonding_period(&ctx.accounts.user) > 0 {
+            return Err(ErrorCode::UnbondingRequired.into());
+        }
 
         if ctx.accounts.user.balance_staked < spt_amount {
             return Err(ErrorCode::InsufficientFundWithdraw.into());
         }
 
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if ctx.accounts.user.is_vote_locked(current_time) {
+            return Err(ErrorCode::StakeVoteLocked.into());
+        }
+
         let user_opt = Some(&mut ctx.accounts.user);
-        update_rewards(pool, user_opt, pool.total_staked).unwrap();
+        update_rewards(pool, user_opt, pool.get_total_effective_stake()).unwrap();
+        forfeit_early_rewards(pool, &mut ctx.accounts.user, current_time)?;
         ctx.accounts.user.balance_staked = ctx
             .accounts
             .user
             .balance_staked
             .checked_sub(spt_amount)
             .unwrap();
+        sync_boost_stake(pool, &mut ctx.accounts.user)?;
 
         // Transfer tokens from the pool vault to user vault.
         {
-            let reward_duration = pool.reward_duration.to_be_bytes();
+            let reward_duration = pool.get_seed_reward_duration().to_be_bytes();
             let seeds = &[
                 reward_duration.as_ref(),
                 pool.staking_mint.as_ref(),
@@ -223,6 +1000,167 @@ pub mod farming {
         Ok(())
     }
 
+    /// Set the reward duration used by the next funding. Only allowed between campaigns.
+    pub fn set_reward_duration(
+        ctx: Context<SetRewardDuration>,
+        reward_duration: u64,
+    ) -> Result<()> {
+        if reward_duration < MIN_DURATION {
+            return Err(ErrorCode::DurationTooShort.into());
+        }
+        let pool = &mut ctx.accounts.pool;
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time < pool.reward_duration_end {
+            return Err(ErrorCode::CampaignActive.into());
+        }
+        // Pools created before the seed was stored derived their address from reward_duration
+        if pool.seed_reward_duration == 0 {
+            pool.seed_reward_duration = pool.reward_duration;
+        }
+        pool.reward_duration = reward_duration;
+        emit!(EventSetRewardDuration { reward_duration });
+        Ok(())
+    }
+
+    /// Set the cooldown applied to withdrawals. Zero disables unbonding, allowing direct withdraw.
+    /// A longer period only applies to stake deposited afterwards, a shorter one applies to every user.
+    pub fn set_unbonding_period(
+        ctx: Context<SetUnbondingPeriod>,
+        unbonding_period: u64,
+    ) -> Result<()> {
+        if unbonding_period > MAX_UNBONDING_PERIOD {
+            return Err(ErrorCode::UnbondingPeriodTooLong.into());
+        }
+        let pool = &mut ctx.accounts.pool;
+        pool.unbonding_period = unbonding_period;
+        Ok(())
+    }
+
+    /// Set the time the stake of a user must stay in the pool before its rewards can be claimed.
+    /// Withdrawing earlier forfeits the pending rewards. Zero disables it.
+    /// A longer duration only applies to stake deposited afterwards, a shorter one applies to every user.
+    pub fn set_min_stake_duration(
+        ctx: Context<SetMinStakeDuration>,
+        min_stake_duration: u64,
+    ) -> Result<()> {
+        if min_stake_duration > MAX_MIN_STAKE_DURATION {
+            return Err(ErrorCode::MinStakeDurationTooLong.into());
+        }
+        let pool = &mut ctx.accounts.pool;
+        pool.min_stake_duration = min_stake_duration;
+        Ok(())
+    }
+
+    /// User moves staked tokens to the unbonding balance. They stop earning rewards immediately and can be withdrawn once the unbonding period is over.
+    pub fn request_withdraw(ctx: Context<Deposit>, spt_amount: u64) -> Result<()> {
+        if spt_amount == 0 {
+            return Err(ErrorCode::AmountMustBeGreaterThanZero.into());
+        }
+
+        let pool = &mut ctx.accounts.pool;
+
+        if ctx.accounts.user.balance_staked < spt_amount {
+            return Err(ErrorCode::InsufficientFundWithdraw.into());
+        }
+
+        let user_opt = Some(&mut ctx.accounts.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake())?;
+
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if ctx.accounts.user.is_vote_locked(current_time) {
+            return Err(ErrorCode::StakeVoteLocked.into());
+        }
+        let unbonding_end = current_time
+            .checked_add(pool.get_unbonding_period(&ctx.accounts.user))
+            .ok_or(ErrorCode::MathOverflow)?;
+        forfeit_early_rewards(pool, &mut ctx.accounts.user, current_time)?;
+
+        let user = &mut ctx.accounts.user;
+        user.balance_staked = user
+            .balance_staked
+            .checked_sub(spt_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        user.balance_unbonding = user
+            .balance_unbonding
+            .checked_add(spt_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        // A new request restarts the cooldown for the whole unbonding balance
+        user.unbonding_end = unbonding_end;
+        sync_boost_stake(pool, user)?;
+
+        pool.total_staked = pool
+            .total_staked
+            .checked_sub(spt_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        pool.total_unbonding = pool
+            .total_unbonding
+            .checked_add(spt_amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        emit!(EventRequestWithdraw {
+            amount: spt_amount,
+            unbonding_end,
+        });
+        Ok(())
+    }
+
+    /// User withdraws the whole unbonding balance after the unbonding period.
+    pub fn complete_withdraw(ctx: Context<Deposit>) -> Result<()> {
+        let amount = ctx.accounts.user.balance_unbonding;
+        if amount == 0 {
+            return Err(ErrorCode::AmountMustBeGreaterThanZero.into());
+        }
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time < ctx.accounts.user.unbonding_end {
+            return Err(ErrorCode::UnbondingNotFinished.into());
+        }
+
+        let user = &mut ctx.accounts.user;
+        user.balance_unbonding = 0;
+        user.unbonding_end = 0;
+
+        let pool = &mut ctx.accounts.pool;
+        pool.total_unbonding = pool
+            .total_unbonding
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        // Transfer tokens from the pool vault to user vault.
+        let reward_duration = pool.get_seed_reward_duration().to_be_bytes();
+        let seeds = &[
+            reward_duration.as_ref(),
+            pool.staking_mint.as_ref(),
+            pool.reward_a_mint.as_ref(),
+            pool.reward_b_mint.as_ref(),
+            pool.base_key.as_ref(),
+            &[pool.pool_bump],
+        ];
+        let pool_signer = &[&seeds[..]];
+
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.staking_vault.to_account_info(),
+                to: ctx.accounts.stake_from_account.to_account_info(),
+                authority: pool.to_account_info(),
+            },
+            pool_signer,
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        emit!(EventCompleteWithdraw { amount });
+        Ok(())
+    }
+
     /// Authorize additional funders for the pool
     pub fn authorize_funder(ctx: Context<FunderChange>, funder_to_add: Pubkey) -> Result<()> {
         if funder_to_add == ctx.accounts.pool.authority.key() {
@@ -261,408 +1199,3367 @@ pub mod farming {
         Ok(())
     }
 
-    /// Fund the pool with rewards.  This resets the clock on the end date, pushing it out to the set duration. And, linearly redistributes remaining rewards.
-    pub fn fund(ctx: Context<Fund>, amount_a: u64, amount_b: u64) -> Result<()> {
+    /// Pays the flagged rewards in native SOL held by the native reward vault instead of the reward vaults.
+    /// The reward mint must be the native mint and the pool must not have been funded yet.
+    pub fn enable_native_reward(
+        ctx: Context<EnableNativeReward>,
+        reward_a: bool,
+        reward_b: bool,
+    ) -> Result<()> {
         let pool = &mut ctx.accounts.pool;
-
-        //can't compare using reward_vault because it is PDA. The PDA seed contain different prefix
-        //if mint are the same, we just use a
-        if amount_b > 0 && ctx.accounts.reward_a_vault.mint == ctx.accounts.reward_b_vault.mint {
-            return Err(ErrorCode::SingleDepositTokenBCannotBeFunded.into());
-        }
-
-        update_rewards(pool, None, pool.total_staked).unwrap();
-
-        let (reward_a_rate, reward_b_rate) = rate_after_funding(pool, amount_a, amount_b)?;
-        pool.reward_a_rate_u128 = reward_a_rate;
-        pool.reward_b_rate_u128 = reward_b_rate;
-
-        // this is to avoid breaking old integrator
-        if let Ok(reward_rate) = u64::try_from(reward_a_rate) {
-            pool._reward_a_rate = reward_rate;
-        }
-        if let Ok(reward_rate) = u64::try_from(reward_b_rate) {
-            pool._reward_b_rate = reward_rate;
+        if pool.reward_duration_end != 0
+            || (reward_a && pool.reward_a_mint != spl_token::native_mint::ID)
+            || (reward_b && pool.reward_b_mint != spl_token::native_mint::ID)
+        {
+            return Err(ErrorCode::InvalidNativeReward.into());
         }
+        pool.reward_a_native = reward_a;
+        pool.reward_b_native = reward_b;
+        pool.native_vault_bump = *ctx.bumps.get("native_vault").unwrap();
 
-        // Transfer reward A tokens into the A vault.
-        if amount_a > 0 {
+        // The vault is created by funding it with its rent exempt minimum, which is never paid out as rewards
+        let lamports_needed = Rent::get()?
+            .minimum_balance(0)
+            .saturating_sub(ctx.accounts.native_vault.lamports());
+        if lamports_needed > 0 {
             let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: ctx.accounts.from_a.to_account_info(),
-                    to: ctx.accounts.reward_a_vault.to_account_info(),
-                    authority: ctx.accounts.funder.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.authority.to_account_info(),
+                    to: ctx.accounts.native_vault.to_account_info(),
                 },
             );
-
-            token::transfer(cpi_ctx, amount_a)?;
+            system_program::transfer(cpi_ctx, lamports_needed)?;
         }
 
-        // Transfer reward B tokens into the B vault.
-        if amount_b > 0 {
-            let cpi_ctx = CpiContext::new(
-                ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: ctx.accounts.from_b.to_account_info(),
-                    to: ctx.accounts.reward_b_vault.to_account_info(),
-                    authority: ctx.accounts.funder.to_account_info(),
-                },
-            );
+        emit!(EventEnableNativeReward { reward_a, reward_b });
+        Ok(())
+    }
 
-            token::transfer(cpi_ctx, amount_b)?;
-        }
+    /// Fund the pool with rewards.  This resets the clock on the end date, pushing it out to the set duration. And, linearly redistributes remaining rewards.
+    pub fn fund(ctx: Context<Fund>, amount_a: u64, amount_b: u64) -> Result<()> {
+        fund_with_mode(ctx, FundMode::Reset { amount_a, amount_b })
+    }
 
-        let current_time = clock::Clock::get()
+    /// Fund the pool with rewards using the given [FundMode].
+    pub fn fund_with_mode(ctx: Context<Fund>, mode: FundMode) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let current_time: u64 = clock::Clock::get()
             .unwrap()
             .unix_timestamp
             .try_into()
             .unwrap();
-        pool.last_update_time = current_time;
-        pool.reward_duration_end = current_time.checked_add(pool.reward_duration).unwrap();
 
-        emit!(EventFund { amount_a, amount_b });
+        // Top up and extension only make sense on a running campaign
+        if !matches!(mode, FundMode::Reset { .. }) && current_time >= pool.reward_duration_end {
+            return Err(ErrorCode::CampaignNotActive.into());
+        }
+        // Schedules are laid over a full reward period
+        if !matches!(mode, FundMode::Reset { .. }) && pool.has_emission_schedule() {
+            return Err(ErrorCode::EmissionScheduleRequiresReset.into());
+        }
+
+        let (amount_a, amount_b) = match mode {
+            FundMode::Reset { amount_a, amount_b } | FundMode::TopUp { amount_a, amount_b } => {
+                (amount_a, amount_b)
+            }
+            FundMode::Extend { seconds } => {
+                funding_for_extension(pool, seconds).ok_or(ErrorCode::MathOverflow)?
+            }
+        };
+
+        if amount_b > 0 && pool.is_single_reward() {
+            return Err(ErrorCode::SingleDepositTokenBCannotBeFunded.into());
+        }
+
+        let (undistributable_a, undistributable_b) =
+            apply_funding(pool, mode, amount_a, amount_b, current_time)?;
+
+        // Transfer reward A tokens into the A vault, or lamports into the native vault.
+        if amount_a > 0 {
+            if pool.reward_a_native {
+                fund_native_reward(
+                    &ctx.accounts.funder,
+                    &ctx.accounts.native_vault,
+                    &ctx.accounts.system_program,
+                    amount_a,
+                )?;
+            } else {
+                let from_a = ctx
+                    .accounts
+                    .from_a
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+                let cpi_ctx = CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: from_a.to_account_info(),
+                        to: ctx.accounts.reward_a_vault.to_account_info(),
+                        authority: ctx.accounts.funder.to_account_info(),
+                    },
+                );
+
+                token::transfer(cpi_ctx, amount_a)?;
+            }
+        }
+
+        // Transfer reward B tokens into the B vault, or lamports into the native vault.
+        if amount_b > 0 {
+            if pool.reward_b_native {
+                fund_native_reward(
+                    &ctx.accounts.funder,
+                    &ctx.accounts.native_vault,
+                    &ctx.accounts.system_program,
+                    amount_b,
+                )?;
+            } else {
+                let from_b = ctx
+                    .accounts
+                    .from_b
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+                let cpi_ctx = CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: from_b.to_account_info(),
+                        to: ctx.accounts.reward_b_vault.to_account_info(),
+                        authority: ctx.accounts.funder.to_account_info(),
+                    },
+                );
+
+                token::transfer(cpi_ctx, amount_b)?;
+            }
+        }
+
+        emit!(EventFund {
+            amount_a,
+            amount_b,
+            undistributable_a,
+            undistributable_b,
+        });
+        Ok(())
+    }
+
+    /// Fund the pool with the rewards held above the reward obligations, such as tokens sent directly to the reward vaults,
+    /// as a [FundMode::Reset] funding of those amounts.
+    pub fn sync_rewards(ctx: Context<SyncRewards>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        if !pool.reward_obligations_tracked {
+            return Err(ErrorCode::RewardObligationsUntracked.into());
+        }
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+
+        // Release the rewards no longer owed before measuring the surplus
+        update_rewards(pool, None, pool.get_total_effective_stake())?;
+
+        let native_balance = if pool.reward_a_native || pool.reward_b_native {
+            native_reward_balance(&ctx.accounts.native_vault)?
+        } else {
+            0
+        };
+        let balance_a = if pool.reward_a_native {
+            native_balance
+        } else {
+            ctx.accounts.reward_a_vault.amount
+        };
+        let amount_a = balance_a.saturating_sub(pool.reward_a_obligation);
+        // Single reward pools only distribute reward A
+        let amount_b = if pool.is_single_reward() {
+            0
+        } else {
+            let balance_b = if pool.reward_b_native {
+                native_balance
+            } else {
+                ctx.accounts.reward_b_vault.amount
+            };
+            balance_b.saturating_sub(pool.reward_b_obligation)
+        };
+        if amount_a == 0 && amount_b == 0 {
+            return Err(ErrorCode::NoRewardSurplus.into());
+        }
+
+        let (undistributable_a, undistributable_b) = apply_funding(
+            pool,
+            FundMode::Reset { amount_a, amount_b },
+            amount_a,
+            amount_b,
+            current_time,
+        )?;
+
+        emit!(EventFund {
+            amount_a,
+            amount_b,
+            undistributable_a,
+            undistributable_b,
+        });
         Ok(())
     }
 
     /// User claim rewards
     pub fn claim(ctx: Context<ClaimReward>) -> Result<()> {
+        ctx.accounts.claim_pending_rewards()?;
+        Ok(())
+    }
+
+    /// Claim pending rewards from many pools at once.
+    /// Remaining accounts hold one group of [ClaimReward](/dual_farming/instruction/struct.ClaimReward.html)
+    /// accounts per pool, ordered as for the claim instruction, with the program id for absent optional accounts.
+    /// Every group is claimed by the caller, as the owner or as the claim delegate.
+    /// All or nothing: when the claim of any group fails, the instruction fails and no pool is claimed.
+    pub fn claim_many<'info>(ctx: Context<'_, '_, '_, 'info, ClaimMany<'info>>) -> Result<()> {
+        let caller = ctx.accounts.caller.key();
+        let mut remaining_accounts = ctx.remaining_accounts;
+        if remaining_accounts.is_empty() {
+            return Err(ErrorCode::InvalidClaimManyAccounts.into());
+        }
+        for _ in 0..MAX_CLAIM_MANY_POOLS {
+            if remaining_accounts.is_empty() {
+                break;
+            }
+            let mut bumps = BTreeMap::new();
+            let mut reallocs = BTreeSet::new();
+            let mut claim = ClaimReward::try_accounts(
+                ctx.program_id,
+                &mut remaining_accounts,
+                &[],
+                &mut bumps,
+                &mut reallocs,
+            )?;
+            let delegate = claim.delegate.as_ref().map(|delegate| delegate.key());
+            if claim.owner.key() != caller && delegate != Some(caller) {
+                return Err(ErrorCode::InvalidClaimAuthority.into());
+            }
+            let (amount_a, amount_b) = claim.claim_pending_rewards()?;
+            claim.exit(ctx.program_id)?;
+            emit!(EventClaimManyResult {
+                pool: claim.pool.key(),
+                owner: claim.owner.key(),
+                amount_a,
+                amount_b,
+            });
+        }
+        if !remaining_accounts.is_empty() {
+            return Err(ErrorCode::InvalidClaimManyAccounts.into());
+        }
+        Ok(())
+    }
+
+    /// Transfer the whole staked balance to another owner. Pending rewards are paid to the current owner first.
+    pub fn transfer_position(ctx: Context<TransferPosition>) -> Result<()> {
+        if !ctx.accounts.claim.owner.is_signer {
+            return Err(ErrorCode::InvalidClaimAuthority.into());
+        }
+        let recipient = ctx.accounts.recipient.key();
+        if recipient == ctx.accounts.claim.owner.key() {
+            return Err(ErrorCode::InvalidPositionRecipient.into());
+        }
+        if ctx.accounts.claim.pool.is_attached_to_hub() {
+            return Err(ErrorCode::PoolAttachedToHub.into());
+        }
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if ctx.accounts.claim.user.is_vote_locked(current_time) {
+            return Err(ErrorCode::StakeVoteLocked.into());
+        }
+        ctx.accounts.claim.claim_pending_rewards()?;
+
+        let amount = ctx.accounts.claim.user.balance_staked;
+        if amount == 0 {
+            return Err(ErrorCode::AmountMustBeGreaterThanZero.into());
+        }
+
+        let pool = &mut ctx.accounts.claim.pool;
+        let recipient_user = &mut ctx.accounts.recipient_user;
+        if recipient_user.owner == Pubkey::default() {
+            recipient_user.pool = pool.key();
+            recipient_user.owner = recipient;
+            recipient_user.nonce = *ctx.bumps.get("recipient_user").unwrap();
+            pool.user_stake_count = pool
+                .user_stake_count
+                .checked_add(1)
+                .ok_or(ErrorCode::MathOverflow)?;
+        }
+        update_rewards(pool, Some(recipient_user), pool.get_total_effective_stake())?;
+
+        recipient_user.last_deposit_at = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        // The transferred stake keeps the unbonding period, minimum stake duration and referral fee of the sender
+        recipient_user.unbonding_period = recipient_user
+            .unbonding_period
+            .max(ctx.accounts.claim.user.unbonding_period);
+        recipient_user.min_stake_duration = recipient_user
+            .min_stake_duration
+            .max(ctx.accounts.claim.user.min_stake_duration);
+        recipient_user.referral_fee_bps = recipient_user
+            .referral_fee_bps
+            .max(ctx.accounts.claim.user.referral_fee_bps);
+        recipient_user.balance_staked = recipient_user
+            .balance_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        ctx.accounts.claim.user.balance_staked = 0;
+        sync_boost_stake(pool, recipient_user)?;
+        sync_boost_stake(pool, &mut ctx.accounts.claim.user)?;
+
+        emit!(EventPositionSent {
+            owner: ctx.accounts.claim.owner.key(),
+            recipient,
+            amount,
+        });
+        emit!(EventPositionReceived {
+            owner: recipient,
+            sender: ctx.accounts.claim.owner.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// Set the keeper allowed to compound rewards on behalf of the user. Pass the default pubkey to revoke.
+    pub fn set_compound_keeper(ctx: Context<SetCompoundKeeper>, keeper: Pubkey) -> Result<()> {
+        let user = &mut ctx.accounts.user;
+        user.compound_keeper = keeper;
+        emit!(EventSetCompoundKeeper { keeper });
+        Ok(())
+    }
+
+    /// Set the delegate allowed to claim rewards on behalf of the user and the token accounts rewards are paid to.
+    /// Pass the default pubkey to revoke the delegate or to unset a destination. Native rewards are always paid to the owner.
+    pub fn set_claim_delegate(
+        ctx: Context<SetClaimDelegate>,
+        delegate: Pubkey,
+        reward_a_destination: Pubkey,
+        reward_b_destination: Pubkey,
+    ) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        if (pool.reward_a_native && reward_a_destination != Pubkey::default())
+            || (pool.reward_b_native && reward_b_destination != Pubkey::default())
+        {
+            return Err(ErrorCode::InvalidRewardDestination.into());
+        }
+        let user = &mut ctx.accounts.user;
+        user.claim_delegate = delegate;
+        user.reward_a_destination = reward_a_destination;
+        user.reward_b_destination = reward_b_destination;
+        emit!(EventSetClaimDelegate {
+            owner: user.owner,
+            delegate,
+            reward_a_destination,
+            reward_b_destination,
+        });
+        Ok(())
+    }
+
+    /// Set the share of the claimed rewards of referred users accrued to their referrer, in basis points.
+    /// A raised fee only applies to users depositing afterwards, a lowered fee applies to every user.
+    pub fn set_referral_fee(ctx: Context<SetReferralFee>, referral_fee_bps: u16) -> Result<()> {
+        if referral_fee_bps > MAX_REFERRAL_FEE_BPS {
+            return Err(ErrorCode::InvalidReferralFee.into());
+        }
         let pool = &mut ctx.accounts.pool;
+        pool.referral_fee_bps = referral_fee_bps;
+        emit!(EventSetReferralFee { referral_fee_bps });
+        Ok(())
+    }
 
-        let user_opt = Some(&mut ctx.accounts.user);
-        update_rewards(pool, user_opt, pool.total_staked).unwrap();
+    /// Set the shape of the emission of the next fundings. Only allowed between campaigns.
+    /// Scheduled pools can only be funded with [FundMode::Reset].
+    pub fn set_emission_schedule(
+        ctx: Context<SetEmissionSchedule>,
+        schedule: EmissionSchedule,
+    ) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time < pool.reward_duration_end {
+            return Err(ErrorCode::CampaignActive.into());
+        }
+        // Settle the last campaign along the schedule it ran with
+        update_rewards(pool, None, pool.get_total_effective_stake())?;
+
+        let mut emission_steps = [EmissionStep::default(); MAX_EMISSION_STEPS];
+        let (emission_curve, decay_start_weight, decay_end_weight) = match &schedule {
+            EmissionSchedule::Flat => (EmissionCurve::Flat, 0, 0),
+            EmissionSchedule::LinearDecay {
+                start_weight,
+                end_weight,
+            } => {
+                if *start_weight == 0 {
+                    return Err(ErrorCode::InvalidEmissionSchedule.into());
+                }
+                (EmissionCurve::LinearDecay, *start_weight, *end_weight)
+            }
+            EmissionSchedule::Stepwise { steps } => {
+                // Steps start at the beginning of the period with a weight, at strictly increasing offsets
+                let valid = match steps.first() {
+                    Some(first) => first.start_offset == 0 && first.weight > 0,
+                    None => false,
+                };
+                if !valid
+                    || steps.len() > MAX_EMISSION_STEPS
+                    || steps
+                        .windows(2)
+                        .any(|pair| pair[0].start_offset >= pair[1].start_offset)
+                {
+                    return Err(ErrorCode::InvalidEmissionSchedule.into());
+                }
+                emission_steps[..steps.len()].copy_from_slice(steps);
+                (EmissionCurve::Stepwise, 0, 0)
+            }
+        };
+        pool.emission_curve = emission_curve;
+        pool.decay_start_weight = decay_start_weight;
+        pool.decay_end_weight = decay_end_weight;
+        pool.emission_steps = emission_steps;
+        emit!(EventSetEmissionSchedule { schedule });
+        Ok(())
+    }
+
+    /// Create the account accruing the referral rewards of a referrer in a pool
+    pub fn create_referrer(ctx: Context<CreateReferrer>) -> Result<()> {
+        let referrer = &mut ctx.accounts.referrer;
+        referrer.pool = ctx.accounts.pool.key();
+        referrer.owner = ctx.accounts.owner.key();
+        referrer.reward_a_accrued = 0;
+        referrer.reward_b_accrued = 0;
+        referrer.bump = *ctx.bumps.get("referrer").unwrap();
+        Ok(())
+    }
+
+    /// User records the referrer it was referred by. It can only be set once, the referral outlives the user account.
+    pub fn set_referrer(ctx: Context<SetReferrer>) -> Result<()> {
+        let user = &mut ctx.accounts.user;
+        if user.referrer != Pubkey::default() {
+            return Err(ErrorCode::ReferrerAlreadySet.into());
+        }
+        user.referrer = ctx.accounts.referrer.owner;
+
+        let referral = &mut ctx.accounts.referral;
+        referral.pool = ctx.accounts.pool.key();
+        referral.owner = user.owner;
+        referral.referrer = user.referrer;
+        referral.bump = *ctx.bumps.get("referral").unwrap();
+        emit!(EventSetReferrer {
+            owner: user.owner,
+            referrer: user.referrer,
+        });
+        Ok(())
+    }
+
+    /// Restores the referrer recorded by the referral on a recreated user. Permissionless.
+    pub fn restore_referrer(ctx: Context<RestoreReferrer>) -> Result<()> {
+        let user = &mut ctx.accounts.user;
+        if user.referrer != Pubkey::default() {
+            return Err(ErrorCode::ReferrerAlreadySet.into());
+        }
+        user.referrer = ctx.accounts.referral.referrer;
+        emit!(EventSetReferrer {
+            owner: user.owner,
+            referrer: user.referrer,
+        });
+        Ok(())
+    }
+
+    /// Referrer claims the referral rewards accrued in a pool
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let referrer = &mut ctx.accounts.referrer;
+        let mut amount_a = referrer.reward_a_accrued;
+        let mut amount_b = referrer.reward_b_accrued;
+        referrer.reward_a_accrued = 0;
+        referrer.reward_b_accrued = 0;
+        // Accrued rewards are no longer owed once claimed, even the part the vault cannot cover
+        pool.reward_a_obligation = pool.reward_a_obligation.saturating_sub(amount_a);
+        pool.reward_b_obligation = pool.reward_b_obligation.saturating_sub(amount_b);
 
-        let reward_duration = ctx.accounts.pool.reward_duration.to_be_bytes();
+        let reward_duration = pool.get_seed_reward_duration().to_be_bytes();
         let seeds = &[
             reward_duration.as_ref(),
-            ctx.accounts.pool.staking_mint.as_ref(),
-            ctx.accounts.pool.reward_a_mint.as_ref(),
-            ctx.accounts.pool.reward_b_mint.as_ref(),
-            ctx.accounts.pool.base_key.as_ref(),
-            &[ctx.accounts.pool.pool_bump],
+            pool.staking_mint.as_ref(),
+            pool.reward_a_mint.as_ref(),
+            pool.reward_b_mint.as_ref(),
+            pool.base_key.as_ref(),
+            &[pool.pool_bump],
         ];
         let pool_signer = &[&seeds[..]];
 
-        let mut claimed_reward_a: u64 = 0;
-        let mut claimed_reward_b: u64 = 0;
-
-        if ctx.accounts.user.reward_a_per_token_pending > 0 {
-            let mut reward_amount = ctx.accounts.user.reward_a_per_token_pending;
-            let vault_balance = ctx.accounts.reward_a_vault.amount;
-
-            ctx.accounts.user.reward_a_per_token_pending = 0;
-            if vault_balance < reward_amount {
-                reward_amount = vault_balance;
-            }
-
-            if reward_amount > 0 {
+        if amount_a > 0 {
+            if pool.reward_a_native {
+                amount_a = amount_a.min(native_reward_balance(&ctx.accounts.native_vault)?);
+                transfer_native_reward(
+                    pool,
+                    &ctx.accounts.native_vault,
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.owner.to_account_info(),
+                    amount_a,
+                )?;
+            } else {
+                amount_a = amount_a.min(ctx.accounts.reward_a_vault.amount);
+                let reward_a_account = ctx
+                    .accounts
+                    .reward_a_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardTokenAccount)?;
                 let cpi_ctx = CpiContext::new_with_signer(
                     ctx.accounts.token_program.to_account_info(),
                     token::Transfer {
                         from: ctx.accounts.reward_a_vault.to_account_info(),
-                        to: ctx.accounts.reward_a_account.to_account_info(),
-                        authority: ctx.accounts.pool.to_account_info(),
+                        to: reward_a_account.to_account_info(),
+                        authority: pool.to_account_info(),
                     },
                     pool_signer,
                 );
-                token::transfer(cpi_ctx, reward_amount)?;
-                claimed_reward_a = reward_amount;
+                token::transfer(cpi_ctx, amount_a)?;
             }
         }
-
-        if ctx.accounts.user.reward_b_per_token_pending > 0 {
-            let mut reward_amount = ctx.accounts.user.reward_b_per_token_pending;
-            let vault_balance = ctx.accounts.reward_b_vault.amount;
-
-            ctx.accounts.user.reward_b_per_token_pending = 0;
-            if vault_balance < reward_amount {
-                reward_amount = vault_balance;
-            }
-
-            if reward_amount > 0 {
+        if amount_b > 0 {
+            if pool.reward_b_native {
+                amount_b = amount_b.min(native_reward_balance(&ctx.accounts.native_vault)?);
+                transfer_native_reward(
+                    pool,
+                    &ctx.accounts.native_vault,
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.owner.to_account_info(),
+                    amount_b,
+                )?;
+            } else {
+                let reward_b_vault = ctx
+                    .accounts
+                    .reward_b_vault
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardVault)?;
+                amount_b = amount_b.min(reward_b_vault.amount);
+                let reward_b_account = ctx
+                    .accounts
+                    .reward_b_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardTokenAccount)?;
                 let cpi_ctx = CpiContext::new_with_signer(
                     ctx.accounts.token_program.to_account_info(),
                     token::Transfer {
-                        from: ctx.accounts.reward_b_vault.to_account_info(),
-                        to: ctx.accounts.reward_b_account.to_account_info(),
-                        authority: ctx.accounts.pool.to_account_info(),
+                        from: reward_b_vault.to_account_info(),
+                        to: reward_b_account.to_account_info(),
+                        authority: pool.to_account_info(),
                     },
                     pool_signer,
                 );
-                token::transfer(cpi_ctx, reward_amount)?;
-                claimed_reward_b = reward_amount;
+                token::transfer(cpi_ctx, amount_b)?;
             }
         }
 
-        emit!(EventClaim {
-            amount_a: claimed_reward_a,
-            amount_b: claimed_reward_b
+        emit!(EventClaimReferralRewards {
+            owner: ctx.accounts.owner.key(),
+            amount_a,
+            amount_b,
         });
         Ok(())
     }
 
-    /// Withdraw token that mistakenly deposited to staking_vault
-    pub fn withdraw_extra_token(ctx: Context<WithdrawExtraToken>) -> Result<()> {
-        let pool = &ctx.accounts.pool;
-        let total_amount = ctx.accounts.staking_vault.amount;
-        let total_staked = pool.total_staked;
-        let withdrawable_amount = total_amount
-            .checked_sub(total_staked)
-            .ok_or(ErrorCode::MathOverflow)?;
+    /// Configure the boost given to holders of a mint or of a verified collection. A zero multiplier disables it.
+    pub fn set_boost_config(
+        ctx: Context<SetBoostConfig>,
+        boost_mint: Pubkey,
+        boost_collection: Pubkey,
+        boost_multiplier_bps: u16,
+    ) -> Result<()> {
+        if boost_multiplier_bps != 0
+            && (boost_multiplier_bps <= 10_000
+                || boost_multiplier_bps > MAX_BOOST_MULTIPLIER_BPS
+                || (boost_mint == Pubkey::default() && boost_collection == Pubkey::default()))
+        {
+            return Err(ErrorCode::InvalidBoostConfig.into());
+        }
+        let pool = &mut ctx.accounts.pool;
+        pool.boost_mint = boost_mint;
+        pool.boost_collection = boost_collection;
+        pool.boost_multiplier_bps = boost_multiplier_bps;
+        emit!(EventSetBoostConfig {
+            boost_mint,
+            boost_collection,
+            boost_multiplier_bps,
+        });
+        Ok(())
+    }
 
-        if withdrawable_amount > 0 {
-            let reward_duration = pool.reward_duration.to_be_bytes();
-            let seeds = &[
-                reward_duration.as_ref(),
-                pool.staking_mint.as_ref(),
-                pool.reward_a_mint.as_ref(),
-                pool.reward_b_mint.as_ref(),
-                pool.base_key.as_ref(),
-                &[pool.pool_bump],
-            ];
-            let pool_signer = &[&seeds[..]];
-            let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                token::Transfer {
-                    from: ctx.accounts.staking_vault.to_account_info(),
-                    to: ctx.accounts.withdraw_to_account.to_account_info(),
-                    authority: pool.to_account_info(),
-                },
-                pool_signer,
-            );
+    /// User proves it holds the boost mint or a token of the boost collection and gets the pool boost on its stake.
+    pub fn refresh_boost(ctx: Context<RefreshBoost>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        if pool.boost_multiplier_bps == 0 {
+            return Err(ErrorCode::BoostNotConfigured.into());
+        }
 
-            token::transfer(cpi_ctx, withdrawable_amount)?;
+        let boost_token_account = &ctx.accounts.boost_token_account;
+        if !is_boost_token(pool, boost_token_account, &ctx.accounts.boost_metadata) {
+            return Err(ErrorCode::InvalidBoostToken.into());
         }
 
+        let user_opt = Some(&mut ctx.accounts.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake())?;
+
+        let user = &mut ctx.accounts.user;
+        user.boost_multiplier_bps = pool.boost_multiplier_bps;
+        user.boost_token_account = boost_token_account.key();
+        sync_boost_stake(pool, user)?;
+
+        emit!(EventRefreshBoost {
+            owner: user.owner,
+            boost_multiplier_bps: user.boost_multiplier_bps,
+            boost_stake: user.boost_stake,
+        });
         Ok(())
     }
 
-    /// Closes a users stake account. Validation is done to ensure this is only allowed when the user has nothing staked and no rewards pending.
-    pub fn close_user(ctx: Context<CloseUser>) -> Result<()> {
+    /// Remove the boost of a user who no longer holds a token of the current boost mint or collection,
+    /// or when the pool boost is disabled. Re-syncs the boost of a holder to a changed pool multiplier. Anyone can call this.
+    pub fn strip_boost(ctx: Context<StripBoost>) -> Result<()> {
         let pool = &mut ctx.accounts.pool;
-        pool.user_stake_count = pool.user_stake_count.checked_sub(1).unwrap();
+        let user = &ctx.accounts.user;
+        let boost_token_account = &ctx.accounts.boost_token_account;
+
+        let token_account = if *boost_token_account.owner == token::ID {
+            TokenAccount::try_deserialize(&mut &boost_token_account.try_borrow_data()?[..])
+                .ok()
+                .filter(|token_account| token_account.owner == user.owner)
+        } else {
+            None
+        };
+        let still_held = match &token_account {
+            Some(token_account) if pool.boost_multiplier_bps != 0 => {
+                // A collection holder cannot be stripped by leaving its metadata out
+                if token_account.mint != pool.boost_mint
+                    && pool.boost_collection != Pubkey::default()
+                    && ctx.accounts.boost_metadata.is_none()
+                {
+                    return Err(ErrorCode::MissingBoostMetadata.into());
+                }
+                is_boost_token(pool, token_account, &ctx.accounts.boost_metadata)
+            }
+            _ => false,
+        };
+        if still_held && user.boost_multiplier_bps == pool.boost_multiplier_bps {
+            return Err(ErrorCode::BoostStillHeld.into());
+        }
+
+        let user_opt = Some(&mut ctx.accounts.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake())?;
+
+        let user = &mut ctx.accounts.user;
+        if still_held {
+            user.boost_multiplier_bps = pool.boost_multiplier_bps;
+            sync_boost_stake(pool, user)?;
+            emit!(EventRefreshBoost {
+                owner: user.owner,
+                boost_multiplier_bps: user.boost_multiplier_bps,
+                boost_stake: user.boost_stake,
+            });
+            return Ok(());
+        }
+        user.boost_multiplier_bps = 0;
+        user.boost_token_account = Pubkey::default();
+        sync_boost_stake(pool, user)?;
+
+        emit!(EventStripBoost { owner: user.owner });
         Ok(())
     }
 
-    /// anyone can call this
-    pub fn migrate_farming_rate(ctx: Context<MigrateFarmingRate>) -> Result<()> {
+    /// Record the current stake seconds of the user and the pool in a checkpoint owned by the authority.
+    /// The checkpoint is the start of a window read by [snapshot_stake].
+    pub fn create_stake_checkpoint(
+        ctx: Context<CreateStakeCheckpoint>,
+        checkpoint_id: u64,
+    ) -> Result<()> {
         let pool = &mut ctx.accounts.pool;
-        if pool.reward_a_rate_u128 == 0 && pool._reward_a_rate != 0 {
-            pool.reward_a_rate_u128 = pool._reward_a_rate.into();
-        }
-        if pool.reward_b_rate_u128 == 0 && pool._reward_b_rate != 0 {
-            pool.reward_b_rate_u128 = pool._reward_b_rate.into();
-        }
+        let user_opt = Some(&mut ctx.accounts.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake())?;
+
+        let checkpoint = &mut ctx.accounts.checkpoint;
+        checkpoint.user = ctx.accounts.user.key();
+        checkpoint.authority = ctx.accounts.authority.key();
+        checkpoint.checkpoint_id = checkpoint_id;
+        checkpoint.timestamp = ctx.accounts.pool.stake_seconds_updated_at;
+        checkpoint.stake_seconds = ctx.accounts.user.stake_seconds;
+        checkpoint.total_stake_seconds = ctx.accounts.pool.stake_seconds;
+        checkpoint.bump = *ctx.bumps.get("checkpoint").unwrap();
+
+        emit!(EventCreateStakeCheckpoint {
+            owner: ctx.accounts.user.owner,
+            authority: checkpoint.authority,
+            checkpoint_id,
+            timestamp: checkpoint.timestamp,
+        });
         Ok(())
     }
 
-    /// Closes a pool account. Only able to be done when there are no users staked.
-    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+    /// Closes a stake checkpoint. Only the authority of the checkpoint is able to do it.
+    pub fn close_stake_checkpoint(_ctx: Context<CloseStakeCheckpoint>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Bring the stake seconds of the user and the pool up to date and return them, with the
+    /// average balances since a stake checkpoint of the user. Other programs can read the result by CPI.
+    pub fn snapshot_stake(ctx: Context<SnapshotStake>) -> Result<StakeSnapshot> {
+        let pool = &mut ctx.accounts.pool;
+        let user_opt = Some(&mut ctx.accounts.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake())?;
+
+        let user = &ctx.accounts.user;
         let pool = &ctx.accounts.pool;
-        let reward_duration = ctx.accounts.pool.reward_duration.to_be_bytes();
-        let signer_seeds = &[
-            reward_duration.as_ref(),
-            ctx.accounts.pool.staking_mint.as_ref(),
-            ctx.accounts.pool.reward_a_mint.as_ref(),
-            ctx.accounts.pool.reward_b_mint.as_ref(),
-            ctx.accounts.pool.base_key.as_ref(),
-            &[ctx.accounts.pool.pool_bump],
-        ];
+        let (average_balance, average_total_staked) = match ctx.accounts.checkpoint.as_ref() {
+            Some(start) if start.timestamp < pool.stake_seconds_updated_at => (
+                average_balance(
+                    start.stake_seconds,
+                    user.stake_seconds,
+                    start.timestamp,
+                    pool.stake_seconds_updated_at,
+                )
+                .ok_or(ErrorCode::InvalidStakeWindow)?,
+                average_balance(
+                    start.total_stake_seconds,
+                    pool.stake_seconds,
+                    start.timestamp,
+                    pool.stake_seconds_updated_at,
+                )
+                .ok_or(ErrorCode::InvalidStakeWindow)?,
+            ),
+            Some(_) => return Err(ErrorCode::InvalidStakeWindow.into()),
+            None => (user.balance_staked, pool.total_staked),
+        };
 
-        //instead of closing these vaults, we could technically just
-        //set_authority on them. it's not very ata clean, but it'd work
-        //if size of tx is an issue, thats an approach
+        let snapshot = StakeSnapshot {
+            timestamp: pool.stake_seconds_updated_at,
+            stake_seconds: user.stake_seconds,
+            total_stake_seconds: pool.stake_seconds,
+            average_balance,
+            average_total_staked,
+        };
+        emit!(EventSnapshotStake {
+            owner: user.owner,
+            snapshot,
+        });
+        Ok(snapshot)
+    }
 
-        //close staking vault
-        let ix = spl_token::instruction::transfer(
-            &spl_token::ID,
-            ctx.accounts.staking_vault.to_account_info().key,
-            ctx.accounts.staking_refundee.to_account_info().key,
-            &ctx.accounts.pool.key(),
-            &[&ctx.accounts.pool.key()],
-            ctx.accounts.staking_vault.amount,
-        )?;
-        solana_program::program::invoke_signed(
-            &ix,
-            &[
-                ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.staking_vault.to_account_info(),
-                ctx.accounts.staking_refundee.to_account_info(),
-                ctx.accounts.pool.to_account_info(),
-            ],
-            &[signer_seeds],
+    /// Bind an spl-governance realm and governing token mint to the pool whose stake counts as voting power.
+    /// Only the realm authority is able to do it.
+    pub fn create_registrar(ctx: Context<CreateRegistrar>, vote_lock_duration: u64) -> Result<()> {
+        let realm = governance::parse_realm(&ctx.accounts.realm.try_borrow_data()?)
+            .ok_or(ErrorCode::InvalidRealm)?;
+        if realm.authority != Some(ctx.accounts.realm_authority.key()) {
+            return Err(ErrorCode::InvalidRealmAuthority.into());
+        }
+        if !realm.is_governing_token_mint(&ctx.accounts.governing_token_mint.key()) {
+            return Err(ErrorCode::InvalidRealm.into());
+        }
+
+        let registrar = &mut ctx.accounts.registrar;
+        registrar.governance_program_id = ctx.accounts.governance_program_id.key();
+        registrar.realm = ctx.accounts.realm.key();
+        registrar.governing_token_mint = ctx.accounts.governing_token_mint.key();
+        registrar.pool = ctx.accounts.pool.key();
+        registrar.vote_lock_duration = vote_lock_duration;
+        registrar.bump = *ctx.bumps.get("registrar").unwrap();
+
+        emit!(EventCreateRegistrar {
+            realm: registrar.realm,
+            governing_token_mint: registrar.governing_token_mint,
+            pool: registrar.pool,
+            vote_lock_duration,
+        });
+        Ok(())
+    }
+
+    /// Create the voter weight record of a governing token owner
+    pub fn create_voter_weight_record(ctx: Context<CreateVoterWeightRecord>) -> Result<()> {
+        let registrar = &ctx.accounts.registrar;
+        let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+        voter_weight_record.realm = registrar.realm;
+        voter_weight_record.governing_token_mint = registrar.governing_token_mint;
+        voter_weight_record.governing_token_owner = ctx.accounts.governing_token_owner.key();
+        voter_weight_record.voter_weight = 0;
+        // Expired until the first update
+        voter_weight_record.voter_weight_expiry = Some(0);
+        voter_weight_record.weight_action = None;
+        voter_weight_record.weight_action_target = None;
+        Ok(())
+    }
+
+    /// Set the voter weight of the owner to its staked balance in the registrar pool. The weight
+    /// expires after the current slot, so it must be updated in the same transaction as the
+    /// governance instruction using it. The stake is locked for the vote lock duration of the
+    /// registrar, so the same tokens cannot be moved to another voter while the vote is running.
+    pub fn update_voter_weight_record(ctx: Context<UpdateVoterWeightRecord>) -> Result<()> {
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        let vote_locked_until = current_time
+            .checked_add(ctx.accounts.registrar.vote_lock_duration)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let user = &mut ctx.accounts.user;
+        user.vote_locked_until = std::cmp::max(user.vote_locked_until, vote_locked_until);
+
+        let voter_weight = user.balance_staked;
+        let voter_weight_record = &mut ctx.accounts.voter_weight_record;
+        voter_weight_record.voter_weight = voter_weight;
+        voter_weight_record.voter_weight_expiry = Some(Clock::get()?.slot);
+        voter_weight_record.weight_action = None;
+        voter_weight_record.weight_action_target = None;
+
+        emit!(EventUpdateVoterWeight {
+            owner: voter_weight_record.governing_token_owner,
+            voter_weight,
+            vote_locked_until: ctx.accounts.user.vote_locked_until,
+        });
+        Ok(())
+    }
+
+    /// Restake pending rewards which have the same mint as the staking mint. Callable by the user owner or its keeper.
+    pub fn compound(ctx: Context<Compound>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        if pool.paused {
+            return Err(ErrorCode::PoolPaused.into());
+        }
+        // Native rewards are not held in a token vault, so they cannot be staked
+        let compound_a = pool.reward_a_mint == pool.staking_mint && !pool.reward_a_native;
+        let compound_b = pool.reward_b_mint == pool.staking_mint
+            && !pool.is_single_reward()
+            && !pool.reward_b_native;
+        if !compound_a && !compound_b {
+            return Err(ErrorCode::NoCompoundableReward.into());
+        }
+
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time < pool.rewards_claimable_at(&ctx.accounts.user) {
+            return Err(ErrorCode::MinStakeDurationNotReached.into());
+        }
+        let referral_fee_bps = if ctx.accounts.user.referrer == Pubkey::default() {
+            0
+        } else {
+            pool.get_referral_fee_bps(&ctx.accounts.user)
+        };
+        if referral_fee_bps > 0 && ctx.accounts.referrer.is_none() {
+            return Err(ErrorCode::MissingReferrerAccount.into());
+        }
+
+        let user_opt = Some(&mut ctx.accounts.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake())?;
+
+        let mut compounded_a: u64 = 0;
+        let mut compounded_b: u64 = 0;
+        if compound_a {
+            let pending = ctx.accounts.user.reward_a_per_token_pending;
+            compounded_a = std::cmp::min(pending, ctx.accounts.reward_a_vault.amount);
+            ctx.accounts.user.reward_a_per_token_pending = 0;
+            pool.reward_a_obligation = pool.reward_a_obligation.saturating_sub(pending);
+        }
+        if compound_b {
+            let pending = ctx.accounts.user.reward_b_per_token_pending;
+            compounded_b = std::cmp::min(pending, ctx.accounts.reward_b_vault.amount);
+            ctx.accounts.user.reward_b_per_token_pending = 0;
+            pool.reward_b_obligation = pool.reward_b_obligation.saturating_sub(pending);
+        }
+
+        let reward_duration = ctx.accounts.pool.get_seed_reward_duration().to_be_bytes();
+        let seeds = &[
+            reward_duration.as_ref(),
+            ctx.accounts.pool.staking_mint.as_ref(),
+            ctx.accounts.pool.reward_a_mint.as_ref(),
+            ctx.accounts.pool.reward_b_mint.as_ref(),
+            ctx.accounts.pool.base_key.as_ref(),
+            &[ctx.accounts.pool.pool_bump],
+        ];
+        let pool_signer = &[&seeds[..]];
+
+        let fee_a = pay_protocol_fee(
+            &ctx.accounts.pool,
+            &ctx.accounts.global_config,
+            &ctx.accounts.reward_a_vault,
+            &ctx.accounts.treasury_a_account,
+            &ctx.accounts.token_program,
+            pool_signer,
+            compounded_a,
         )?;
-        let ix = spl_token::instruction::close_account(
-            &spl_token::ID,
-            ctx.accounts.staking_vault.to_account_info().key,
-            ctx.accounts.refundee.key,
-            &ctx.accounts.pool.key(),
-            &[&ctx.accounts.pool.key()],
+        let compounded_a = compounded_a
+            .checked_sub(fee_a)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let fee_b = pay_protocol_fee(
+            &ctx.accounts.pool,
+            &ctx.accounts.global_config,
+            &ctx.accounts.reward_b_vault,
+            &ctx.accounts.treasury_b_account,
+            &ctx.accounts.token_program,
+            pool_signer,
+            compounded_b,
         )?;
-        solana_program::program::invoke_signed(
-            &ix,
-            &[
+        let compounded_b = compounded_b
+            .checked_sub(fee_b)
+            .ok_or(ErrorCode::MathOverflow)?;
+        // The referral share stays in the vault until the referrer claims it
+        let referral_a =
+            referral_share(compounded_a, referral_fee_bps).ok_or(ErrorCode::MathOverflow)?;
+        let compounded_a = compounded_a
+            .checked_sub(referral_a)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let referral_b =
+            referral_share(compounded_b, referral_fee_bps).ok_or(ErrorCode::MathOverflow)?;
+        let compounded_b = compounded_b
+            .checked_sub(referral_b)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        if compounded_a > 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.staking_vault.to_account_info(),
-                ctx.accounts.refundee.to_account_info(),
-                ctx.accounts.pool.to_account_info(),
-            ],
-            &[signer_seeds],
-        )?;
+                token::Transfer {
+                    from: ctx.accounts.reward_a_vault.to_account_info(),
+                    to: ctx.accounts.staking_vault.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                pool_signer,
+            );
+            token::transfer(cpi_ctx, compounded_a)?;
+        }
 
-        //close token a vault
-        let ix = spl_token::instruction::transfer(
-            &spl_token::ID,
-            ctx.accounts.reward_a_vault.to_account_info().key,
-            ctx.accounts.reward_a_refundee.to_account_info().key,
-            &ctx.accounts.pool.key(),
-            &[&ctx.accounts.pool.key()],
-            ctx.accounts.reward_a_vault.amount,
-        )?;
-        solana_program::program::invoke_signed(
-            &ix,
-            &[
+        if compounded_b > 0 {
+            let cpi_ctx = CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.reward_a_vault.to_account_info(),
-                ctx.accounts.reward_a_refundee.to_account_info(),
-                ctx.accounts.pool.to_account_info(),
-            ],
-            &[signer_seeds],
-        )?;
-        let ix = spl_token::instruction::close_account(
-            &spl_token::ID,
-            ctx.accounts.reward_a_vault.to_account_info().key,
-            ctx.accounts.refundee.key,
-            &ctx.accounts.pool.key(),
-            &[&ctx.accounts.pool.key()],
-        )?;
-        solana_program::program::invoke_signed(
-            &ix,
-            &[
+                token::Transfer {
+                    from: ctx.accounts.reward_b_vault.to_account_info(),
+                    to: ctx.accounts.staking_vault.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                pool_signer,
+            );
+            token::transfer(cpi_ctx, compounded_b)?;
+        }
+
+        let amount = compounded_a
+            .checked_add(compounded_b)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let user = &mut ctx.accounts.user;
+        user.balance_staked = user
+            .balance_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let pool = &mut ctx.accounts.pool;
+        pool.total_staked = pool
+            .total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        sync_boost_stake(pool, &mut ctx.accounts.user)?;
+
+        if referral_a > 0 || referral_b > 0 {
+            let referrer = ctx
+                .accounts
+                .referrer
+                .as_mut()
+                .ok_or(ErrorCode::MissingReferrerAccount)?;
+            referrer.reward_a_accrued = referrer
+                .reward_a_accrued
+                .checked_add(referral_a)
+                .ok_or(ErrorCode::MathOverflow)?;
+            referrer.reward_b_accrued = referrer
+                .reward_b_accrued
+                .checked_add(referral_b)
+                .ok_or(ErrorCode::MathOverflow)?;
+            let pool = &mut ctx.accounts.pool;
+            pool.reward_a_obligation = pool
+                .reward_a_obligation
+                .checked_add(referral_a)
+                .ok_or(ErrorCode::MathOverflow)?;
+            pool.reward_b_obligation = pool
+                .reward_b_obligation
+                .checked_add(referral_b)
+                .ok_or(ErrorCode::MathOverflow)?;
+            emit!(EventReferralAccrued {
+                referrer: referrer.owner,
+                amount_a: referral_a,
+                amount_b: referral_b,
+            });
+        }
+
+        emit!(EventCompound {
+            amount_a: compounded_a,
+            amount_b: compounded_b
+        });
+        if fee_a > 0 || fee_b > 0 {
+            emit!(EventProtocolFee {
+                amount_a: fee_a,
+                amount_b: fee_b
+            });
+        }
+        Ok(())
+    }
+
+    /// Withdraw token that mistakenly deposited to staking_vault
+    pub fn withdraw_extra_token(ctx: Context<WithdrawExtraToken>) -> Result<()> {
+        let pool = &ctx.accounts.pool;
+        let total_amount = ctx.accounts.staking_vault.amount;
+        // The stake of a pool attached to a stake hub is held by the hub
+        let held_stake = if pool.is_attached_to_hub() {
+            0
+        } else {
+            pool.total_staked
+        };
+        let total_staked = held_stake
+            .checked_add(pool.total_unbonding)
+            .ok_or(ErrorCode::MathOverflow)?;
+        let withdrawable_amount = total_amount
+            .checked_sub(total_staked)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        if withdrawable_amount > 0 {
+            let reward_duration = pool.get_seed_reward_duration().to_be_bytes();
+            let seeds = &[
+                reward_duration.as_ref(),
+                pool.staking_mint.as_ref(),
+                pool.reward_a_mint.as_ref(),
+                pool.reward_b_mint.as_ref(),
+                pool.base_key.as_ref(),
+                &[pool.pool_bump],
+            ];
+            let pool_signer = &[&seeds[..]];
+            let cpi_ctx = CpiContext::new_with_signer(
                 ctx.accounts.token_program.to_account_info(),
-                ctx.accounts.reward_a_vault.to_account_info(),
-                ctx.accounts.refundee.to_account_info(),
-                ctx.accounts.pool.to_account_info(),
-            ],
-            &[signer_seeds],
-        )?;
+                token::Transfer {
+                    from: ctx.accounts.staking_vault.to_account_info(),
+                    to: ctx.accounts.withdraw_to_account.to_account_info(),
+                    authority: pool.to_account_info(),
+                },
+                pool_signer,
+            );
 
-        if pool.reward_a_vault != pool.reward_b_vault {
-            //close token b vault
-            let ix = spl_token::instruction::transfer(
-                &spl_token::ID,
-                ctx.accounts.reward_b_vault.to_account_info().key,
-                ctx.accounts.reward_b_refundee.to_account_info().key,
-                &ctx.accounts.pool.key(),
-                &[&ctx.accounts.pool.key()],
-                ctx.accounts.reward_b_vault.amount,
-            )?;
-            solana_program::program::invoke_signed(
-                &ix,
-                &[
-                    ctx.accounts.token_program.to_account_info(),
-                    ctx.accounts.reward_b_vault.to_account_info(),
-                    ctx.accounts.reward_b_refundee.to_account_info(),
-                    ctx.accounts.pool.to_account_info(),
-                ],
-                &[signer_seeds],
-            )?;
-            let ix = spl_token::instruction::close_account(
-                &spl_token::ID,
-                ctx.accounts.reward_b_vault.to_account_info().key,
-                ctx.accounts.refundee.key,
-                &ctx.accounts.pool.key(),
-                &[&ctx.accounts.pool.key()],
-            )?;
-            solana_program::program::invoke_signed(
-                &ix,
-                &[
-                    ctx.accounts.token_program.to_account_info(),
-                    ctx.accounts.reward_b_vault.to_account_info(),
-                    ctx.accounts.refundee.to_account_info(),
-                    ctx.accounts.pool.to_account_info(),
-                ],
-                &[signer_seeds],
+            token::transfer(cpi_ctx, withdrawable_amount)?;
+        }
+
+        Ok(())
+    }
+
+    /// Withdraw reward tokens above the outstanding reward obligations, such as tokens sent directly to the reward vaults
+    /// or left over after campaigns
+    pub fn withdraw_extra_reward(ctx: Context<WithdrawExtraReward>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        if !pool.reward_obligations_tracked {
+            return Err(ErrorCode::RewardObligationsUntracked.into());
+        }
+        update_rewards(pool, None, pool.get_total_effective_stake())?;
+
+        // Native rewards are owed by the native vault, their token vaults owe nothing
+        let native_obligation = if pool.reward_a_native {
+            pool.reward_a_obligation
+        } else {
+            0
+        }
+        .checked_add(if pool.reward_b_native {
+            pool.reward_b_obligation
+        } else {
+            0
+        })
+        .ok_or(ErrorCode::MathOverflow)?;
+        let extra_a = if pool.reward_a_native {
+            ctx.accounts.reward_a_vault.amount
+        } else {
+            ctx.accounts
+                .reward_a_vault
+                .amount
+                .saturating_sub(pool.reward_a_obligation)
+        };
+        let extra_b = if !pool.has_reward_b_vault() {
+            0
+        } else if pool.reward_b_native {
+            ctx.accounts.reward_b_vault.amount
+        } else {
+            ctx.accounts
+                .reward_b_vault
+                .amount
+                .saturating_sub(pool.reward_b_obligation)
+        };
+        let extra_native = if pool.reward_a_native || pool.reward_b_native {
+            native_reward_balance(&ctx.accounts.native_vault)?.saturating_sub(native_obligation)
+        } else {
+            0
+        };
+
+        let reward_duration = ctx.accounts.pool.get_seed_reward_duration().to_be_bytes();
+        let seeds = &[
+            reward_duration.as_ref(),
+            ctx.accounts.pool.staking_mint.as_ref(),
+            ctx.accounts.pool.reward_a_mint.as_ref(),
+            ctx.accounts.pool.reward_b_mint.as_ref(),
+            ctx.accounts.pool.base_key.as_ref(),
+            &[ctx.accounts.pool.pool_bump],
+        ];
+        let pool_signer = &[&seeds[..]];
+
+        if extra_a > 0 {
+            let withdraw_a_account = ctx
+                .accounts
+                .withdraw_a_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.reward_a_vault.to_account_info(),
+                    to: withdraw_a_account.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                pool_signer,
+            );
+            token::transfer(cpi_ctx, extra_a)?;
+        }
+
+        if extra_b > 0 {
+            let withdraw_b_account = ctx
+                .accounts
+                .withdraw_b_account
+                .as_ref()
+                .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+            let cpi_ctx = CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                token::Transfer {
+                    from: ctx.accounts.reward_b_vault.to_account_info(),
+                    to: withdraw_b_account.to_account_info(),
+                    authority: ctx.accounts.pool.to_account_info(),
+                },
+                pool_signer,
+            );
+            token::transfer(cpi_ctx, extra_b)?;
+        }
+
+        if extra_native > 0 {
+            transfer_native_reward(
+                &ctx.accounts.pool,
+                &ctx.accounts.native_vault,
+                &ctx.accounts.system_program,
+                &ctx.accounts.authority.to_account_info(),
+                extra_native,
             )?;
         }
+
+        emit!(EventWithdrawExtraReward {
+            amount_a: extra_a,
+            amount_b: extra_b,
+            amount_native: extra_native,
+        });
         Ok(())
     }
+
+    /// End the running campaign now and refund the rewards not yet emitted. Rewards already accrued by users are kept in the vaults.
+    pub fn end_campaign(ctx: Context<EndCampaign>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time >= pool.reward_duration_end {
+            return Err(ErrorCode::CampaignNotActive.into());
+        }
+
+        update_rewards(pool, None, pool.get_total_effective_stake())?;
+
+        let ((leftover_a, leftover_remainder_a), (leftover_b, leftover_remainder_b)) =
+            leftover_rewards(pool, current_time);
+        pool.reward_duration_end = current_time;
+        pool.reward_a_obligation = pool.reward_a_obligation.saturating_sub(leftover_a);
+        pool.reward_b_obligation = pool.reward_b_obligation.saturating_sub(leftover_b);
+        // Keep the unrefundable dust for the next funding
+        pool.reward_a_rate_remainder = pool
+            .reward_a_rate_remainder
+            .checked_add(leftover_remainder_a)
+            .ok_or(ErrorCode::MathOverflow)?;
+        pool.reward_b_rate_remainder = pool
+            .reward_b_rate_remainder
+            .checked_add(leftover_remainder_b)
+            .ok_or(ErrorCode::MathOverflow)?;
+
+        let vault_a_balance = if pool.reward_a_native {
+            native_reward_balance(&ctx.accounts.native_vault)?
+        } else {
+            ctx.accounts.reward_a_vault.amount
+        };
+        let vault_b_balance = if pool.reward_b_native {
+            native_reward_balance(&ctx.accounts.native_vault)?
+        } else {
+            ctx.accounts.reward_b_vault.amount
+        };
+        let refund_a = std::cmp::min(leftover_a, vault_a_balance);
+        let refund_b = std::cmp::min(leftover_b, vault_b_balance);
+
+        let reward_duration = ctx.accounts.pool.get_seed_reward_duration().to_be_bytes();
+        let seeds = &[
+            reward_duration.as_ref(),
+            ctx.accounts.pool.staking_mint.as_ref(),
+            ctx.accounts.pool.reward_a_mint.as_ref(),
+            ctx.accounts.pool.reward_b_mint.as_ref(),
+            ctx.accounts.pool.base_key.as_ref(),
+            &[ctx.accounts.pool.pool_bump],
+        ];
+        let pool_signer = &[&seeds[..]];
+
+        if refund_a > 0 {
+            if ctx.accounts.pool.reward_a_native {
+                transfer_native_reward(
+                    &ctx.accounts.pool,
+                    &ctx.accounts.native_vault,
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.authority.to_account_info(),
+                    refund_a,
+                )?;
+            } else {
+                let refund_a_account = ctx
+                    .accounts
+                    .refund_a_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+                let cpi_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.reward_a_vault.to_account_info(),
+                        to: refund_a_account.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    pool_signer,
+                );
+                token::transfer(cpi_ctx, refund_a)?;
+            }
+        }
+
+        if refund_b > 0 {
+            if ctx.accounts.pool.reward_b_native {
+                transfer_native_reward(
+                    &ctx.accounts.pool,
+                    &ctx.accounts.native_vault,
+                    &ctx.accounts.system_program,
+                    &ctx.accounts.authority.to_account_info(),
+                    refund_b,
+                )?;
+            } else {
+                let refund_b_account = ctx
+                    .accounts
+                    .refund_b_account
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+                let cpi_ctx = CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    token::Transfer {
+                        from: ctx.accounts.reward_b_vault.to_account_info(),
+                        to: refund_b_account.to_account_info(),
+                        authority: ctx.accounts.pool.to_account_info(),
+                    },
+                    pool_signer,
+                );
+                token::transfer(cpi_ctx, refund_b)?;
+            }
+        }
+
+        emit!(EventEndCampaign {
+            refund_a,
+            refund_b,
+            end_time: current_time,
+        });
+        Ok(())
+    }
+
+    /// Closes a users stake account. Validation is done to ensure this is only allowed when the user has nothing staked and no rewards pending.
+    pub fn close_user(ctx: Context<CloseUser>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.user_stake_count = pool.user_stake_count.checked_sub(1).unwrap();
+        Ok(())
+    }
+
+    /// Closes an abandoned user account with nothing staked and no rewards pending once the campaign is finished,
+    /// so that it does not block closing the pool. Rent goes back to the user owner. Anyone can call this.
+    pub fn close_dormant_user(ctx: Context<CloseDormantUser>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        pool.user_stake_count = pool
+            .user_stake_count
+            .checked_sub(1)
+            .ok_or(ErrorCode::MathOverflow)?;
+        emit!(EventCloseDormantUser {
+            owner: ctx.accounts.owner.key(),
+        });
+        Ok(())
+    }
+
+    /// anyone can call this
+    pub fn migrate_farming_rate(ctx: Context<MigrateFarmingRate>) -> Result<()> {
+        let pool = &mut ctx.accounts.pool;
+        if pool.reward_a_rate_u128 == 0 && pool._reward_a_rate != 0 {
+            pool.reward_a_rate_u128 = pool._reward_a_rate.into();
+        }
+        if pool.reward_b_rate_u128 == 0 && pool._reward_b_rate != 0 {
+            pool.reward_b_rate_u128 = pool._reward_b_rate.into();
+        }
+        Ok(())
+    }
+
+    /// Grow a user account created with a smaller size to the current user size. Anyone can call this.
+    pub fn migrate_user(ctx: Context<MigrateUser>) -> Result<()> {
+        let user = ctx.accounts.user.to_account_info();
+        {
+            let data = user.try_borrow_data()?;
+            if data.len() < 8 || data[..8] != User::DISCRIMINATOR {
+                return Err(ErrorCode::InvalidUserAccount.into());
+            }
+            if data.len() >= USER_SPACE {
+                return Ok(());
+            }
+        }
+
+        let lamports_needed = Rent::get()?
+            .minimum_balance(USER_SPACE)
+            .saturating_sub(user.lamports());
+        if lamports_needed > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: user.clone(),
+                },
+            );
+            system_program::transfer(cpi_ctx, lamports_needed)?;
+        }
+        user.realloc(USER_SPACE, true)?;
+        Ok(())
+    }
+
+    /// Grow a pool account created with a smaller size to the current pool size. Anyone can call this.
+    pub fn migrate_pool(ctx: Context<MigratePool>) -> Result<()> {
+        let pool = ctx.accounts.pool.to_account_info();
+        {
+            let data = pool.try_borrow_data()?;
+            if data.len() < 8 || data[..8] != Pool::DISCRIMINATOR {
+                return Err(ErrorCode::InvalidPoolAccount.into());
+            }
+            if data.len() >= POOL_SPACE {
+                return Ok(());
+            }
+        }
+        // Pools created before the reward mode was stored are left in RewardMode::Unknown, as they
+        // may have a separate, unused reward B vault
+
+        let lamports_needed = Rent::get()?
+            .minimum_balance(POOL_SPACE)
+            .saturating_sub(pool.lamports());
+        if lamports_needed > 0 {
+            let cpi_ctx = CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: pool.clone(),
+                },
+            );
+            system_program::transfer(cpi_ctx, lamports_needed)?;
+        }
+        pool.realloc(POOL_SPACE, true)?;
+        Ok(())
+    }
+
+    /// Closes a pool account. Only able to be done when there are no users staked.
+    pub fn close_pool(ctx: Context<ClosePool>) -> Result<()> {
+        if ctx.accounts.pool.registered {
+            let pool_registry = ctx
+                .accounts
+                .pool_registry
+                .as_mut()
+                .ok_or(ErrorCode::MissingPoolRegistry)?;
+            remove_pool_from_registry(
+                pool_registry,
+                ctx.accounts.pool.key(),
+                &ctx.accounts.refundee.to_account_info(),
+            )?;
+        }
+
+        let pool = &ctx.accounts.pool;
+        let reward_duration = ctx.accounts.pool.get_seed_reward_duration().to_be_bytes();
+        let signer_seeds = &[
+            reward_duration.as_ref(),
+            ctx.accounts.pool.staking_mint.as_ref(),
+            ctx.accounts.pool.reward_a_mint.as_ref(),
+            ctx.accounts.pool.reward_b_mint.as_ref(),
+            ctx.accounts.pool.base_key.as_ref(),
+            &[ctx.accounts.pool.pool_bump],
+        ];
+
+        //instead of closing these vaults, we could technically just
+        //set_authority on them. it's not very ata clean, but it'd work
+        //if size of tx is an issue, thats an approach
+
+        //close staking vault
+        let ix = spl_token::instruction::transfer(
+            &spl_token::ID,
+            ctx.accounts.staking_vault.to_account_info().key,
+            ctx.accounts.staking_refundee.to_account_info().key,
+            &ctx.accounts.pool.key(),
+            &[&ctx.accounts.pool.key()],
+            ctx.accounts.staking_vault.amount,
+        )?;
+        solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.staking_vault.to_account_info(),
+                ctx.accounts.staking_refundee.to_account_info(),
+                ctx.accounts.pool.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        let ix = spl_token::instruction::close_account(
+            &spl_token::ID,
+            ctx.accounts.staking_vault.to_account_info().key,
+            ctx.accounts.refundee.key,
+            &ctx.accounts.pool.key(),
+            &[&ctx.accounts.pool.key()],
+        )?;
+        solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.staking_vault.to_account_info(),
+                ctx.accounts.refundee.to_account_info(),
+                ctx.accounts.pool.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+
+        //close token a vault
+        let ix = spl_token::instruction::transfer(
+            &spl_token::ID,
+            ctx.accounts.reward_a_vault.to_account_info().key,
+            ctx.accounts.reward_a_refundee.to_account_info().key,
+            &ctx.accounts.pool.key(),
+            &[&ctx.accounts.pool.key()],
+            ctx.accounts.reward_a_vault.amount,
+        )?;
+        solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.reward_a_vault.to_account_info(),
+                ctx.accounts.reward_a_refundee.to_account_info(),
+                ctx.accounts.pool.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+        let ix = spl_token::instruction::close_account(
+            &spl_token::ID,
+            ctx.accounts.reward_a_vault.to_account_info().key,
+            ctx.accounts.refundee.key,
+            &ctx.accounts.pool.key(),
+            &[&ctx.accounts.pool.key()],
+        )?;
+        solana_program::program::invoke_signed(
+            &ix,
+            &[
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.reward_a_vault.to_account_info(),
+                ctx.accounts.refundee.to_account_info(),
+                ctx.accounts.pool.to_account_info(),
+            ],
+            &[signer_seeds],
+        )?;
+
+        if pool.has_reward_b_vault() {
+            let reward_b_vault = ctx
+                .accounts
+                .reward_b_vault
+                .as_ref()
+                .ok_or(ErrorCode::MissingRewardVault)?;
+            let reward_b_refundee = ctx
+                .accounts
+                .reward_b_refundee
+                .as_ref()
+                .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+            //close token b vault
+            let ix = spl_token::instruction::transfer(
+                &spl_token::ID,
+                reward_b_vault.to_account_info().key,
+                reward_b_refundee.to_account_info().key,
+                &ctx.accounts.pool.key(),
+                &[&ctx.accounts.pool.key()],
+                reward_b_vault.amount,
+            )?;
+            solana_program::program::invoke_signed(
+                &ix,
+                &[
+                    ctx.accounts.token_program.to_account_info(),
+                    reward_b_vault.to_account_info(),
+                    reward_b_refundee.to_account_info(),
+                    ctx.accounts.pool.to_account_info(),
+                ],
+                &[signer_seeds],
+            )?;
+            let ix = spl_token::instruction::close_account(
+                &spl_token::ID,
+                reward_b_vault.to_account_info().key,
+                ctx.accounts.refundee.key,
+                &ctx.accounts.pool.key(),
+                &[&ctx.accounts.pool.key()],
+            )?;
+            solana_program::program::invoke_signed(
+                &ix,
+                &[
+                    ctx.accounts.token_program.to_account_info(),
+                    reward_b_vault.to_account_info(),
+                    ctx.accounts.refundee.to_account_info(),
+                    ctx.accounts.pool.to_account_info(),
+                ],
+                &[signer_seeds],
+            )?;
+        }
+
+        if pool.reward_a_native || pool.reward_b_native {
+            // Drain the native reward vault, rent exempt minimum included
+            let lamports = ctx
+                .accounts
+                .native_vault
+                .as_ref()
+                .ok_or(ErrorCode::MissingNativeRewardAccounts)?
+                .lamports();
+            transfer_native_reward(
+                pool,
+                &ctx.accounts.native_vault,
+                &ctx.accounts.system_program,
+                &ctx.accounts.refundee.to_account_info(),
+                lamports,
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Create a stake hub holding the staked tokens once for every reward pool attached to it.
+    pub fn initialize_stake_hub(ctx: Context<InitializeStakeHub>) -> Result<()> {
+        let stake_hub = &mut ctx.accounts.stake_hub;
+        stake_hub.authority = ctx.accounts.authority.key();
+        stake_hub.staking_mint = ctx.accounts.staking_mint.key();
+        stake_hub.staking_vault = ctx.accounts.staking_vault.key();
+        stake_hub.base_key = ctx.accounts.base.key();
+        stake_hub.bump = *ctx.bumps.get("stake_hub").unwrap();
+        stake_hub.total_staked = 0;
+        stake_hub.pools = [Pubkey::default(); MAX_HUB_POOLS];
+        Ok(())
+    }
+
+    /// Attach a reward pool to a stake hub. The pool then distributes rewards on the hub balances
+    /// of the users who joined it instead of its own staking vault. Only allowed while the pool holds no stake.
+    pub fn attach_pool(ctx: Context<AttachPool>) -> Result<()> {
+        let stake_hub = &mut ctx.accounts.stake_hub;
+        let slot = stake_hub
+            .pools
+            .iter()
+            .position(|pool| *pool == Pubkey::default())
+            .ok_or(ErrorCode::StakeHubFull)?;
+        let pool = &mut ctx.accounts.pool;
+        stake_hub.pools[slot] = pool.key();
+        pool.stake_hub = stake_hub.key();
+        emit!(EventAttachPool {
+            stake_hub: stake_hub.key(),
+            pool: pool.key(),
+        });
+        Ok(())
+    }
+
+    /// User enables staking in a stake hub
+    pub fn create_hub_user(ctx: Context<CreateHubUser>) -> Result<()> {
+        let hub_user = &mut ctx.accounts.hub_user;
+        hub_user.stake_hub = ctx.accounts.stake_hub.key();
+        hub_user.owner = ctx.accounts.owner.key();
+        hub_user.balance_staked = 0;
+        hub_user.joined_pools = [Pubkey::default(); MAX_HUB_POOLS];
+        hub_user.bump = *ctx.bumps.get("hub_user").unwrap();
+        Ok(())
+    }
+
+    /// User starts earning the rewards of a pool attached to the stake hub with its hub balance.
+    /// The user account of the pool must exist.
+    pub fn join_hub_pool(ctx: Context<HubPoolMembership>) -> Result<()> {
+        if ctx.accounts.pool.paused {
+            return Err(ErrorCode::PoolPaused.into());
+        }
+        let slot = ctx.accounts.slot()?;
+        let hub_user = &mut ctx.accounts.hub_user;
+        if hub_user.joined_pools[slot] == ctx.accounts.pool.key() {
+            return Err(ErrorCode::HubPoolAlreadyJoined.into());
+        }
+        mirror_hub_balance(
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.user,
+            0,
+            hub_user.balance_staked,
+        )?;
+        hub_user.joined_pools[slot] = ctx.accounts.pool.key();
+        emit!(EventJoinHubPool {
+            owner: hub_user.owner,
+            pool: ctx.accounts.pool.key(),
+            amount: hub_user.balance_staked,
+        });
+        Ok(())
+    }
+
+    /// User stops earning the rewards of a pool attached to the stake hub, such as after its campaign ended.
+    pub fn leave_hub_pool(ctx: Context<HubPoolMembership>) -> Result<()> {
+        let slot = ctx.accounts.slot()?;
+        leave_hub_pool_slot(
+            &mut ctx.accounts.hub_user,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.user,
+            slot,
+        )
+    }
+
+    /// Stake hub authority removes a user from a pool whose campaign ended, so that the pool can be detached.
+    /// The rewards earned by the user stay claimable.
+    pub fn remove_hub_pool_member(ctx: Context<RemoveHubPoolMember>) -> Result<()> {
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time < ctx.accounts.pool.reward_duration_end {
+            return Err(ErrorCode::CampaignActive.into());
+        }
+        let slot = ctx.accounts.slot()?;
+        leave_hub_pool_slot(
+            &mut ctx.accounts.hub_user,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.user,
+            slot,
+        )
+    }
+
+    /// Detach a pool from its stake hub, freeing its slot. The pool must be closed,
+    /// or have its campaign ended and no hub balance mirrored anymore.
+    /// An open pool can then take deposits of its own again.
+    pub fn detach_pool(ctx: Context<DetachPool>) -> Result<()> {
+        let stake_hub = &mut ctx.accounts.stake_hub;
+        let pool_info = ctx.accounts.pool.to_account_info();
+        let slot = stake_hub
+            .pools
+            .iter()
+            .position(|pool| *pool == pool_info.key())
+            .ok_or(ErrorCode::PoolNotAttachedToHub)?;
+        if !pool_info.data_is_empty() {
+            let mut pool = Account::<Pool>::try_from(&pool_info)?;
+            let current_time: u64 = clock::Clock::get()?
+                .unix_timestamp
+                .try_into()
+                .map_err(|_| ErrorCode::MathOverflow)?;
+            if current_time < pool.reward_duration_end {
+                return Err(ErrorCode::CampaignActive.into());
+            }
+            if pool.total_staked > 0 {
+                return Err(ErrorCode::PoolHasStake.into());
+            }
+            pool.stake_hub = Pubkey::default();
+            pool.exit(ctx.program_id)?;
+        }
+        stake_hub.pools[slot] = Pubkey::default();
+        emit!(EventDetachPool {
+            stake_hub: stake_hub.key(),
+            pool: pool_info.key(),
+        });
+        Ok(())
+    }
+
+    /// User deposits tokens in the stake hub.
+    /// Remaining accounts hold the pool and user accounts of every joined pool, in slot order.
+    pub fn hub_deposit<'info>(
+        ctx: Context<'_, '_, '_, 'info, HubStake<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Err(ErrorCode::AmountMustBeGreaterThanZero.into());
+        }
+        let previous_balance = ctx.accounts.hub_user.balance_staked;
+        let balance = previous_balance
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        mirror_hub_balance_in_joined_pools(
+            ctx.program_id,
+            &ctx.accounts.stake_hub,
+            &mut ctx.accounts.hub_user,
+            ctx.remaining_accounts,
+            previous_balance,
+            balance,
+        )?;
+
+        let cpi_ctx = CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.stake_from_account.to_account_info(),
+                to: ctx.accounts.staking_vault.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        ctx.accounts.hub_user.balance_staked = balance;
+        let stake_hub = &mut ctx.accounts.stake_hub;
+        stake_hub.total_staked = stake_hub
+            .total_staked
+            .checked_add(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        emit!(EventHubDeposit {
+            owner: ctx.accounts.owner.key(),
+            amount,
+        });
+        Ok(())
+    }
+
+    /// User withdraws tokens from the stake hub.
+    /// Remaining accounts hold the pool and user accounts of every joined pool, in slot order.
+    pub fn hub_withdraw<'info>(
+        ctx: Context<'_, '_, '_, 'info, HubStake<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        if amount == 0 {
+            return Err(ErrorCode::AmountMustBeGreaterThanZero.into());
+        }
+        let previous_balance = ctx.accounts.hub_user.balance_staked;
+        let balance = previous_balance
+            .checked_sub(amount)
+            .ok_or(ErrorCode::InsufficientFundWithdraw)?;
+        mirror_hub_balance_in_joined_pools(
+            ctx.program_id,
+            &ctx.accounts.stake_hub,
+            &mut ctx.accounts.hub_user,
+            ctx.remaining_accounts,
+            previous_balance,
+            balance,
+        )?;
+
+        let stake_hub = &ctx.accounts.stake_hub;
+        let seeds = &[
+            STAKE_HUB_SEED,
+            stake_hub.staking_mint.as_ref(),
+            stake_hub.base_key.as_ref(),
+            &[stake_hub.bump],
+        ];
+        let stake_hub_signer = &[&seeds[..]];
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            token::Transfer {
+                from: ctx.accounts.staking_vault.to_account_info(),
+                to: ctx.accounts.stake_from_account.to_account_info(),
+                authority: stake_hub.to_account_info(),
+            },
+            stake_hub_signer,
+        );
+        token::transfer(cpi_ctx, amount)?;
+
+        ctx.accounts.hub_user.balance_staked = balance;
+        let stake_hub = &mut ctx.accounts.stake_hub;
+        stake_hub.total_staked = stake_hub
+            .total_staked
+            .checked_sub(amount)
+            .ok_or(ErrorCode::MathOverflow)?;
+        emit!(EventHubWithdraw {
+            owner: ctx.accounts.owner.key(),
+            amount,
+        });
+        Ok(())
+    }
+}
+
+/// Accounts for [InitializePool](/dual_farming/instruction/struct.InitializePool.html) instruction
+#[derive(Accounts)]
+#[instruction(reward_duration: u64)]
+pub struct InitializePool<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        init,
+        seeds = [
+            reward_duration.to_be_bytes().as_ref(),
+            staking_mint.key().as_ref(),
+            reward_a_mint.key().as_ref(),
+            reward_b_mint.key().as_ref(),
+            base.key().as_ref(),
+        ],
+        payer = authority,
+        bump,
+        space = POOL_SPACE
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Staking mint
+    staking_mint: Box<Account<'info, Mint>>,
+
+    /// Staking vault PDA
+    #[account(
+        init,
+        seeds = [
+            b"staking",
+            pool.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        token::mint = staking_mint,
+        token::authority = pool
+    )]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Reward A mint
+    reward_a_mint: Box<Account<'info, Mint>>,
+
+    /// Reward A vault PDA
+    #[account(
+        init,
+        seeds = [
+            b"reward_a",
+            pool.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        token::mint = reward_a_mint,
+        token::authority = pool
+    )]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Reward B mint
+    reward_b_mint: Box<Account<'info, Mint>>,
+
+    /// Reward B vault PDA. Must be omitted when the reward B mint is the reward A mint
+    #[account(
+        init,
+        seeds = [
+            b"reward_b",
+            pool.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        token::mint = reward_b_mint,
+        token::authority = pool
+    )]
+    reward_b_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Authority of the pool
+    #[account(mut)]
+    authority: Signer<'info>,
+
+    /// Base
+    base: Signer<'info>,
+    /// Global config. The pool charges no protocol fee when omitted
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    global_config: Option<Box<Account<'info, GlobalConfig>>>,
+    /// Registry of the pools of the staking mint
+    #[account(
+        init_if_needed,
+        seeds = [
+            POOL_REGISTRY_SEED,
+            staking_mint.key().as_ref(),
+        ],
+        bump,
+        payer = authority,
+        space = PoolRegistry::init_space(pool_registry),
+    )]
+    pool_registry: Box<Account<'info, PoolRegistry>>,
+    /// System program
+    system_program: Program<'info, System>,
+    /// SPL Token program
+    token_program: Program<'info, Token>,
+    /// Rent
+    rent: Sysvar<'info, Rent>,
+}
+
+/// Accounts for [InitializeGlobalConfig](/dual_farming/instruction/struct.InitializeGlobalConfig.html) instruction
+#[derive(Accounts)]
+pub struct InitializeGlobalConfig<'info> {
+    /// Global config
+    #[account(
+        init,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump,
+        payer = admin,
+        space = 8 + 128 // discriminator + content + buffer
+    )]
+    global_config: Box<Account<'info, GlobalConfig>>,
+    /// Program upgrade authority, becoming the protocol admin
+    #[account(mut)]
+    admin: Signer<'info>,
+    /// Farming program
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    program: Program<'info, crate::program::Farming>,
+    /// Farming program data
+    #[account(constraint = program_data.upgrade_authority_address == Some(admin.key()) @ ErrorCode::InvalidProtocolAdmin)]
+    program_data: Box<Account<'info, ProgramData>>,
+    /// System program
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [UpdateGlobalConfig](/dual_farming/instruction/struct.UpdateGlobalConfig.html) instruction
+#[derive(Accounts)]
+pub struct UpdateGlobalConfig<'info> {
+    /// Global config
+    #[account(
+        mut,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ ErrorCode::InvalidProtocolAdmin,
+    )]
+    global_config: Box<Account<'info, GlobalConfig>>,
+    /// Protocol admin
+    admin: Signer<'info>,
+}
+
+/// Accounts for [CreateUser](/dual_farming/instruction/struct.CreateUser.html) instruction
+#[derive(Accounts)]
+pub struct CreateUser<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        constraint = !pool.paused,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// User
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            owner.key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump,
+        space = USER_SPACE,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Authority of user account
+    #[account(mut)]
+    owner: Signer<'info>,
+    /// Misc.
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [Pause](/dual_farming/instruction/struct.Pause.html) instruction
+#[derive(Accounts)]
+pub struct Pause<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = !pool.paused,
+        constraint = pool.reward_duration_end < clock::Clock::get().unwrap().unix_timestamp.try_into().unwrap(),
+        constraint = pool.reward_duration_end > 0,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [Unpause](/dual_farming/instruction/struct.Unpause.html) instruction
+#[derive(Accounts)]
+pub struct Unpause<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+        constraint = pool.paused,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [Deposit](/dual_farming/instruction/struct.Deposit.html) and [Withdraw](/dual_farming/instruction/struct.Withdraw.html) instructions.
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    /// Global accounts for the deposit/withdraw instance.
+    #[account(
+        mut,
+        has_one = staking_vault,
+        constraint = !pool.is_attached_to_hub() @ ErrorCode::PoolAttachedToHub,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Staking vault PDA.
+    #[account(mut)]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+
+    /// User.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Authority of user
+    owner: Signer<'info>,
+    /// User staking ATA
+    #[account(mut)]
+    stake_from_account: Box<Account<'info, TokenAccount>>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+}
+
+/// Accounts for [SetRewardDuration](/dual_farming/instruction/struct.SetRewardDuration.html) instruction
+#[derive(Accounts)]
+pub struct SetRewardDuration<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [SetUnbondingPeriod](/dual_farming/instruction/struct.SetUnbondingPeriod.html) instruction
+#[derive(Accounts)]
+pub struct SetUnbondingPeriod<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [SetMinStakeDuration](/dual_farming/instruction/struct.SetMinStakeDuration.html) instruction
+#[derive(Accounts)]
+pub struct SetMinStakeDuration<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [AuthorizeFunder](/dual_farming/instruction/struct.AuthorizeFunder.html)
+/// and [DeauthorizeFunder](/dual_farming/instruction/struct.DeauthorizeFunder.html) instructions.
+#[derive(Accounts)]
+pub struct FunderChange<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [EnableNativeReward](/dual_farming/instruction/struct.EnableNativeReward.html) instruction
+#[derive(Accounts)]
+pub struct EnableNativeReward<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Native reward vault PDA
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump,
+    )]
+    native_vault: SystemAccount<'info>,
+    /// Authority of the pool, paying the native reward vault rent
+    #[account(mut)]
+    authority: Signer<'info>,
+    /// System program
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [Fund](/dual_farming/instruction/struct.Fund.html) instruction.
+#[derive(Accounts)]
+pub struct Fund<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = staking_vault,
+        has_one = reward_a_vault,
+        has_one = reward_b_vault,
+        constraint = !pool.paused,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Staking vault PDA
+    #[account(mut)]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward A Vault PDA
+    #[account(mut)]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA
+    #[account(mut)]
+    reward_b_vault: Box<Account<'info, TokenAccount>>,
+    /// Funder
+    #[account(
+        mut,
+        //require signed funder auth - otherwise constant micro fund could hold funds hostage
+        constraint = funder.key() == pool.authority || pool.funders.iter().any(|x| *x == funder.key()),
+    )]
+    funder: Signer<'info>,
+    /// Funder reward A ATA. Required when reward A is a token
+    #[account(mut)]
+    from_a: Option<Box<Account<'info, TokenAccount>>>,
+    /// Funder reward B ATA. Required when reward B is a token
+    #[account(mut)]
+    from_b: Option<Box<Account<'info, TokenAccount>>>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+    /// System program. Required when a reward is native SOL
+    system_program: Option<Program<'info, System>>,
+}
+
+/// Accounts for [SyncRewards](/dual_farming/instruction/struct.SyncRewards.html) instruction.
+#[derive(Accounts)]
+pub struct SyncRewards<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = reward_a_vault,
+        has_one = reward_b_vault,
+        constraint = !pool.paused,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Reward A Vault PDA
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA
+    reward_b_vault: Box<Account<'info, TokenAccount>>,
+    /// Funder
+    #[account(
+        constraint = funder.key() == pool.authority || pool.funders.iter().any(|x| *x == funder.key()),
+    )]
+    funder: Signer<'info>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+}
+
+/// Accounts for [WithdrawExtraToken](/dual_farming/instruction/struct.WithdrawExtraToken.html) instruction
+#[derive(Accounts)]
+pub struct WithdrawExtraToken<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        has_one = staking_vault,
+        has_one = authority,
+        constraint = pool.reward_duration_end < sysvar::clock::Clock::get().unwrap().unix_timestamp.try_into().unwrap(),
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Staking vault PDA
+    #[account(mut)]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+    /// Token account to receive mistakenly deposited token
+    #[account(mut)]
+    withdraw_to_account: Box<Account<'info, TokenAccount>>,
+    /// Authority of the staking instance
+    authority: Signer<'info>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+}
+
+/// Accounts for [WithdrawExtraReward](/dual_farming/instruction/struct.WithdrawExtraReward.html) instruction
+#[derive(Accounts)]
+pub struct WithdrawExtraReward<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+        has_one = reward_a_vault,
+        has_one = reward_b_vault,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Reward A Vault PDA
+    #[account(mut)]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA
+    #[account(mut)]
+    reward_b_vault: Box<Account<'info, TokenAccount>>,
+    /// Token account to receive the reward A vault surplus. Required when there is one
+    #[account(mut)]
+    withdraw_a_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Token account to receive the reward B vault surplus. Required when there is one
+    #[account(mut)]
+    withdraw_b_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Authority of the staking instance. Receives the native reward vault surplus
+    #[account(mut)]
+    authority: Signer<'info>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+    /// System program. Required when a reward is native SOL
+    system_program: Option<Program<'info, System>>,
+}
+
+/// Accounts for [EndCampaign](/dual_farming/instruction/struct.EndCampaign.html) instruction
+#[derive(Accounts)]
+pub struct EndCampaign<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+        has_one = reward_a_vault,
+        has_one = reward_b_vault,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Reward A Vault PDA
+    #[account(mut)]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA
+    #[account(mut)]
+    reward_b_vault: Box<Account<'info, TokenAccount>>,
+    /// Token account to receive unemitted reward A. Required when reward A is a token
+    #[account(mut)]
+    refund_a_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Token account to receive unemitted reward B. Required when reward B is a token
+    #[account(mut)]
+    refund_b_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Authority of the staking instance. Receives unemitted native rewards
+    #[account(mut)]
+    authority: Signer<'info>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+    /// System program. Required when a reward is native SOL
+    system_program: Option<Program<'info, System>>,
+}
+
+/// Accounts for [Claim](/dual_farming/instruction/struct.Claim.html) instruction.
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = staking_vault,
+        has_one = reward_a_vault,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Staking vault PDA.
+    #[account(mut)]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward A Vault PDA
+    #[account(mut)]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA. Required when reward B is a token of a dual reward pool
+    #[account(
+        mut,
+        address = pool.reward_b_vault,
+    )]
+    reward_b_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// User.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.to_account_info().key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Owner of user. Signs unless the claim delegate does. Receives native rewards
+    /// CHECK: Checked by the user constraints, the signer is checked in the instruction
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+    /// User's Reward A ATA. Required when reward A is a token
+    #[account(mut)]
+    reward_a_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// User's Reward B ATA. Required when reward B is a token
+    #[account(mut)]
+    reward_b_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+    /// Global config. Required when the pool charges a protocol fee
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    global_config: Option<Box<Account<'info, GlobalConfig>>>,
+    /// Treasury Reward A ATA. Required when the pool charges a protocol fee
+    #[account(mut)]
+    treasury_a_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Treasury Reward B ATA. Required when the pool charges a protocol fee
+    #[account(mut)]
+    treasury_b_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+    /// Treasury wallet. Required when the pool charges a protocol fee on a native reward
+    #[account(mut)]
+    treasury: Option<SystemAccount<'info>>,
+    /// System program. Required when a reward is native SOL
+    system_program: Option<Program<'info, System>>,
+    /// Claim delegate registered by the user. Required when the owner does not sign
+    #[account(
+        constraint = delegate.key() == user.claim_delegate @ ErrorCode::InvalidClaimAuthority,
+    )]
+    delegate: Option<Signer<'info>>,
+    /// Referrer of the user. Required when the user has one and the pool pays a referral share
+    #[account(
+        mut,
+        seeds = [
+            REFERRER_SEED,
+            pool.key().as_ref(),
+            user.referrer.as_ref(),
+        ],
+        bump = referrer.bump,
+    )]
+    referrer: Option<Box<Account<'info, Referrer>>>,
+}
+
+impl<'info> ClaimReward<'info> {
+    /// Updates and pays the pending rewards of the user, net of the protocol fee.
+    /// Returns the claimed amounts of reward A and B
+    #[allow(clippy::result_large_err)]
+    pub fn claim_pending_rewards(&mut self) -> Result<(u64, u64)> {
+        if !self.owner.is_signer && self.delegate.is_none() {
+            return Err(ErrorCode::InvalidClaimAuthority.into());
+        }
+        let current_time: u64 = clock::Clock::get()?
+            .unix_timestamp
+            .try_into()
+            .map_err(|_| ErrorCode::MathOverflow)?;
+        if current_time < self.pool.rewards_claimable_at(&self.user) {
+            return Err(ErrorCode::MinStakeDurationNotReached.into());
+        }
+        let referral_fee_bps = if self.user.referrer == Pubkey::default() {
+            0
+        } else {
+            self.pool.get_referral_fee_bps(&self.user)
+        };
+        if referral_fee_bps > 0 && self.referrer.is_none() {
+            return Err(ErrorCode::MissingReferrerAccount.into());
+        }
+        let pool = &mut self.pool;
+
+        let user_opt = Some(&mut self.user);
+        update_rewards(pool, user_opt, pool.get_total_effective_stake()).unwrap();
+        // Pending rewards are no longer owed once claimed, even the part the vault cannot cover
+        pool.reward_a_obligation = pool
+            .reward_a_obligation
+            .saturating_sub(self.user.reward_a_per_token_pending);
+        pool.reward_b_obligation = pool
+            .reward_b_obligation
+            .saturating_sub(self.user.reward_b_per_token_pending);
+
+        let reward_duration = self.pool.get_seed_reward_duration().to_be_bytes();
+        let seeds = &[
+            reward_duration.as_ref(),
+            self.pool.staking_mint.as_ref(),
+            self.pool.reward_a_mint.as_ref(),
+            self.pool.reward_b_mint.as_ref(),
+            self.pool.base_key.as_ref(),
+            &[self.pool.pool_bump],
+        ];
+        let pool_signer = &[&seeds[..]];
+
+        let mut claimed_reward_a: u64 = 0;
+        let mut claimed_reward_b: u64 = 0;
+        let mut fee_a: u64 = 0;
+        let mut fee_b: u64 = 0;
+        let mut referral_a: u64 = 0;
+        let mut referral_b: u64 = 0;
+
+        if self.user.reward_a_per_token_pending > 0 {
+            let mut reward_amount = self.user.reward_a_per_token_pending;
+            let vault_balance = if self.pool.reward_a_native {
+                native_reward_balance(&self.native_vault)?
+            } else {
+                self.reward_a_vault.amount
+            };
+
+            self.user.reward_a_per_token_pending = 0;
+            if vault_balance < reward_amount {
+                reward_amount = vault_balance;
+            }
+
+            if reward_amount > 0 {
+                fee_a = if self.pool.reward_a_native {
+                    pay_native_protocol_fee(
+                        &self.pool,
+                        &self.global_config,
+                        &self.native_vault,
+                        &self.treasury,
+                        &self.system_program,
+                        reward_amount,
+                    )?
+                } else {
+                    pay_protocol_fee(
+                        &self.pool,
+                        &self.global_config,
+                        &self.reward_a_vault,
+                        &self.treasury_a_account,
+                        &self.token_program,
+                        pool_signer,
+                        reward_amount,
+                    )?
+                };
+                reward_amount = reward_amount
+                    .checked_sub(fee_a)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                // The referral share stays in the vault until the referrer claims it
+                referral_a = referral_share(reward_amount, referral_fee_bps)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                reward_amount = reward_amount
+                    .checked_sub(referral_a)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+
+            if reward_amount > 0 {
+                if self.pool.reward_a_native {
+                    transfer_native_reward(
+                        &self.pool,
+                        &self.native_vault,
+                        &self.system_program,
+                        &self.owner.to_account_info(),
+                        reward_amount,
+                    )?;
+                } else {
+                    let reward_a_account = self
+                        .reward_a_account
+                        .as_ref()
+                        .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+                    self.check_reward_destination(
+                        self.user.reward_a_destination,
+                        reward_a_account.key(),
+                    )?;
+                    let cpi_ctx = CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        token::Transfer {
+                            from: self.reward_a_vault.to_account_info(),
+                            to: reward_a_account.to_account_info(),
+                            authority: self.pool.to_account_info(),
+                        },
+                        pool_signer,
+                    );
+                    token::transfer(cpi_ctx, reward_amount)?;
+                }
+                claimed_reward_a = reward_amount;
+            }
+        }
+
+        if self.user.reward_b_per_token_pending > 0 {
+            let mut reward_amount = self.user.reward_b_per_token_pending;
+            let vault_balance = if self.pool.reward_b_native {
+                native_reward_balance(&self.native_vault)?
+            } else {
+                self.reward_b_vault
+                    .as_ref()
+                    .ok_or(ErrorCode::MissingRewardVault)?
+                    .amount
+            };
+
+            self.user.reward_b_per_token_pending = 0;
+            if vault_balance < reward_amount {
+                reward_amount = vault_balance;
+            }
+
+            if reward_amount > 0 {
+                fee_b = if self.pool.reward_b_native {
+                    pay_native_protocol_fee(
+                        &self.pool,
+                        &self.global_config,
+                        &self.native_vault,
+                        &self.treasury,
+                        &self.system_program,
+                        reward_amount,
+                    )?
+                } else {
+                    pay_protocol_fee(
+                        &self.pool,
+                        &self.global_config,
+                        self.reward_b_vault
+                            .as_ref()
+                            .ok_or(ErrorCode::MissingRewardVault)?,
+                        &self.treasury_b_account,
+                        &self.token_program,
+                        pool_signer,
+                        reward_amount,
+                    )?
+                };
+                reward_amount = reward_amount
+                    .checked_sub(fee_b)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                referral_b = referral_share(reward_amount, referral_fee_bps)
+                    .ok_or(ErrorCode::MathOverflow)?;
+                reward_amount = reward_amount
+                    .checked_sub(referral_b)
+                    .ok_or(ErrorCode::MathOverflow)?;
+            }
+
+            if reward_amount > 0 {
+                if self.pool.reward_b_native {
+                    transfer_native_reward(
+                        &self.pool,
+                        &self.native_vault,
+                        &self.system_program,
+                        &self.owner.to_account_info(),
+                        reward_amount,
+                    )?;
+                } else {
+                    let reward_b_vault = self
+                        .reward_b_vault
+                        .as_ref()
+                        .ok_or(ErrorCode::MissingRewardVault)?;
+                    let reward_b_account = self
+                        .reward_b_account
+                        .as_ref()
+                        .ok_or(ErrorCode::MissingRewardTokenAccount)?;
+                    self.check_reward_destination(
+                        self.user.reward_b_destination,
+                        reward_b_account.key(),
+                    )?;
+                    let cpi_ctx = CpiContext::new_with_signer(
+                        self.token_program.to_account_info(),
+                        token::Transfer {
+                            from: reward_b_vault.to_account_info(),
+                            to: reward_b_account.to_account_info(),
+                            authority: self.pool.to_account_info(),
+                        },
+                        pool_signer,
+                    );
+                    token::transfer(cpi_ctx, reward_amount)?;
+                }
+                claimed_reward_b = reward_amount;
+            }
+        }
+
+        if referral_a > 0 || referral_b > 0 {
+            let referrer = self
+                .referrer
+                .as_mut()
+                .ok_or(ErrorCode::MissingReferrerAccount)?;
+            referrer.reward_a_accrued = referrer
+                .reward_a_accrued
+                .checked_add(referral_a)
+                .ok_or(ErrorCode::MathOverflow)?;
+            referrer.reward_b_accrued = referrer
+                .reward_b_accrued
+                .checked_add(referral_b)
+                .ok_or(ErrorCode::MathOverflow)?;
+            self.pool.reward_a_obligation = self
+                .pool
+                .reward_a_obligation
+                .checked_add(referral_a)
+                .ok_or(ErrorCode::MathOverflow)?;
+            self.pool.reward_b_obligation = self
+                .pool
+                .reward_b_obligation
+                .checked_add(referral_b)
+                .ok_or(ErrorCode::MathOverflow)?;
+            emit!(EventReferralAccrued {
+                referrer: referrer.owner,
+                amount_a: referral_a,
+                amount_b: referral_b,
+            });
+        }
+
+        emit!(EventClaim {
+            amount_a: claimed_reward_a,
+            amount_b: claimed_reward_b
+        });
+        if fee_a > 0 || fee_b > 0 {
+            emit!(EventProtocolFee {
+                amount_a: fee_a,
+                amount_b: fee_b
+            });
+        }
+        Ok((claimed_reward_a, claimed_reward_b))
+    }
+
+    /// Checks that a token reward is paid to the destination registered by the user,
+    /// or to an account chosen by the owner when none is registered
+    #[allow(clippy::result_large_err)]
+    fn check_reward_destination(&self, registered: Pubkey, destination: Pubkey) -> Result<()> {
+        let allowed = if registered == Pubkey::default() {
+            self.owner.is_signer
+        } else {
+            destination == registered
+        };
+        if !allowed {
+            return Err(ErrorCode::InvalidRewardDestination.into());
+        }
+        Ok(())
+    }
+}
+
+/// Accounts for [ClaimMany](/dual_farming/instruction/struct.ClaimMany.html) instruction.
+#[derive(Accounts)]
+pub struct ClaimMany<'info> {
+    /// Owner or claim delegate of every claimed user
+    caller: Signer<'info>,
+}
+
+/// Accounts for [TransferPosition](/dual_farming/instruction/struct.TransferPosition.html) instruction.
+#[derive(Accounts)]
+pub struct TransferPosition<'info> {
+    /// New owner of the position
+    /// CHECK: Any account is able to receive the position
+    recipient: UncheckedAccount<'info>,
+    /// Recipient user, created if needed.
+    #[account(
+        init_if_needed,
+        payer = payer,
+        seeds = [
+            recipient.key.as_ref(),
+            claim.pool.to_account_info().key.as_ref()
+        ],
+        bump,
+        space = USER_SPACE,
+    )]
+    recipient_user: Box<Account<'info, User>>,
+    /// Payer of the recipient user rent
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// Misc.
+    system_program: Program<'info, System>,
+    /// Accounts settling the pending rewards of the current owner
+    claim: ClaimReward<'info>,
+}
+
+/// Accounts for [SetBoostConfig](/dual_farming/instruction/struct.SetBoostConfig.html) instruction
+#[derive(Accounts)]
+pub struct SetBoostConfig<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [RefreshBoost](/dual_farming/instruction/struct.RefreshBoost.html) instruction
+#[derive(Accounts)]
+pub struct RefreshBoost<'info> {
+    /// Global accounts for the staking instance.
+    #[account(mut)]
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Authority of user
+    owner: Signer<'info>,
+    /// Token account of the owner holding the boost mint or a token of the boost collection
+    #[account(
+        constraint = boost_token_account.owner == owner.key() @ ErrorCode::InvalidBoostToken,
+    )]
+    boost_token_account: Box<Account<'info, TokenAccount>>,
+    /// Metadata of the boost token mint. Required for collection boosts
+    /// CHECK: Metadata PDA and collection are checked in the instruction
+    boost_metadata: Option<UncheckedAccount<'info>>,
+}
+
+/// Accounts for [StripBoost](/dual_farming/instruction/struct.StripBoost.html) instruction
+#[derive(Accounts)]
+pub struct StripBoost<'info> {
+    /// Global accounts for the staking instance.
+    #[account(mut)]
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = pool,
+        constraint = user.boost_multiplier_bps > 0 @ ErrorCode::BoostNotActive,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Token account recorded when the boost was refreshed
+    /// CHECK: It may have been closed since, only its key is checked
+    #[account(address = user.boost_token_account)]
+    boost_token_account: UncheckedAccount<'info>,
+    /// Metadata of the boost token mint. Required for collection boosts while the token account exists
+    /// CHECK: Metadata PDA and collection are checked in the instruction
+    boost_metadata: Option<UncheckedAccount<'info>>,
+}
+
+/// Accounts for [CreateStakeCheckpoint](/dual_farming/instruction/struct.CreateStakeCheckpoint.html) instruction
+#[derive(Accounts)]
+#[instruction(checkpoint_id: u64)]
+pub struct CreateStakeCheckpoint<'info> {
+    /// Global accounts for the staking instance.
+    #[account(mut)]
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = pool,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Stake checkpoint
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            STAKE_CHECKPOINT_SEED,
+            user.key().as_ref(),
+            authority.key.as_ref(),
+            checkpoint_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+        space = STAKE_CHECKPOINT_SPACE,
+    )]
+    checkpoint: Box<Account<'info, StakeCheckpoint>>,
+    /// Authority of the checkpoint, usually a PDA of the program reading the snapshots
+    authority: Signer<'info>,
+    /// Payer of the checkpoint rent
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// Misc.
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [CloseStakeCheckpoint](/dual_farming/instruction/struct.CloseStakeCheckpoint.html) instruction
+#[derive(Accounts)]
+pub struct CloseStakeCheckpoint<'info> {
+    /// Stake checkpoint
+    #[account(
+        mut,
+        close = refundee,
+        has_one = authority,
+    )]
+    checkpoint: Box<Account<'info, StakeCheckpoint>>,
+    /// Authority of the checkpoint
+    authority: Signer<'info>,
+    /// Rent receiver
+    /// CHECK: Any account is able to receive the rent
+    #[account(mut)]
+    refundee: UncheckedAccount<'info>,
+}
+
+/// Accounts for [SnapshotStake](/dual_farming/instruction/struct.SnapshotStake.html) instruction
+#[derive(Accounts)]
+pub struct SnapshotStake<'info> {
+    /// Global accounts for the staking instance.
+    #[account(mut)]
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = pool,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Stake checkpoint starting the window. Without it the averages are the current balances
+    #[account(has_one = user @ ErrorCode::InvalidStakeWindow)]
+    checkpoint: Option<Box<Account<'info, StakeCheckpoint>>>,
+}
+
+/// Accounts for [CreateRegistrar](/dual_farming/instruction/struct.CreateRegistrar.html) instruction
+#[derive(Accounts)]
+pub struct CreateRegistrar<'info> {
+    /// Voter weight registrar of the realm and governing token mint
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            REGISTRAR_SEED,
+            realm.key().as_ref(),
+            governing_token_mint.key().as_ref(),
+        ],
+        bump,
+        space = 8 + 32 * 4 + 8 + 1,
+    )]
+    registrar: Box<Account<'info, Registrar>>,
+    /// spl-governance realm
+    /// CHECK: Owned by the governance program. Data is checked in the instruction
+    #[account(owner = governance_program_id.key())]
+    realm: UncheckedAccount<'info>,
+    /// spl-governance program owning the realm
+    /// CHECK: Any executable account, the realm owner is checked against it
+    #[account(executable)]
+    governance_program_id: UncheckedAccount<'info>,
+    /// Community or council mint of the realm
+    governing_token_mint: Box<Account<'info, Mint>>,
+    /// Authority of the realm
+    realm_authority: Signer<'info>,
+    /// Pool whose stake counts as voting power
+    pool: Box<Account<'info, Pool>>,
+    /// Payer of the registrar account
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// System program
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [CreateVoterWeightRecord](/dual_farming/instruction/struct.CreateVoterWeightRecord.html) instruction
+#[derive(Accounts)]
+pub struct CreateVoterWeightRecord<'info> {
+    /// Voter weight registrar
+    registrar: Box<Account<'info, Registrar>>,
+    /// Voter weight record of the governing token owner
+    #[account(
+        init,
+        payer = payer,
+        seeds = [
+            VOTER_WEIGHT_RECORD_SEED,
+            registrar.key().as_ref(),
+            governing_token_owner.key().as_ref(),
+        ],
+        bump,
+        space = 8 + 32 * 3 + 8 + 9 + 2 + 33 + 8,
+    )]
+    voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+    /// Owner of the stake
+    governing_token_owner: Signer<'info>,
+    /// Payer of the voter weight record account
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// System program
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [UpdateVoterWeightRecord](/dual_farming/instruction/struct.UpdateVoterWeightRecord.html) instruction
+#[derive(Accounts)]
+pub struct UpdateVoterWeightRecord<'info> {
+    /// Voter weight registrar
+    registrar: Box<Account<'info, Registrar>>,
+    /// Voter weight record of the governing token owner
+    #[account(
+        mut,
+        seeds = [
+            VOTER_WEIGHT_RECORD_SEED,
+            registrar.key().as_ref(),
+            voter_weight_record.governing_token_owner.as_ref(),
+        ],
+        bump,
+    )]
+    voter_weight_record: Box<Account<'info, VoterWeightRecord>>,
+    /// User stake account of the governing token owner in the registrar pool
+    #[account(
+        mut,
+        constraint = user.pool == registrar.pool @ ErrorCode::InvalidVoterUser,
+        constraint = user.owner == voter_weight_record.governing_token_owner @ ErrorCode::InvalidVoterUser,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Governing token owner, whose stake gets locked
+    #[account(address = voter_weight_record.governing_token_owner @ ErrorCode::InvalidVoterUser)]
+    governing_token_owner: Signer<'info>,
+}
+
+/// Accounts for [SetCompoundKeeper](/dual_farming/instruction/struct.SetCompoundKeeper.html) instruction.
+#[derive(Accounts)]
+pub struct SetCompoundKeeper<'info> {
+    /// Global accounts for the staking instance.
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Authority of user
+    owner: Signer<'info>,
+}
+
+/// Accounts for [SetReferralFee](/dual_farming/instruction/struct.SetReferralFee.html) instruction
+#[derive(Accounts)]
+pub struct SetReferralFee<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [SetEmissionSchedule](/dual_farming/instruction/struct.SetEmissionSchedule.html) instruction
+#[derive(Accounts)]
+pub struct SetEmissionSchedule<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = authority,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Authority of the pool
+    authority: Signer<'info>,
+}
+
+/// Accounts for [CreateReferrer](/dual_farming/instruction/struct.CreateReferrer.html) instruction
+#[derive(Accounts)]
+pub struct CreateReferrer<'info> {
+    /// Global accounts for the staking instance.
+    pool: Box<Account<'info, Pool>>,
+    /// Referrer
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            REFERRER_SEED,
+            pool.key().as_ref(),
+            owner.key.as_ref(),
+        ],
+        bump,
+        space = 8 + 32 * 2 + 8 * 2 + 1,
+    )]
+    referrer: Box<Account<'info, Referrer>>,
+    /// Wallet of the referrer
+    #[account(mut)]
+    owner: Signer<'info>,
+    /// Misc.
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [SetReferrer](/dual_farming/instruction/struct.SetReferrer.html) instruction
+#[derive(Accounts)]
+pub struct SetReferrer<'info> {
+    /// Global accounts for the staking instance.
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Referrer of the user in the pool
+    #[account(
+        has_one = pool,
+        constraint = referrer.owner != owner.key() @ ErrorCode::InvalidReferrer,
+    )]
+    referrer: Box<Account<'info, Referrer>>,
+    /// Referral of the user, kept when the user is closed
+    #[account(
+        init,
+        payer = owner,
+        seeds = [
+            REFERRAL_SEED,
+            pool.key().as_ref(),
+            owner.key.as_ref(),
+        ],
+        bump,
+        space = 8 + 32 * 3 + 1,
+    )]
+    referral: Box<Account<'info, Referral>>,
+    /// Authority of user
+    #[account(mut)]
+    owner: Signer<'info>,
+    /// Misc.
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [RestoreReferrer](/dual_farming/instruction/struct.RestoreReferrer.html) instruction
+#[derive(Accounts)]
+pub struct RestoreReferrer<'info> {
+    /// User.
+    #[account(mut)]
+    user: Box<Account<'info, User>>,
+    /// Referral of the user
+    #[account(
+        seeds = [
+            REFERRAL_SEED,
+            user.pool.as_ref(),
+            user.owner.as_ref(),
+        ],
+        bump = referral.bump,
+    )]
+    referral: Box<Account<'info, Referral>>,
+}
+
+/// Accounts for [ClaimReferralRewards](/dual_farming/instruction/struct.ClaimReferralRewards.html) instruction
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        has_one = reward_a_vault,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Reward A Vault PDA
+    #[account(mut)]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA. Required when reward B is a token of a dual reward pool
+    #[account(
+        mut,
+        address = pool.reward_b_vault,
+    )]
+    reward_b_vault: Option<Box<Account<'info, TokenAccount>>>,
+    /// Referrer
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            REFERRER_SEED,
+            pool.key().as_ref(),
+            owner.key.as_ref(),
+        ],
+        bump = referrer.bump,
+    )]
+    referrer: Box<Account<'info, Referrer>>,
+    /// Wallet of the referrer. Receives native rewards
+    #[account(mut)]
+    owner: Signer<'info>,
+    /// Referrer's Reward A account. Required when reward A is a token
+    #[account(mut)]
+    reward_a_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Referrer's Reward B account. Required when reward B is a token
+    #[account(mut)]
+    reward_b_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+    /// System program. Required when a reward is native SOL
+    system_program: Option<Program<'info, System>>,
+}
+
+/// Accounts for [SetClaimDelegate](/dual_farming/instruction/struct.SetClaimDelegate.html) instruction.
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    /// Global accounts for the staking instance.
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Authority of user
+    owner: Signer<'info>,
+}
+
+/// Accounts for [Compound](/dual_farming/instruction/struct.Compound.html) instruction.
+#[derive(Accounts)]
+pub struct Compound<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        constraint = !pool.is_attached_to_hub() @ ErrorCode::PoolAttachedToHub,
+        has_one = staking_vault,
+        has_one = reward_a_vault,
+        has_one = reward_b_vault,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Staking vault PDA.
+    #[account(mut)]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward A Vault PDA
+    #[account(mut)]
+    reward_a_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B Vault PDA
+    #[account(mut)]
+    reward_b_vault: Box<Account<'info, TokenAccount>>,
+
+    /// User.
+    #[account(
+        mut,
+        has_one = pool,
+        seeds = [
+            user.owner.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Owner of user or its compound keeper
+    #[account(
+        constraint = authority.key() == user.owner
+            || (user.compound_keeper != Pubkey::default() && authority.key() == user.compound_keeper)
+            @ ErrorCode::InvalidCompoundAuthority,
+    )]
+    authority: Signer<'info>,
+    /// Misc.
+    token_program: Program<'info, Token>,
+    /// Global config. Required when the pool charges a protocol fee
+    #[account(
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+    )]
+    global_config: Option<Box<Account<'info, GlobalConfig>>>,
+    /// Treasury Reward A ATA. Required when the pool charges a protocol fee
+    #[account(mut)]
+    treasury_a_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Treasury Reward B ATA. Required when the pool charges a protocol fee
+    #[account(mut)]
+    treasury_b_account: Option<Box<Account<'info, TokenAccount>>>,
+    /// Referrer of the user. Required when the user has one and the pool pays a referral share
+    #[account(
+        mut,
+        seeds = [
+            REFERRER_SEED,
+            pool.key().as_ref(),
+            user.referrer.as_ref(),
+        ],
+        bump = referrer.bump,
+    )]
+    referrer: Option<Box<Account<'info, Referrer>>>,
+}
+
+/// Accounts for [CloseUser](/dual_farming/instruction/struct.CloseUser.html) instruction
+#[derive(Accounts)]
+pub struct CloseUser<'info> {
+    #[account(mut)]
+    pool: Box<Account<'info, Pool>>,
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.to_account_info().key.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
+        constraint = user.balance_staked == 0,
+        constraint = user.reward_a_per_token_pending == 0,
+        constraint = user.reward_b_per_token_pending == 0,
+        constraint = user.balance_unbonding == 0,
+    )]
+    user: Account<'info, User>,
+    // To receive lamports when close the user account
+    #[account(mut)]
+    owner: Signer<'info>,
+}
+
+/// Accounts for [CloseDormantUser](/dual_farming/instruction/struct.CloseDormantUser.html) instruction
+#[derive(Accounts)]
+pub struct CloseDormantUser<'info> {
+    /// Global accounts for the staking instance.
+    #[account(
+        mut,
+        constraint = pool.reward_duration_end > 0,
+        constraint = pool.reward_duration_end < sysvar::clock::Clock::get().unwrap().unix_timestamp.try_into().unwrap() @ ErrorCode::CampaignActive,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// User.
+    #[account(
+        mut,
+        close = owner,
+        has_one = owner,
+        has_one = pool,
+        seeds = [
+            owner.key().as_ref(),
+            pool.key().as_ref()
+        ],
+        bump = user.nonce,
+        constraint = user.balance_staked == 0,
+        constraint = user.reward_a_per_token_pending == 0,
+        constraint = user.reward_b_per_token_pending == 0,
+        constraint = user.balance_unbonding == 0,
+    )]
+    user: Box<Account<'info, User>>,
+    /// Owner of the user, receiving the rent
+    /// CHECK: Checked by the user has_one constraint
+    #[account(mut)]
+    owner: UncheckedAccount<'info>,
+}
+
+/// Accounts for [MigrateFarmingRate](/dual_farming/instruction/struct.MigrateFarmingRate.html) instruction
+#[derive(Accounts)]
+pub struct MigrateFarmingRate<'info> {
+    #[account(mut)]
+    pool: Box<Account<'info, Pool>>,
+}
+
+/// Accounts for [MigrateUser](/dual_farming/instruction/struct.MigrateUser.html) instruction
+#[derive(Accounts)]
+pub struct MigrateUser<'info> {
+    /// User to migrate
+    /// CHECK: User account which may be too small to deserialize. Discriminator is checked in the instruction
+    #[account(mut, owner = crate::ID)]
+    user: UncheckedAccount<'info>,
+    /// Payer of the additional rent
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// System program
+    system_program: Program<'info, System>,
 }
 
-/// Accounts for [InitializePool](/dual_farming/instruction/struct.InitializePool.html) instruction
+/// Accounts for [MigratePool](/dual_farming/instruction/struct.MigratePool.html) instruction
 #[derive(Accounts)]
-#[instruction(reward_duration: u64)]
-pub struct InitializePool<'info> {
+pub struct MigratePool<'info> {
+    /// Pool to migrate
+    /// CHECK: Pool account which may be too small to deserialize. Discriminator is checked in the instruction
+    #[account(mut, owner = crate::ID)]
+    pool: UncheckedAccount<'info>,
+    /// Payer of the additional rent
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// System program
+    system_program: Program<'info, System>,
+}
+
+/// Accounts for [RegisterPool](/dual_farming/instruction/struct.RegisterPool.html) instruction
+#[derive(Accounts)]
+pub struct RegisterPool<'info> {
     /// Global accounts for the staking instance.
     #[account(
-        init,
+        mut,
+        constraint = !pool.registered @ ErrorCode::PoolAlreadyRegistered,
+    )]
+    pool: Box<Account<'info, Pool>>,
+    /// Registry of the pools of the staking mint
+    #[account(
+        init_if_needed,
         seeds = [
-            reward_duration.to_be_bytes().as_ref(),
-            staking_mint.key().as_ref(),
-            reward_a_mint.key().as_ref(),
-            reward_b_mint.key().as_ref(),
-            base.key().as_ref(),
+            POOL_REGISTRY_SEED,
+            pool.staking_mint.as_ref(),
         ],
-        payer = authority,
         bump,
-        space = 8 + 494 // discriminator + content + buffer
+        payer = payer,
+        space = PoolRegistry::init_space(pool_registry),
     )]
-    pool: Box<Account<'info, Pool>>,
-    /// Staking mint
-    staking_mint: Box<Account<'info, Mint>>,
+    pool_registry: Box<Account<'info, PoolRegistry>>,
+    /// Payer of the registry rent
+    #[account(mut)]
+    payer: Signer<'info>,
+    /// System program
+    system_program: Program<'info, System>,
+}
 
-    /// Staking vault PDA
+/// Accounts for [PrunePoolRegistry](/dual_farming/instruction/struct.PrunePoolRegistry.html) instruction
+#[derive(Accounts)]
+pub struct PrunePoolRegistry<'info> {
+    /// Global config
     #[account(
-        init,
+        seeds = [GLOBAL_CONFIG_SEED],
+        bump = global_config.bump,
+        has_one = admin @ ErrorCode::InvalidProtocolAdmin,
+    )]
+    global_config: Box<Account<'info, GlobalConfig>>,
+    /// Registry of the pools of the staking mint
+    #[account(
+        mut,
         seeds = [
-            b"staking",
-            pool.key().as_ref(),
+            POOL_REGISTRY_SEED,
+            pool_registry.staking_mint.as_ref(),
         ],
-        bump,
-        payer = authority,
-        token::mint = staking_mint,
-        token::authority = pool
+        bump = pool_registry.bump,
     )]
-    staking_vault: Box<Account<'info, TokenAccount>>,
-
-    /// Reward A mint
-    reward_a_mint: Box<Account<'info, Mint>>,
+    pool_registry: Box<Account<'info, PoolRegistry>>,
+    /// Protocol admin, receiving the rent excess of the registry
+    #[account(mut)]
+    admin: Signer<'info>,
+}
 
-    /// Reward A vault PDA
+/// Accounts for [InitializeStakeHub](/dual_farming/instruction/struct.InitializeStakeHub.html) instruction
+#[derive(Accounts)]
+pub struct InitializeStakeHub<'info> {
+    /// Stake hub
     #[account(
         init,
         seeds = [
-            b"reward_a",
-            pool.key().as_ref(),
+            STAKE_HUB_SEED,
+            staking_mint.key().as_ref(),
+            base.key().as_ref(),
         ],
         bump,
         payer = authority,
-        token::mint = reward_a_mint,
-        token::authority = pool
+        space = STAKE_HUB_SPACE,
     )]
-    reward_a_vault: Box<Account<'info, TokenAccount>>,
-
-    /// Reward B mint
-    reward_b_mint: Box<Account<'info, Mint>>,
-
-    /// Reward B vault PDA
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Staking mint
+    staking_mint: Box<Account<'info, Mint>>,
+    /// Staking vault PDA
     #[account(
         init,
         seeds = [
-            b"reward_b",
-            pool.key().as_ref(),
+            b"staking",
+            stake_hub.key().as_ref(),
         ],
         bump,
         payer = authority,
-        token::mint = reward_b_mint,
-        token::authority = pool
+        token::mint = staking_mint,
+        token::authority = stake_hub
     )]
-    reward_b_vault: Box<Account<'info, TokenAccount>>,
-
-    /// Authority of the pool
+    staking_vault: Box<Account<'info, TokenAccount>>,
+    /// Authority of the stake hub
     #[account(mut)]
     authority: Signer<'info>,
-
     /// Base
     base: Signer<'info>,
     /// System program
@@ -673,78 +4570,87 @@ pub struct InitializePool<'info> {
     rent: Sysvar<'info, Rent>,
 }
 
-/// Accounts for [CreateUser](/dual_farming/instruction/struct.CreateUser.html) instruction
+/// Accounts for [AttachPool](/dual_farming/instruction/struct.AttachPool.html) instruction
 #[derive(Accounts)]
-pub struct CreateUser<'info> {
-    /// Global accounts for the staking instance.
+pub struct AttachPool<'info> {
+    /// Stake hub
     #[account(
         mut,
-        constraint = !pool.paused,
+        has_one = authority,
+    )]
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Authority of the stake hub
+    authority: Signer<'info>,
+    /// Pool to attach
+    #[account(
+        mut,
+        has_one = staking_vault,
+        constraint = pool.authority == pool_authority.key(),
+        constraint = pool.staking_mint == stake_hub.staking_mint @ ErrorCode::InvalidStakeHubMint,
+        constraint = !pool.is_attached_to_hub() @ ErrorCode::PoolAttachedToHub,
+        constraint = pool.total_staked == 0 @ ErrorCode::PoolHasStake,
     )]
     pool: Box<Account<'info, Pool>>,
-    /// User
+    /// Staking vault of the pool
+    #[account(
+        constraint = staking_vault.amount == 0 @ ErrorCode::PoolHasStake,
+    )]
+    staking_vault: Box<Account<'info, TokenAccount>>,
+    /// Authority of the pool
+    pool_authority: Signer<'info>,
+}
+
+/// Accounts for [CreateHubUser](/dual_farming/instruction/struct.CreateHubUser.html) instruction
+#[derive(Accounts)]
+pub struct CreateHubUser<'info> {
+    /// Stake hub
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Hub user
     #[account(
         init,
         payer = owner,
         seeds = [
+            HUB_USER_SEED,
+            stake_hub.key().as_ref(),
             owner.key.as_ref(),
-            pool.to_account_info().key.as_ref()
         ],
         bump,
-        space = 200, //8 + 32 + 32 + 16 + 16 + 8 + 8 + 8 + 1 + buffer
+        space = HUB_USER_SPACE,
     )]
-    user: Box<Account<'info, User>>,
-    /// Authority of user account
+    hub_user: Box<Account<'info, HubUser>>,
+    /// Authority of hub user account
     #[account(mut)]
     owner: Signer<'info>,
     /// Misc.
     system_program: Program<'info, System>,
 }
 
-/// Accounts for [Pause](/dual_farming/instruction/struct.Pause.html) instruction
-#[derive(Accounts)]
-pub struct Pause<'info> {
-    /// Global accounts for the staking instance.
-    #[account(
-        mut,
-        has_one = authority,
-        constraint = !pool.paused,
-        constraint = pool.reward_duration_end < clock::Clock::get().unwrap().unix_timestamp.try_into().unwrap(),
-        constraint = pool.reward_duration_end > 0,
-    )]
-    pool: Box<Account<'info, Pool>>,
-    /// Authority of the pool
-    authority: Signer<'info>,
-}
-
-/// Accounts for [Unpause](/dual_farming/instruction/struct.Unpause.html) instruction
+/// Accounts for [JoinHubPool](/dual_farming/instruction/struct.JoinHubPool.html)
+/// and [LeaveHubPool](/dual_farming/instruction/struct.LeaveHubPool.html) instructions
 #[derive(Accounts)]
-pub struct Unpause<'info> {
-    /// Global accounts for the staking instance.
+pub struct HubPoolMembership<'info> {
+    /// Stake hub
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Hub user
     #[account(
         mut,
-        has_one = authority,
-        constraint = pool.paused,
+        has_one = owner,
+        has_one = stake_hub,
+        seeds = [
+            HUB_USER_SEED,
+            stake_hub.key().as_ref(),
+            owner.key.as_ref(),
+        ],
+        bump = hub_user.bump,
     )]
-    pool: Box<Account<'info, Pool>>,
-    /// Authority of the pool
-    authority: Signer<'info>,
-}
-
-/// Accounts for [Deposit](/dual_farming/instruction/struct.Deposit.html) and [Withdraw](/dual_farming/instruction/struct.Withdraw.html) instructions.
-#[derive(Accounts)]
-pub struct Deposit<'info> {
-    /// Global accounts for the deposit/withdraw instance.
+    hub_user: Box<Account<'info, HubUser>>,
+    /// Pool attached to the stake hub
     #[account(
         mut,
-        has_one = staking_vault,
+        constraint = pool.stake_hub == stake_hub.key() @ ErrorCode::PoolNotAttachedToHub,
     )]
     pool: Box<Account<'info, Pool>>,
-    /// Staking vault PDA.
-    #[account(mut)]
-    staking_vault: Box<Account<'info, TokenAccount>>,
-
-    /// User.
+    /// User of the pool
     #[account(
         mut,
         has_one = owner,
@@ -758,160 +4664,116 @@ pub struct Deposit<'info> {
     user: Box<Account<'info, User>>,
     /// Authority of user
     owner: Signer<'info>,
-    /// User staking ATA
-    #[account(mut)]
-    stake_from_account: Box<Account<'info, TokenAccount>>,
-    /// Misc.
-    token_program: Program<'info, Token>,
 }
 
-/// Accounts for [AuthorizeFunder](/dual_farming/instruction/struct.AuthorizeFunder.html)
-/// and [DeauthorizeFunder](/dual_farming/instruction/struct.DeauthorizeFunder.html) instructions.
+impl<'info> HubPoolMembership<'info> {
+    /// Slot of the pool in the stake hub
+    #[allow(clippy::result_large_err)]
+    fn slot(&self) -> Result<usize> {
+        self.stake_hub
+            .pools
+            .iter()
+            .position(|pool| *pool == self.pool.key())
+            .ok_or_else(|| ErrorCode::PoolNotAttachedToHub.into())
+    }
+}
+
+/// Accounts for [RemoveHubPoolMember](/dual_farming/instruction/struct.RemoveHubPoolMember.html) instruction
 #[derive(Accounts)]
-pub struct FunderChange<'info> {
-    /// Global accounts for the staking instance.
+pub struct RemoveHubPoolMember<'info> {
+    /// Stake hub
+    #[account(has_one = authority)]
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Authority of the stake hub
+    authority: Signer<'info>,
+    /// Hub user to remove
     #[account(
         mut,
-        has_one = authority,
+        has_one = stake_hub,
     )]
-    pool: Box<Account<'info, Pool>>,
-    /// Authority of the pool
-    authority: Signer<'info>,
-}
-
-/// Accounts for [Fund](/dual_farming/instruction/struct.Fund.html) instruction.
-#[derive(Accounts)]
-pub struct Fund<'info> {
-    /// Global accounts for the staking instance.
+    hub_user: Box<Account<'info, HubUser>>,
+    /// Pool attached to the stake hub
     #[account(
         mut,
-        has_one = staking_vault,
-        has_one = reward_a_vault,
-        has_one = reward_b_vault,
-        constraint = !pool.paused,
+        constraint = pool.stake_hub == stake_hub.key() @ ErrorCode::PoolNotAttachedToHub,
     )]
     pool: Box<Account<'info, Pool>>,
-    /// Staking vault PDA
-    #[account(mut)]
-    staking_vault: Box<Account<'info, TokenAccount>>,
-    /// Reward A Vault PDA
-    #[account(mut)]
-    reward_a_vault: Box<Account<'info, TokenAccount>>,
-    /// Reward B Vault PDA
-    #[account(mut)]
-    reward_b_vault: Box<Account<'info, TokenAccount>>,
-    /// Funder
+    /// User of the pool
     #[account(
-        //require signed funder auth - otherwise constant micro fund could hold funds hostage
-        constraint = funder.key() == pool.authority || pool.funders.iter().any(|x| *x == funder.key()),
+        mut,
+        has_one = pool,
+        constraint = user.owner == hub_user.owner,
+        seeds = [
+            hub_user.owner.as_ref(),
+            pool.to_account_info().key.as_ref()
+        ],
+        bump = user.nonce,
     )]
-    funder: Signer<'info>,
-    /// Funder reward A ATA
-    #[account(mut)]
-    from_a: Box<Account<'info, TokenAccount>>,
-    /// Funder reward B ATA
-    #[account(mut)]
-    from_b: Box<Account<'info, TokenAccount>>,
-    /// Misc.
-    token_program: Program<'info, Token>,
+    user: Box<Account<'info, User>>,
 }
 
-/// Accounts for [WithdrawExtraToken](/dual_farming/instruction/struct.WithdrawExtraToken.html) instruction
+impl<'info> RemoveHubPoolMember<'info> {
+    /// Slot of the pool in the stake hub
+    #[allow(clippy::result_large_err)]
+    fn slot(&self) -> Result<usize> {
+        self.stake_hub
+            .pools
+            .iter()
+            .position(|pool| *pool == self.pool.key())
+            .ok_or_else(|| ErrorCode::PoolNotAttachedToHub.into())
+    }
+}
+
+/// Accounts for [DetachPool](/dual_farming/instruction/struct.DetachPool.html) instruction
 #[derive(Accounts)]
-pub struct WithdrawExtraToken<'info> {
-    /// Global accounts for the staking instance.
+pub struct DetachPool<'info> {
+    /// Stake hub
     #[account(
-        has_one = staking_vault,
+        mut,
         has_one = authority,
-        constraint = pool.reward_duration_end < sysvar::clock::Clock::get().unwrap().unix_timestamp.try_into().unwrap(),
     )]
-    pool: Box<Account<'info, Pool>>,
-    /// Staking vault PDA
-    #[account(mut)]
-    staking_vault: Box<Account<'info, TokenAccount>>,
-    /// Token account to receive mistakenly deposited token
-    #[account(mut)]
-    withdraw_to_account: Box<Account<'info, TokenAccount>>,
-    /// Authority of the staking instance
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Authority of the stake hub
     authority: Signer<'info>,
-    /// Misc.
-    token_program: Program<'info, Token>,
+    /// Pool to detach
+    /// CHECK: Pool attached to the stake hub, which may have been closed. Checked in the instruction
+    #[account(mut)]
+    pool: UncheckedAccount<'info>,
 }
 
-/// Accounts for [Claim](/dual_farming/instruction/struct.Claim.html) instruction.
+/// Accounts for [HubDeposit](/dual_farming/instruction/struct.HubDeposit.html)
+/// and [HubWithdraw](/dual_farming/instruction/struct.HubWithdraw.html) instructions
 #[derive(Accounts)]
-pub struct ClaimReward<'info> {
-    /// Global accounts for the staking instance.
+pub struct HubStake<'info> {
+    /// Stake hub
     #[account(
         mut,
         has_one = staking_vault,
-        has_one = reward_a_vault,
-        has_one = reward_b_vault,
     )]
-    pool: Box<Account<'info, Pool>>,
-    /// Staking vault PDA.
+    stake_hub: Box<Account<'info, StakeHub>>,
+    /// Staking vault PDA
     #[account(mut)]
     staking_vault: Box<Account<'info, TokenAccount>>,
-    /// Reward A Vault PDA
-    #[account(mut)]
-    reward_a_vault: Box<Account<'info, TokenAccount>>,
-    /// Reward B Vault PDA
-    #[account(mut)]
-    reward_b_vault: Box<Account<'info, TokenAccount>>,
-
-    /// User.
-    #[account(
-        mut,
-        has_one = owner,
-        has_one = pool,
-        seeds = [
-            owner.to_account_info().key.as_ref(),
-            pool.to_account_info().key.as_ref()
-        ],
-        bump = user.nonce,
-    )]
-    user: Box<Account<'info, User>>,
-    /// Authority of user
-    owner: Signer<'info>,
-    /// User's Reward A ATA
-    #[account(mut)]
-    reward_a_account: Box<Account<'info, TokenAccount>>,
-    /// User's Reward B ATA
-    #[account(mut)]
-    reward_b_account: Box<Account<'info, TokenAccount>>,
-    // Misc.
-    token_program: Program<'info, Token>,
-}
-/// Accounts for [CloseUser](/dual_farming/instruction/struct.CloseUser.html) instruction
-#[derive(Accounts)]
-pub struct CloseUser<'info> {
-    #[account(mut)]
-    pool: Box<Account<'info, Pool>>,
+    /// Hub user
     #[account(
         mut,
-        close = owner,
         has_one = owner,
-        has_one = pool,
+        has_one = stake_hub,
         seeds = [
-            owner.to_account_info().key.as_ref(),
-            pool.to_account_info().key.as_ref()
+            HUB_USER_SEED,
+            stake_hub.key().as_ref(),
+            owner.key.as_ref(),
         ],
-        bump = user.nonce,
-        constraint = user.balance_staked == 0,
-        constraint = user.reward_a_per_token_pending == 0,
-        constraint = user.reward_b_per_token_pending == 0,
+        bump = hub_user.bump,
     )]
-    user: Account<'info, User>,
-    // To receive lamports when close the user account
-    #[account(mut)]
+    hub_user: Box<Account<'info, HubUser>>,
+    /// Authority of user
     owner: Signer<'info>,
-}
-
-/// Accounts for [MigrateFarmingRate](/dual_farming/instruction/struct.MigrateFarmingRate.html) instruction
-#[derive(Accounts)]
-pub struct MigrateFarmingRate<'info> {
+    /// The user token account to deposit from or withdraw to
     #[account(mut)]
-    pool: Box<Account<'info, Pool>>,
+    stake_from_account: Box<Account<'info, TokenAccount>>,
+    /// Misc.
+    token_program: Program<'info, Token>,
 }
 
 /// Accounts for [ClosePool](/dual_farming/instruction/struct.ClosePool.html) instruction
@@ -924,15 +4786,15 @@ pub struct ClosePool<'info> {
     staking_refundee: Box<Account<'info, TokenAccount>>,
     #[account(mut)]
     reward_a_refundee: Box<Account<'info, TokenAccount>>,
+    /// Token account to receive the reward B vault balance. Required when the pool has a reward B vault
     #[account(mut)]
-    reward_b_refundee: Box<Account<'info, TokenAccount>>,
+    reward_b_refundee: Option<Box<Account<'info, TokenAccount>>>,
     #[account(
         mut,
         close = refundee,
         has_one = authority,
         has_one = staking_vault,
         has_one = reward_a_vault,
-        has_one = reward_b_vault,
         constraint = pool.paused,
         constraint = pool.reward_duration_end > 0,
         constraint = pool.reward_duration_end < sysvar::clock::Clock::get().unwrap().unix_timestamp.try_into().unwrap(),
@@ -946,9 +4808,35 @@ pub struct ClosePool<'info> {
     staking_vault: Box<Account<'info, TokenAccount>>,
     #[account(mut)]
     reward_a_vault: Box<Account<'info, TokenAccount>>,
-    #[account(mut)]
-    reward_b_vault: Box<Account<'info, TokenAccount>>,
+    /// Reward B vault. Required when the pool has one separate from the reward A vault
+    #[account(
+        mut,
+        address = pool.reward_b_vault,
+    )]
+    reward_b_vault: Option<Box<Account<'info, TokenAccount>>>,
     token_program: Program<'info, Token>,
+    /// Registry of the pools of the staking mint. Required when the pool is registered
+    #[account(
+        mut,
+        seeds = [
+            POOL_REGISTRY_SEED,
+            pool.staking_mint.as_ref(),
+        ],
+        bump = pool_registry.bump,
+    )]
+    pool_registry: Option<Box<Account<'info, PoolRegistry>>>,
+    /// Native reward vault PDA. Required when a reward is native SOL
+    #[account(
+        mut,
+        seeds = [
+            NATIVE_REWARD_VAULT_SEED,
+            pool.key().as_ref(),
+        ],
+        bump = pool.native_vault_bump,
+    )]
+    native_vault: Option<SystemAccount<'info>>,
+    /// System program. Required when a reward is native SOL
+    system_program: Option<Program<'info, System>>,
 }
 
 /// Pool account wrapper
@@ -973,7 +4861,7 @@ pub struct Pool {
     pub reward_b_vault: Pubkey, // 32
     /// Base key
     pub base_key: Pubkey, // 32
-    /// The period which rewards are linearly distributed.
+    /// The period which rewards are linearly distributed by the next funding.
     pub reward_duration: u64, // 8
     /// The timestamp at which the current reward period ends.
     pub reward_duration_end: u64, // 8
@@ -1001,9 +4889,150 @@ pub struct Pool {
     pub pool_bump: u8, // 1
     /// Total staked amount
     pub total_staked: u64,
+    /// Cooldown before unbonding tokens can be withdrawn. Zero allows direct withdraw.
+    pub unbonding_period: u64,
+    /// Total amount waiting for the unbonding period to end
+    pub total_unbonding: u64,
+    /// Reward duration used in the pool PDA seeds. Zero for pools created before it was stored.
+    pub seed_reward_duration: u64,
+    /// Protocol fee on claimed rewards in basis points, snapshotted from the global config at creation
+    pub protocol_fee_bps: u16,
+    /// Whether the pool is listed in the registry of its staking mint
+    pub registered: bool,
+    /// Scale of reward rates and reward per token, from the mint decimals. Zero for pools created before it was stored.
+    pub precision: u128,
+    /// Emitted reward A, scaled by precision, not yet assigned to reward A per token
+    pub reward_a_per_token_remainder: u128,
+    /// Emitted reward B, scaled by precision, not yet assigned to reward B per token
+    pub reward_b_per_token_remainder: u128,
+    /// Funded reward A, scaled by precision, not covered by the reward A rate. Carried into the next funding.
+    pub reward_a_rate_remainder: u128,
+    /// Funded reward B, scaled by precision, not covered by the reward B rate. Carried into the next funding.
+    pub reward_b_rate_remainder: u128,
+    /// Mint whose holders get the boost. Default pubkey when unused.
+    pub boost_mint: Pubkey,
+    /// Verified collection whose holders get the boost. Default pubkey when unused.
+    pub boost_collection: Pubkey,
+    /// Multiplier of the stake of boosted users in basis points. Zero disables the boost.
+    pub boost_multiplier_bps: u16,
+    /// Extra stake of boosted users, counted in rewards but not in the staking vault
+    pub total_boost_stake: u64,
+    /// Cumulative total staked multiplied by the seconds it was staked
+    pub stake_seconds: u128,
+    /// The last time stake seconds were updated. Zero before the first update.
+    pub stake_seconds_updated_at: u64,
+    /// Whether reward A is paid in native SOL from the native reward vault
+    pub reward_a_native: bool,
+    /// Whether reward B is paid in native SOL from the native reward vault
+    pub reward_b_native: bool,
+    /// Native reward vault bump
+    pub native_vault_bump: u8,
+    /// Reward A owed by the pool, funded and neither refunded, claimed nor emitted while nothing was staked.
+    /// Reward A above it is surplus.
+    pub reward_a_obligation: u64,
+    /// Reward B owed by the pool, funded and neither refunded, claimed nor emitted while nothing was staked.
+    /// Reward B above it is surplus.
+    pub reward_b_obligation: u64,
+    /// Whether reward obligations are tracked. False for pools created before they were tracked.
+    pub reward_obligations_tracked: bool,
+    /// Whether the pool distributes one or two rewards. Unknown for pools created before it was stored.
+    pub reward_mode: RewardMode,
+    /// Stake hub holding the stake the pool distributes rewards on. Default pubkey when the pool holds its own stake.
+    pub stake_hub: Pubkey,
+    /// Seconds the stake of a user must stay in the pool before its rewards can be claimed. Zero disables it.
+    pub min_stake_duration: u64,
+    /// Share of the claimed rewards of referred users accrued to their referrer, in basis points
+    pub referral_fee_bps: u16,
+    /// Shape of the emission over the reward period. Flat for pools created before it was stored.
+    pub emission_curve: EmissionCurve,
+    /// Relative emission rate at the start of the reward period of a linear decay
+    pub decay_start_weight: u16,
+    /// Relative emission rate at the end of the reward period of a linear decay
+    pub decay_end_weight: u16,
+    /// Relative emission rates of a stepwise schedule. Unused steps are zeroed.
+    pub emission_steps: [EmissionStep; MAX_EMISSION_STEPS],
+    /// The timestamp at which the current reward period started. Zero for periods started before it was stored.
+    pub reward_period_start: u64,
+    /// Reward A, scaled by precision, emitted by the emission schedule over the current reward period
+    pub reward_a_scheduled: u128,
+    /// Reward B, scaled by precision, emitted by the emission schedule over the current reward period
+    pub reward_b_scheduled: u128,
 }
 
 impl Pool {
+    /// Whether the pool only distributes reward A
+    pub fn is_single_reward(&self) -> bool {
+        match self.reward_mode {
+            RewardMode::Single => true,
+            RewardMode::Dual => false,
+            RewardMode::Unknown => self.reward_a_mint == self.reward_b_mint,
+        }
+    }
+
+    /// Time the rewards of the user become claimable, the minimum stake duration after its last deposit.
+    /// The duration in force at that deposit applies, unless the pool duration was lowered since
+    pub fn rewards_claimable_at(&self, user: &User) -> u64 {
+        user.last_deposit_at
+            .saturating_add(self.min_stake_duration.min(user.min_stake_duration))
+    }
+
+    /// Whether rewards are emitted along a decaying or stepwise schedule instead of at flat rates
+    pub fn has_emission_schedule(&self) -> bool {
+        self.emission_curve != EmissionCurve::Flat
+    }
+
+    /// Used steps of the stepwise schedule, up to the first unused one
+    pub fn get_emission_steps(&self) -> &[EmissionStep] {
+        let len = self
+            .emission_steps
+            .iter()
+            .skip(1)
+            .position(|step| step.start_offset == 0)
+            .map_or(MAX_EMISSION_STEPS, |position| position.saturating_add(1));
+        &self.emission_steps[..len]
+    }
+
+    /// Whether the pool distributes rewards on the stake of a stake hub
+    pub fn is_attached_to_hub(&self) -> bool {
+        self.stake_hub != Pubkey::default()
+    }
+
+    /// Whether the pool has a reward B vault separate from the reward A vault
+    pub fn has_reward_b_vault(&self) -> bool {
+        self.reward_b_vault != self.reward_a_vault
+    }
+
+    /// return reward duration used to derive the pool address
+    pub fn get_seed_reward_duration(&self) -> u64 {
+        if self.seed_reward_duration == 0 {
+            return self.reward_duration;
+        }
+        self.seed_reward_duration
+    }
+
+    /// return precision of reward rates and reward per token
+    pub fn get_precision(&self) -> u128 {
+        if self.precision == 0 {
+            return PRECISION;
+        }
+        self.precision
+    }
+
+    /// return the unbonding period of the user, the pool period unless it was raised after the last deposit
+    pub fn get_unbonding_period(&self, user: &User) -> u64 {
+        self.unbonding_period.min(user.unbonding_period)
+    }
+
+    /// return the referral fee of the user, the pool fee unless it was raised after the last deposit
+    pub fn get_referral_fee_bps(&self, user: &User) -> u16 {
+        self.referral_fee_bps.min(user.referral_fee_bps)
+    }
+
+    /// return total stake weighting rewards, including boosts
+    pub fn get_total_effective_stake(&self) -> u64 {
+        self.total_staked.saturating_add(self.total_boost_stake)
+    }
+
     /// return reward a rate
     pub fn get_reward_a_rate(&self) -> u128 {
         if self.reward_a_rate_u128 == 0 {
@@ -1021,6 +5050,133 @@ impl Pool {
     }
 }
 
+/// Program-wide configuration
+#[account]
+#[derive(Debug)]
+pub struct GlobalConfig {
+    /// Protocol admin
+    pub admin: Pubkey,
+    /// Protocol fee on claimed rewards in basis points, applied to pools created afterward
+    pub protocol_fee_bps: u16,
+    /// Owner of the token accounts receiving the protocol fee
+    pub treasury: Pubkey,
+    /// Global config bump
+    pub bump: u8,
+}
+
+/// Pools sharing the same staking mint
+#[account]
+#[derive(Debug)]
+pub struct PoolRegistry {
+    /// Staking mint of the pools
+    pub staking_mint: Pubkey,
+    /// Pool registry bump
+    pub bump: u8,
+    /// Pools staking the mint
+    pub pools: Vec<Pubkey>,
+}
+
+impl PoolRegistry {
+    /// Account size without pools, discriminator + staking_mint + bump + vec length
+    const BASE_SPACE: usize = 8 + 32 + 1 + 4;
+
+    /// Account size for the given number of pools
+    pub fn space(pools_len: usize) -> usize {
+        Self::BASE_SPACE.saturating_add(pools_len.saturating_mul(32))
+    }
+
+    /// Account size expected by `init_if_needed`, empty when created and as reallocated since
+    pub fn init_space(registry: &AccountInfo) -> usize {
+        registry.data_len().max(Self::space(0))
+    }
+}
+
+/// Staked tokens shared by the reward pools attached to it
+#[account]
+#[derive(Debug)]
+pub struct StakeHub {
+    /// Authority allowed to attach and detach pools
+    pub authority: Pubkey,
+    /// Mint of the staked tokens
+    pub staking_mint: Pubkey,
+    /// Vault holding the staked tokens
+    pub staking_vault: Pubkey,
+    /// Base used to derive the stake hub address
+    pub base_key: Pubkey,
+    /// Stake hub bump
+    pub bump: u8,
+    /// Total staked in the hub
+    pub total_staked: u64,
+    /// Attached pools. Default pubkey for free slots.
+    pub pools: [Pubkey; MAX_HUB_POOLS],
+}
+
+/// Stake seconds of a user and its pool recorded on chain, starting a stake snapshot window
+#[account]
+#[derive(Debug)]
+pub struct StakeCheckpoint {
+    /// User the checkpoint belongs to
+    pub user: Pubkey,
+    /// Authority of the checkpoint
+    pub authority: Pubkey,
+    /// Checkpoint id, chosen by the authority
+    pub checkpoint_id: u64,
+    /// Time of the checkpoint
+    pub timestamp: u64,
+    /// Cumulative stake seconds of the user
+    pub stake_seconds: u128,
+    /// Cumulative stake seconds of the pool
+    pub total_stake_seconds: u128,
+    /// Stake checkpoint bump
+    pub bump: u8,
+}
+
+/// Stake of a user in a stake hub
+#[account]
+#[derive(Debug)]
+pub struct HubUser {
+    /// Stake hub the user belongs to
+    pub stake_hub: Pubkey,
+    /// The owner of this account
+    pub owner: Pubkey,
+    /// The amount staked in the hub
+    pub balance_staked: u64,
+    /// Pool the user earns the rewards of in each hub slot. Default pubkey when none.
+    pub joined_pools: [Pubkey; MAX_HUB_POOLS],
+    /// Hub user bump
+    pub bump: u8,
+}
+
+/// Referral rewards accrued by a referrer in a pool
+#[account]
+#[derive(Debug)]
+pub struct Referrer {
+    /// Pool the referrer belongs to
+    pub pool: Pubkey,
+    /// Wallet of the referrer
+    pub owner: Pubkey,
+    /// Reward A accrued and not claimed yet
+    pub reward_a_accrued: u64,
+    /// Reward B accrued and not claimed yet
+    pub reward_b_accrued: u64,
+    /// Referrer bump
+    pub bump: u8,
+}
+
+/// Referrer recorded by a user in a pool. Not closed with the user, so the referrer survives its recreation
+#[account]
+#[derive(Debug)]
+pub struct Referral {
+    /// Pool the user belongs to
+    pub pool: Pubkey,
+    /// Wallet of the user
+    pub owner: Pubkey,
+    /// Wallet of the referrer
+    pub referrer: Pubkey,
+    /// Referral bump
+    pub bump: u8,
+}
+
 /// Farming user account
 #[account]
 #[derive(Default)]
@@ -1041,6 +5197,202 @@ pub struct User {
     pub balance_staked: u64,
     /// Signer nonce.
     pub nonce: u8,
+    /// Keeper allowed to compound rewards on behalf of the owner.
+    pub compound_keeper: Pubkey,
+    /// The amount waiting for the unbonding period to end.
+    pub balance_unbonding: u64,
+    /// The timestamp at which the unbonding balance can be withdrawn.
+    pub unbonding_end: u64,
+    /// Earned reward A, scaled by precision, below one token unit. Carried into the next update.
+    pub reward_a_earned_remainder: u64,
+    /// Earned reward B, scaled by precision, below one token unit. Carried into the next update.
+    pub reward_b_earned_remainder: u64,
+    /// Boost multiplier of the user in basis points, snapshotted from the pool at refresh. Zero when not boosted.
+    pub boost_multiplier_bps: u16,
+    /// Extra stake given by the boost.
+    pub boost_stake: u64,
+    /// Token account proving the boost holding.
+    pub boost_token_account: Pubkey,
+    /// Cumulative balance staked multiplied by the seconds it was staked.
+    pub stake_seconds: u128,
+    /// The last time stake seconds were updated. Zero before the first update.
+    pub stake_seconds_updated_at: u64,
+    /// Delegate allowed to claim rewards on behalf of the owner.
+    pub claim_delegate: Pubkey,
+    /// Token account reward A must be paid to. Default pubkey when unset.
+    pub reward_a_destination: Pubkey,
+    /// Token account reward B must be paid to. Default pubkey when unset.
+    pub reward_b_destination: Pubkey,
+    /// The last time the user added stake. Zero before the first deposit.
+    pub last_deposit_at: u64,
+    /// Wallet of the referrer of the user. Default pubkey when unset.
+    pub referrer: Pubkey,
+    /// The stake cannot be withdrawn or transferred before this time, since it was used as voter weight.
+    pub vote_locked_until: u64,
+    /// Unbonding period of the pool at the last deposit of the user.
+    pub unbonding_period: u64,
+    /// Minimum stake duration of the pool at the last deposit of the user.
+    pub min_stake_duration: u64,
+    /// Referral fee of the pool at the last deposit of the user, in basis points.
+    pub referral_fee_bps: u16,
+}
+
+impl User {
+    /// return stake weighting rewards, including the boost
+    pub fn get_effective_stake(&self) -> u64 {
+        self.balance_staked.saturating_add(self.boost_stake)
+    }
+
+    /// return whether the stake is locked by a vote at the given time
+    pub fn is_vote_locked(&self, current_time: u64) -> bool {
+        current_time < self.vote_locked_until
+    }
+}
+
+/// Binding of an spl-governance realm and governing token mint to the pool whose stake is voting power
+#[account]
+#[derive(Debug)]
+pub struct Registrar {
+    /// spl-governance program owning the realm
+    pub governance_program_id: Pubkey,
+    /// Realm using the farm stake as voter weight
+    pub realm: Pubkey,
+    /// Community or council mint of the realm
+    pub governing_token_mint: Pubkey,
+    /// Pool whose stake counts as voting power
+    pub pool: Pubkey,
+    /// Time the stake of a voter stays locked after its voter weight is updated
+    pub vote_lock_duration: u64,
+    /// Registrar bump
+    pub bump: u8,
+}
+
+/// spl-governance voter weight record. The layout and discriminator match the spl-governance addin api.
+#[account]
+#[derive(Debug)]
+pub struct VoterWeightRecord {
+    /// Realm the record belongs to
+    pub realm: Pubkey,
+    /// Governing token mint the record belongs to
+    pub governing_token_mint: Pubkey,
+    /// Owner of the voting power
+    pub governing_token_owner: Pubkey,
+    /// Voter weight
+    pub voter_weight: u64,
+    /// Slot after which the voter weight is stale
+    pub voter_weight_expiry: Option<u64>,
+    /// Governance action the voter weight is valid for. Any action when none.
+    pub weight_action: Option<VoterWeightAction>,
+    /// Target of the governance action. Any target when none.
+    pub weight_action_target: Option<Pubkey>,
+    /// Reserved space
+    pub reserved: [u8; 8],
+}
+
+/// spl-governance action a voter weight is used for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VoterWeightAction {
+    /// Cast vote on a proposal
+    CastVote,
+    /// Comment on a proposal
+    CommentProposal,
+    /// Create a governance
+    CreateGovernance,
+    /// Create a proposal
+    CreateProposal,
+    /// Sign off a proposal
+    SignOffProposal,
+}
+
+/// Stake seconds of a user and its pool at a point in time
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StakeSnapshot {
+    /// Time of the snapshot
+    pub timestamp: u64,
+    /// Cumulative stake seconds of the user
+    pub stake_seconds: u128,
+    /// Cumulative stake seconds of the pool
+    pub total_stake_seconds: u128,
+    /// Average balance staked by the user since the checkpoint
+    pub average_balance: u64,
+    /// Average total staked in the pool since the checkpoint
+    pub average_total_staked: u64,
+}
+
+/// Number of rewards distributed by a pool
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RewardMode {
+    /// Pool created before the reward mode was stored, single reward when both reward mints are the same
+    #[default]
+    Unknown,
+    /// Rewards A and B
+    Dual,
+    /// Reward A only
+    Single,
+}
+
+/// Shape of the emission over the reward period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EmissionCurve {
+    /// Constant rate
+    #[default]
+    Flat,
+    /// Rate moving linearly from the decay start weight to the decay end weight
+    LinearDecay,
+    /// Rate following the emission steps
+    Stepwise,
+}
+
+/// Step of a stepwise emission schedule
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct EmissionStep {
+    /// Seconds from the start of the reward period at which the step starts
+    pub start_offset: u64,
+    /// Emission rate of the step, relative to the other steps
+    pub weight: u16,
+}
+
+/// Emission schedule of the fundings of a pool. Weights are relative rates, the funded amount is spread over the reward period in their proportions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum EmissionSchedule {
+    /// Constant rate
+    Flat,
+    /// Rate moving linearly from the start weight to the end weight over the reward period
+    LinearDecay {
+        /// Relative rate at the start of the reward period
+        start_weight: u16,
+        /// Relative rate at the end of the reward period
+        end_weight: u16,
+    },
+    /// Rate of each step until the next one starts, the last step lasting until the end of the reward period
+    Stepwise {
+        /// Steps in increasing start offsets, the first one starting at offset zero
+        steps: Vec<EmissionStep>,
+    },
+}
+
+/// How funding affects the reward rate and the end of the reward period
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FundMode {
+    /// Spread leftover and new funds over a full reward duration starting now
+    Reset {
+        /// Amount of reward A to fund
+        amount_a: u64,
+        /// Amount of reward B to fund
+        amount_b: u64,
+    },
+    /// Keep the current end time and raise the rate for the remaining period
+    TopUp {
+        /// Amount of reward A to fund
+        amount_a: u64,
+        /// Amount of reward B to fund
+        amount_b: u64,
+    },
+    /// Push the end time by the given seconds at the current rate, funding the required amounts
+    Extend {
+        /// Seconds added to the reward period
+        seconds: u64,
+    },
 }
 
 /// Deposit event
@@ -1055,11 +5407,40 @@ pub struct EventWithdraw {
     amount: u64,
 }
 
+/// Request withdraw event
+#[event]
+pub struct EventRequestWithdraw {
+    amount: u64,
+    unbonding_end: u64,
+}
+
+/// Complete withdraw event
+#[event]
+pub struct EventCompleteWithdraw {
+    amount: u64,
+}
+
 /// Fund event
 #[event]
 pub struct EventFund {
     amount_a: u64,
     amount_b: u64,
+    undistributable_a: u64,
+    undistributable_b: u64,
+}
+
+/// End campaign event
+#[event]
+pub struct EventEndCampaign {
+    refund_a: u64,
+    refund_b: u64,
+    end_time: u64,
+}
+
+/// Set reward duration event
+#[event]
+pub struct EventSetRewardDuration {
+    reward_duration: u64,
 }
 
 /// Claim event
@@ -1069,6 +5450,238 @@ pub struct EventClaim {
     amount_b: u64,
 }
 
+/// Claim result of a pool in a claim many instruction
+#[event]
+pub struct EventClaimManyResult {
+    pool: Pubkey,
+    owner: Pubkey,
+    amount_a: u64,
+    amount_b: u64,
+}
+
+/// Attach pool to stake hub event
+#[event]
+pub struct EventAttachPool {
+    stake_hub: Pubkey,
+    pool: Pubkey,
+}
+
+/// Detach pool from stake hub event
+#[event]
+pub struct EventDetachPool {
+    stake_hub: Pubkey,
+    pool: Pubkey,
+}
+
+/// Join hub pool event
+#[event]
+pub struct EventJoinHubPool {
+    owner: Pubkey,
+    pool: Pubkey,
+    amount: u64,
+}
+
+/// Leave hub pool event
+#[event]
+pub struct EventLeaveHubPool {
+    owner: Pubkey,
+    pool: Pubkey,
+    amount: u64,
+}
+
+/// Stake hub deposit event
+#[event]
+pub struct EventHubDeposit {
+    owner: Pubkey,
+    amount: u64,
+}
+
+/// Stake hub withdraw event
+#[event]
+pub struct EventHubWithdraw {
+    owner: Pubkey,
+    amount: u64,
+}
+
+/// Forfeit rewards event
+#[event]
+pub struct EventForfeitRewards {
+    owner: Pubkey,
+    amount_a: u64,
+    amount_b: u64,
+}
+
+/// Set referral fee event
+#[event]
+pub struct EventSetReferralFee {
+    referral_fee_bps: u16,
+}
+
+/// Set emission schedule event
+#[event]
+pub struct EventSetEmissionSchedule {
+    schedule: EmissionSchedule,
+}
+
+/// Set referrer event
+#[event]
+pub struct EventSetReferrer {
+    owner: Pubkey,
+    referrer: Pubkey,
+}
+
+/// Referral rewards accrued on a claim event
+#[event]
+pub struct EventReferralAccrued {
+    referrer: Pubkey,
+    amount_a: u64,
+    amount_b: u64,
+}
+
+/// Claim referral rewards event
+#[event]
+pub struct EventClaimReferralRewards {
+    owner: Pubkey,
+    amount_a: u64,
+    amount_b: u64,
+}
+
+/// Compound event
+#[event]
+pub struct EventCompound {
+    amount_a: u64,
+    amount_b: u64,
+}
+
+/// Position sent to another owner event
+#[event]
+pub struct EventPositionSent {
+    owner: Pubkey,
+    recipient: Pubkey,
+    amount: u64,
+}
+
+/// Position received from another owner event
+#[event]
+pub struct EventPositionReceived {
+    owner: Pubkey,
+    sender: Pubkey,
+    amount: u64,
+}
+
+/// Set compound keeper event
+#[event]
+pub struct EventSetCompoundKeeper {
+    keeper: Pubkey,
+}
+
+/// Set claim delegate event
+#[event]
+pub struct EventSetClaimDelegate {
+    owner: Pubkey,
+    delegate: Pubkey,
+    reward_a_destination: Pubkey,
+    reward_b_destination: Pubkey,
+}
+
+/// Set boost config event
+#[event]
+pub struct EventSetBoostConfig {
+    boost_mint: Pubkey,
+    boost_collection: Pubkey,
+    boost_multiplier_bps: u16,
+}
+
+/// Withdraw extra reward event
+#[event]
+pub struct EventWithdrawExtraReward {
+    amount_a: u64,
+    amount_b: u64,
+    amount_native: u64,
+}
+
+/// Close dormant user event
+#[event]
+pub struct EventCloseDormantUser {
+    owner: Pubkey,
+}
+
+/// Enable native reward event
+#[event]
+pub struct EventEnableNativeReward {
+    reward_a: bool,
+    reward_b: bool,
+}
+
+/// Refresh boost event
+#[event]
+pub struct EventRefreshBoost {
+    owner: Pubkey,
+    boost_multiplier_bps: u16,
+    boost_stake: u64,
+}
+
+/// Strip boost event
+#[event]
+pub struct EventStripBoost {
+    owner: Pubkey,
+}
+
+/// Create stake checkpoint event
+#[event]
+pub struct EventCreateStakeCheckpoint {
+    owner: Pubkey,
+    authority: Pubkey,
+    checkpoint_id: u64,
+    timestamp: u64,
+}
+
+/// Snapshot stake event
+#[event]
+pub struct EventSnapshotStake {
+    owner: Pubkey,
+    snapshot: StakeSnapshot,
+}
+
+/// Create registrar event
+#[event]
+pub struct EventCreateRegistrar {
+    realm: Pubkey,
+    governing_token_mint: Pubkey,
+    pool: Pubkey,
+    vote_lock_duration: u64,
+}
+
+/// Update voter weight event
+#[event]
+pub struct EventUpdateVoterWeight {
+    owner: Pubkey,
+    voter_weight: u64,
+    vote_locked_until: u64,
+}
+
+/// Protocol fee event
+#[event]
+pub struct EventProtocolFee {
+    amount_a: u64,
+    amount_b: u64,
+}
+
+/// Global config update event
+#[event]
+pub struct EventUpdateGlobalConfig {
+    admin: Pubkey,
+    protocol_fee_bps: u16,
+    treasury: Pubkey,
+}
+
+/// Pool registry prune event
+#[event]
+pub struct EventPrunePoolRegistry {
+    staking_mint: Pubkey,
+    pool: Pubkey,
+}
+
 /// Authorized funder event
 #[event]
 pub struct EventAuthorizeFunder {
@@ -1114,6 +5727,179 @@ pub enum ErrorCode {
     /// Math opeartion overflow
     #[msg("Math operation overflow")]
     MathOverflow,
+    /// Pool has no reward with the same mint as the staking mint.
+    #[msg("Pool has no reward with the same mint as the staking mint.")]
+    NoCompoundableReward,
+    /// Signer is neither the user owner nor its compound keeper.
+    #[msg("Signer is neither the user owner nor its compound keeper.")]
+    InvalidCompoundAuthority,
+    /// Pool has an unbonding period, withdraw must be requested first.
+    #[msg("Pool has an unbonding period, withdraw must be requested first.")]
+    UnbondingRequired,
+    /// Unbonding period is not finished.
+    #[msg("Unbonding period is not finished.")]
+    UnbondingNotFinished,
+    /// Campaign is not running.
+    #[msg("Campaign is not running.")]
+    CampaignNotActive,
+    /// Campaign is still running.
+    #[msg("Campaign is still running.")]
+    CampaignActive,
+    /// Protocol fee exceeds the maximum.
+    #[msg("Protocol fee exceeds the maximum.")]
+    InvalidProtocolFee,
+    /// Signer is not the protocol admin.
+    #[msg("Signer is not the protocol admin.")]
+    InvalidProtocolAdmin,
+    /// Treasury account is required to pay the protocol fee.
+    #[msg("Treasury account is required to pay the protocol fee.")]
+    MissingTreasuryAccount,
+    /// Treasury account does not belong to the treasury or has the wrong mint.
+    #[msg("Treasury account does not belong to the treasury or has the wrong mint.")]
+    InvalidTreasuryAccount,
+    /// Account is not a pool.
+    #[msg("Account is not a pool.")]
+    InvalidPoolAccount,
+    /// Pool registry is required to close a registered pool.
+    #[msg("Pool registry is required to close a registered pool.")]
+    MissingPoolRegistry,
+    /// Position cannot be transferred to its current owner.
+    #[msg("Position cannot be transferred to its current owner.")]
+    InvalidPositionRecipient,
+    /// Funding is too small to emit any reward over the reward period.
+    #[msg("Funding is too small to emit any reward over the reward period.")]
+    FundingTooSmall,
+    /// Account is not a user account.
+    #[msg("Account is not a user account.")]
+    InvalidUserAccount,
+    /// Boost multiplier must be above 1x and at most the maximum, with a mint or a collection.
+    #[msg(
+        "Boost multiplier must be above 1x and at most the maximum, with a mint or a collection."
+    )]
+    InvalidBoostConfig,
+    /// Pool has no boost configured.
+    #[msg("Pool has no boost configured.")]
+    BoostNotConfigured,
+    /// Token account does not hold the boost mint or a token of the boost collection.
+    #[msg("Token account does not hold the boost mint or a token of the boost collection.")]
+    InvalidBoostToken,
+    /// User has no boost.
+    #[msg("User has no boost.")]
+    BoostNotActive,
+    /// Boost token is still held.
+    #[msg("Boost token is still held.")]
+    BoostStillHeld,
+    /// Stake checkpoint is not an earlier checkpoint of this user.
+    #[msg("Stake checkpoint is not an earlier checkpoint of this user.")]
+    InvalidStakeWindow,
+    /// Account is not a realm of the governance program or does not use the governing token mint.
+    #[msg("Account is not a realm of the governance program or does not use the governing token mint.")]
+    InvalidRealm,
+    /// Signer is not the realm authority.
+    #[msg("Signer is not the realm authority.")]
+    InvalidRealmAuthority,
+    /// User account does not belong to the registrar pool and the voter.
+    #[msg("User account does not belong to the registrar pool and the voter.")]
+    InvalidVoterUser,
+    /// Native reward requires the native mint and a pool not funded yet.
+    #[msg("Native reward requires the native mint and a pool not funded yet.")]
+    InvalidNativeReward,
+    /// Native reward vault and system program are required for a native reward.
+    #[msg("Native reward vault and system program are required for a native reward.")]
+    MissingNativeRewardAccounts,
+    /// Account sending or receiving a native reward must be writable.
+    #[msg("Account sending or receiving a native reward must be writable.")]
+    NativeRewardAccountNotWritable,
+    /// Token account is required for a token reward.
+    #[msg("Token account is required for a token reward.")]
+    MissingRewardTokenAccount,
+    /// Pool was created before reward obligations were tracked.
+    #[msg("Pool was created before reward obligations were tracked.")]
+    RewardObligationsUntracked,
+    /// Reward vaults hold nothing above the reward obligations.
+    #[msg("Reward vaults hold nothing above the reward obligations.")]
+    NoRewardSurplus,
+    /// Reward B vault is required for a dual reward pool.
+    #[msg("Reward B vault is required for a dual reward pool.")]
+    MissingRewardVault,
+    /// Signer is neither the user owner nor its claim delegate.
+    #[msg("Signer is neither the user owner nor its claim delegate.")]
+    InvalidClaimAuthority,
+    /// Reward destination is not the one registered by the user.
+    #[msg("Reward destination is not the one registered by the user.")]
+    InvalidRewardDestination,
+    /// Claim many accounts are empty or hold too many pools.
+    #[msg("Claim many accounts are empty or hold too many pools.")]
+    InvalidClaimManyAccounts,
+    /// Pool stake is held by a stake hub.
+    #[msg("Pool stake is held by a stake hub.")]
+    PoolAttachedToHub,
+    /// Pool is not attached to the stake hub.
+    #[msg("Pool is not attached to the stake hub.")]
+    PoolNotAttachedToHub,
+    /// Pool still holds stake.
+    #[msg("Pool still holds stake.")]
+    PoolHasStake,
+    /// Stake hub has no free pool slot.
+    #[msg("Stake hub has no free pool slot.")]
+    StakeHubFull,
+    /// Pool staking mint differs from the stake hub staking mint.
+    #[msg("Pool staking mint differs from the stake hub staking mint.")]
+    InvalidStakeHubMint,
+    /// User already earns from the hub pool.
+    #[msg("User already earns from the hub pool.")]
+    HubPoolAlreadyJoined,
+    /// User does not earn from the hub pool.
+    #[msg("User does not earn from the hub pool.")]
+    HubPoolNotJoined,
+    /// Hub pool accounts do not match the pools joined by the user.
+    #[msg("Hub pool accounts do not match the pools joined by the user.")]
+    InvalidHubPoolAccounts,
+    /// Stake has not been in the pool for the minimum stake duration.
+    #[msg("Stake has not been in the pool for the minimum stake duration.")]
+    MinStakeDurationNotReached,
+    /// Referral fee is above the maximum.
+    #[msg("Referral fee is above the maximum.")]
+    InvalidReferralFee,
+    /// Referrer is already set.
+    #[msg("Referrer is already set.")]
+    ReferrerAlreadySet,
+    /// User cannot refer itself.
+    #[msg("User cannot refer itself.")]
+    InvalidReferrer,
+    /// Referrer account is required to claim.
+    #[msg("Referrer account is required to claim.")]
+    MissingReferrerAccount,
+    /// Emission schedule is invalid.
+    #[msg("Emission schedule is invalid.")]
+    InvalidEmissionSchedule,
+    /// Pools with an emission schedule can only be funded over a new reward period.
+    #[msg("Pools with an emission schedule can only be funded over a new reward period.")]
+    EmissionScheduleRequiresReset,
+    /// Pool registry is full.
+    #[msg("Pool registry is full.")]
+    PoolRegistryFull,
+    /// Pool is already registered.
+    #[msg("Pool is already registered.")]
+    PoolAlreadyRegistered,
+    /// Pool is not in the registry.
+    #[msg("Pool is not in the registry.")]
+    PoolNotInRegistry,
+    /// Stake is locked until the vote it was used for ends.
+    #[msg("Stake is locked until the vote it was used for ends.")]
+    StakeVoteLocked,
+    /// Metadata of the boost token mint is required to check a collection boost.
+    #[msg("Metadata of the boost token mint is required to check a collection boost.")]
+    MissingBoostMetadata,
+    /// Unbonding period is longer than the maximum.
+    #[msg("Unbonding period is longer than the maximum.")]
+    UnbondingPeriodTooLong,
+    /// Minimum stake duration is longer than the maximum.
+    #[msg("Minimum stake duration is longer than the maximum.")]
+    MinStakeDurationTooLong,
+    /// Reward B vault must not be passed for a single reward pool.
+    #[msg("Reward B vault must not be passed for a single reward pool.")]
+    UnexpectedRewardVault,
 }
 
 impl Debug for User {
@@ -1138,6 +5924,15 @@ impl Debug for User {
 mod pool_test {
     use super::*;
     use std::str::FromStr;
+    #[test]
+    fn test_voter_weight_record_discriminator() {
+        // spl_governance_addin_api::voter_weight::VoterWeightRecord::ACCOUNT_DISCRIMINATOR
+        assert_eq!(
+            VoterWeightRecord::DISCRIMINATOR,
+            [46, 249, 155, 75, 153, 248, 116, 9]
+        );
+    }
+
     #[test]
     fn test_get_pool_address() {
         let reward_duration = 10u64;
@@ -0,0 +1,169 @@
+use anchor_lang::prelude::*;
+use std::convert::{TryFrom, TryInto};
+
+/// Metaplex token metadata program
+pub const TOKEN_METADATA_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Size of a creator entry, address + verified + share
+const CREATOR_SIZE: usize = 32 + 1 + 1;
+
+/// Sequential reader over borsh encoded account data
+pub(crate) struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(data: &'a [u8]) -> Self {
+        Reader { data, offset: 0 }
+    }
+
+    pub(crate) fn take(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.offset.checked_add(len)?;
+        let bytes = self.data.get(self.offset..end)?;
+        self.offset = end;
+        Some(bytes)
+    }
+
+    pub(crate) fn u8(&mut self) -> Option<u8> {
+        self.take(1).map(|bytes| bytes[0])
+    }
+
+    pub(crate) fn u32(&mut self) -> Option<u32> {
+        let bytes = self.take(4)?;
+        Some(u32::from_le_bytes(bytes.try_into().ok()?))
+    }
+
+    pub(crate) fn pubkey(&mut self) -> Option<Pubkey> {
+        Pubkey::try_from(self.take(32)?).ok()
+    }
+
+    pub(crate) fn option_pubkey(&mut self) -> Option<Option<Pubkey>> {
+        if self.u8()? == 1 {
+            return Some(Some(self.pubkey()?));
+        }
+        Some(None)
+    }
+
+    /// Skips an optional value of the given size
+    pub(crate) fn skip_option(&mut self, len: usize) -> Option<()> {
+        if self.u8()? == 1 {
+            self.take(len)?;
+        }
+        Some(())
+    }
+}
+
+/// Verified collection of mint in the metadata account data, if any
+pub fn parse_verified_collection(data: &[u8], mint: &Pubkey) -> Option<Pubkey> {
+    let mut reader = Reader::new(data);
+    // key, update authority
+    reader.take(1 + 32)?;
+    if reader.pubkey()? != *mint {
+        return None;
+    }
+    // name, symbol, uri
+    for _ in 0..3 {
+        let len = reader.u32()?;
+        reader.take(len.try_into().ok()?)?;
+    }
+    // seller fee basis points
+    reader.take(2)?;
+    // creators
+    if reader.u8()? == 1 {
+        let len: usize = reader.u32()?.try_into().ok()?;
+        reader.take(len.checked_mul(CREATOR_SIZE)?)?;
+    }
+    // primary sale happened, is mutable
+    reader.take(2)?;
+    // edition nonce, token standard
+    reader.skip_option(1)?;
+    reader.skip_option(1)?;
+    // collection
+    if reader.u8()? != 1 {
+        return None;
+    }
+    let verified = reader.u8()? == 1;
+    let collection = reader.pubkey()?;
+    if verified {
+        Some(collection)
+    } else {
+        None
+    }
+}
+
+/// Verified collection of mint, read from its metadata account
+pub fn verified_collection(metadata: &AccountInfo, mint: &Pubkey) -> Option<Pubkey> {
+    if *metadata.owner != TOKEN_METADATA_PROGRAM_ID {
+        return None;
+    }
+    let (metadata_address, _bump) = Pubkey::find_program_address(
+        &[
+            b"metadata",
+            TOKEN_METADATA_PROGRAM_ID.as_ref(),
+            mint.as_ref(),
+        ],
+        &TOKEN_METADATA_PROGRAM_ID,
+    );
+    if metadata_address != metadata.key() {
+        return None;
+    }
+    let data = metadata.try_borrow_data().ok()?;
+    parse_verified_collection(&data, mint)
+}
+
+#[cfg(test)]
+mod metadata_test {
+    use super::*;
+
+    fn metadata_data(mint: &Pubkey, collection: Option<(bool, Pubkey)>) -> Vec<u8> {
+        let mut data = vec![4u8];
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(mint.as_ref());
+        for field in ["name", "SYM", "https://example.com"] {
+            data.extend_from_slice(&(field.len() as u32).to_le_bytes());
+            data.extend_from_slice(field.as_bytes());
+        }
+        data.extend_from_slice(&500u16.to_le_bytes());
+        // one creator
+        data.push(1);
+        data.extend_from_slice(&1u32.to_le_bytes());
+        data.extend_from_slice(&[7u8; CREATOR_SIZE]);
+        data.extend_from_slice(&[1, 1]);
+        // edition nonce set, token standard unset
+        data.extend_from_slice(&[1, 255, 0]);
+        match collection {
+            Some((verified, key)) => {
+                data.push(1);
+                data.push(verified as u8);
+                data.extend_from_slice(key.as_ref());
+            }
+            None => data.push(0),
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse_verified_collection() {
+        let mint = Pubkey::new_unique();
+        let collection = Pubkey::new_unique();
+
+        let data = metadata_data(&mint, Some((true, collection)));
+        assert_eq!(parse_verified_collection(&data, &mint), Some(collection));
+        assert_eq!(
+            parse_verified_collection(&data, &Pubkey::new_unique()),
+            None
+        );
+        assert_eq!(
+            parse_verified_collection(&data[..data.len() - 1], &mint),
+            None
+        );
+
+        let data = metadata_data(&mint, Some((false, collection)));
+        assert_eq!(parse_verified_collection(&data, &mint), None);
+
+        let data = metadata_data(&mint, None);
+        assert_eq!(parse_verified_collection(&data, &mint), None);
+    }
+}
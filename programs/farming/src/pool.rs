@@ -1,62 +1,322 @@
 pub use crate::*;
-use spl_math::uint::U192;
+use spl_math::uint::{U192, U256};
 
-/// Rate by funding
-fn calculate_reward_rate(funding_amount: u64, reward_duration: u64) -> Option<u128> {
-    let funding_amount: u128 = funding_amount.into();
-    let reward_duration: u128 = reward_duration.into();
-    let reward_rate = funding_amount
-        .checked_mul(PRECISION)?
-        .checked_div(reward_duration)?;
-    Some(reward_rate)
+/// Precision exponent when the staking and reward mints have the same decimals
+const PRECISION_BASE_EXPONENT: u32 = 12;
+/// Lowest precision exponent, matching the legacy precision
+const MIN_PRECISION_EXPONENT: u32 = 9;
+/// Highest precision exponent, keeping u64::MAX funding within a u128 rate
+const MAX_PRECISION_EXPONENT: u32 = 18;
+
+/// Precision scale of a pool from its mint decimals.
+/// Staking tokens with more decimals than the rewards need a larger scale for
+/// reward per token to not truncate to zero.
+pub fn precision_from_decimals(
+    staking_decimals: u8,
+    reward_a_decimals: u8,
+    reward_b_decimals: u8,
+) -> u128 {
+    let reward_decimals = u32::from(reward_a_decimals.min(reward_b_decimals));
+    let exponent = PRECISION_BASE_EXPONENT
+        .saturating_add(staking_decimals.into())
+        .saturating_sub(reward_decimals)
+        .clamp(MIN_PRECISION_EXPONENT, MAX_PRECISION_EXPONENT);
+    10u128.pow(exponent)
+}
+
+/// Rate by funding plus a carried scaled remainder.
+/// Returns the rate and the scaled funding not covered by it.
+fn calculate_reward_rate(
+    funding_amount: u64,
+    reward_duration: u64,
+    precision: u128,
+    carried_remainder: u128,
+) -> Option<(u128, u128)> {
+    let scaled_amount = U192::from(funding_amount)
+        .checked_mul(precision.into())?
+        .checked_add(carried_remainder.into())?;
+    let reward_duration = U192::from(reward_duration);
+    let reward_rate = scaled_amount.checked_div(reward_duration)?;
+    let remainder = scaled_amount.checked_sub(reward_rate.checked_mul(reward_duration)?)?;
+    Some((reward_rate.try_into().ok()?, remainder.try_into().ok()?))
 }
 
-/// Calculate reward per token
+/// Amount emitted at rate during duration, rounded down, with the scaled remainder
+fn emitted_amount(reward_rate: u128, duration: u64, precision: u128) -> Option<(u64, u128)> {
+    unscaled_amount(
+        U192::from(reward_rate).checked_mul(duration.into())?,
+        precision,
+    )
+}
+
+/// Amount of a scaled amount, rounded down, with the scaled remainder
+fn unscaled_amount(scaled_amount: U192, precision: u128) -> Option<(u64, u128)> {
+    let precision = U192::from(precision);
+    let amount = scaled_amount.checked_div(precision)?;
+    let remainder = scaled_amount.checked_sub(amount.checked_mul(precision)?)?;
+    Some((amount.try_into().ok()?, remainder.try_into().ok()?))
+}
+
+/// Funding required to emit at rate during duration, rounded up
+fn calculate_funding_amount(reward_rate: u128, duration: u64, precision: u128) -> Option<u64> {
+    let amount = U192::from(reward_rate)
+        .checked_mul(duration.into())?
+        .checked_add(precision.checked_sub(1)?.into())?
+        .checked_div(precision.into())?;
+    amount.try_into().ok()
+}
+
+/// Reward per token accrued at rate during time period plus a carried remainder.
+/// Returns the increment and the remainder not assigned to it.
+fn reward_per_token_increment(
+    time_period: u64,
+    reward_rate: u128,
+    total_staked: u64,
+    carried_remainder: u128,
+) -> Option<(u128, u128)> {
+    let emitted = U192::from(time_period).checked_mul(reward_rate.into())?;
+    emission_per_token(emitted, total_staked, carried_remainder)
+}
+
+/// Reward per token of a scaled emission plus a carried remainder.
+/// Returns the increment and the remainder not assigned to it.
+fn emission_per_token(
+    emitted: U192,
+    total_staked: u64,
+    carried_remainder: u128,
+) -> Option<(u128, u128)> {
+    let emitted = emitted.checked_add(carried_remainder.into())?;
+    let total_staked = U192::from(total_staked);
+    let increment = emitted.checked_div(total_staked)?;
+    let remainder = emitted.checked_sub(increment.checked_mul(total_staked)?)?;
+    Some((increment.try_into().ok()?, remainder.try_into().ok()?))
+}
+
+/// Cumulative weight of a linear decay from start weight to end weight over duration, after elapsed seconds.
+/// Scaled by twice the duration to stay an integer.
+fn linear_decay_weight(
+    start_weight: u16,
+    end_weight: u16,
+    elapsed: u64,
+    duration: u64,
+) -> Option<U256> {
+    let elapsed = U256::from(elapsed);
+    let flat = U256::from(duration)
+        .checked_mul(2.into())?
+        .checked_mul(elapsed)?
+        .checked_mul(start_weight.into())?;
+    let squared = elapsed.checked_mul(elapsed)?;
+    if start_weight >= end_weight {
+        flat.checked_sub(squared.checked_mul(start_weight.checked_sub(end_weight)?.into())?)
+    } else {
+        flat.checked_add(squared.checked_mul(end_weight.checked_sub(start_weight)?.into())?)
+    }
+}
+
+/// Cumulative weight of steps, each lasting until the offset of the next one, after elapsed seconds
+fn stepwise_weight(steps: &[EmissionStep], elapsed: u64) -> Option<U256> {
+    let mut weight = U256::zero();
+    for (index, step) in steps.iter().enumerate() {
+        let step_end = steps
+            .get(index.checked_add(1)?)
+            .map_or(u64::MAX, |next| next.start_offset);
+        let seconds = elapsed.min(step_end).saturating_sub(step.start_offset);
+        weight = weight.checked_add(U256::from(seconds).checked_mul(step.weight.into())?)?;
+    }
+    Some(weight)
+}
+
+/// Cumulative weight of the emission schedule of the pool after elapsed seconds of the reward period
+fn schedule_weight(pool: &Pool, elapsed: u64) -> Option<U256> {
+    let duration = pool
+        .reward_duration_end
+        .checked_sub(pool.reward_period_start)?;
+    let elapsed = elapsed.min(duration);
+    match pool.emission_curve {
+        EmissionCurve::Flat => Some(elapsed.into()),
+        EmissionCurve::LinearDecay => linear_decay_weight(
+            pool.decay_start_weight,
+            pool.decay_end_weight,
+            elapsed,
+            duration,
+        ),
+        EmissionCurve::Stepwise => stepwise_weight(pool.get_emission_steps(), elapsed),
+    }
+}
+
+/// Share of a scheduled amount emitted at a cumulative weight, rounded down
+fn scheduled_share(scheduled_amount: u128, weight: U256, total_weight: U256) -> Option<U256> {
+    if total_weight.is_zero() {
+        return Some(U256::zero());
+    }
+    U256::from(scheduled_amount)
+        .checked_mul(weight)?
+        .checked_div(total_weight)
+}
+
+/// Scaled amount emitted by the schedule of the pool between two times of the reward period.
+/// Shares are taken on the cumulative weight so that the emissions of consecutive periods add up to the scheduled amount.
+fn scheduled_emission(pool: &Pool, scheduled_amount: u128, from: u64, to: u64) -> Option<U192> {
+    let total_weight = schedule_weight(pool, u64::MAX)?;
+    let emitted_from = scheduled_share(
+        scheduled_amount,
+        schedule_weight(pool, from.saturating_sub(pool.reward_period_start))?,
+        total_weight,
+    )?;
+    let emitted_to = scheduled_share(
+        scheduled_amount,
+        schedule_weight(pool, to.saturating_sub(pool.reward_period_start))?,
+        total_weight,
+    )?;
+    let emitted: u128 = emitted_to.checked_sub(emitted_from)?.try_into().ok()?;
+    Some(emitted.into())
+}
+
+/// Reward per token accrued by one reward of the pool between the last update and last time reward applicable,
+/// at the flat rate or along the emission schedule, plus a carried remainder
+fn pool_reward_per_token_increment(
+    pool: &Pool,
+    reward_rate: u128,
+    scheduled_amount: u128,
+    total_staked: u64,
+    carried_remainder: u128,
+    last_time_reward_applicable: u64,
+) -> Option<(u128, u128)> {
+    if !pool.has_emission_schedule() {
+        let time_period = last_time_reward_applicable.checked_sub(pool.last_update_time)?;
+        return reward_per_token_increment(
+            time_period,
+            reward_rate,
+            total_staked,
+            carried_remainder,
+        );
+    }
+    let emitted = scheduled_emission(
+        pool,
+        scheduled_amount,
+        pool.last_update_time,
+        last_time_reward_applicable,
+    )?;
+    emission_per_token(emitted, total_staked, carried_remainder)
+}
+
+/// Reward earned by balance between two reward per token values plus a carried remainder, added to the pending amount.
+/// Returns the earned amount and the remainder below one token unit.
+fn earned_amount(
+    balance: u64,
+    reward_per_token_stored: u128,
+    reward_per_token_complete: u128,
+    reward_per_token_pending: u64,
+    carried_remainder: u64,
+    precision: u128,
+) -> Option<(u64, u64)> {
+    let scaled_earned = U192::from(balance)
+        .checked_mul(
+            reward_per_token_stored
+                .checked_sub(reward_per_token_complete)?
+                .into(),
+        )?
+        .checked_add(carried_remainder.into())?;
+    let precision = U192::from(precision);
+    let earned = scaled_earned.checked_div(precision)?;
+    let remainder = scaled_earned.checked_sub(earned.checked_mul(precision)?)?;
+    let earned: u64 = earned.try_into().ok()?;
+    Some((
+        earned.checked_add(reward_per_token_pending)?,
+        remainder.try_into().ok()?,
+    ))
+}
+
+/// Calculate reward per token, with the remainders carried to the next update
 pub fn reward_per_token(
     pool: &Account<Pool>,
     total_staked: u64,
     last_time_reward_applicable: u64,
-) -> (u128, u128) {
+) -> ((u128, u128), (u128, u128)) {
     if total_staked == 0 {
         return (
-            pool.reward_a_per_token_stored,
-            pool.reward_b_per_token_stored,
+            (
+                pool.reward_a_per_token_stored,
+                pool.reward_a_per_token_remainder,
+            ),
+            (
+                pool.reward_b_per_token_stored,
+                pool.reward_b_per_token_remainder,
+            ),
         );
     }
 
-    let time_period = U192::from(last_time_reward_applicable)
-        .checked_sub(pool.last_update_time.into())
-        .unwrap();
+    let (increment_a, remainder_a) = pool_reward_per_token_increment(
+        pool,
+        pool.get_reward_a_rate(),
+        pool.reward_a_scheduled,
+        total_staked,
+        pool.reward_a_per_token_remainder,
+        last_time_reward_applicable,
+    )
+    .unwrap();
     let a = pool
         .reward_a_per_token_stored
-        .checked_add(
-            time_period
-                .checked_mul(pool.get_reward_a_rate().into())
-                .unwrap()
-                .checked_div(total_staked.into())
-                .unwrap()
-                .try_into()
-                .unwrap(), //back to u128
-        )
+        .checked_add(increment_a)
         .unwrap();
 
+    let (increment_b, remainder_b) = pool_reward_per_token_increment(
+        pool,
+        pool.get_reward_b_rate(),
+        pool.reward_b_scheduled,
+        total_staked,
+        pool.reward_b_per_token_remainder,
+        last_time_reward_applicable,
+    )
+    .unwrap();
     let b = pool
         .reward_b_per_token_stored
-        .checked_add(
-            time_period
-                .checked_mul(pool.get_reward_b_rate().into())
-                .unwrap()
-                .checked_div(total_staked.into())
-                .unwrap()
-                .try_into()
-                .unwrap(), //back to u128
+        .checked_add(increment_b)
+        .unwrap();
+
+    ((a, remainder_a), (b, remainder_b))
+}
+
+/// Rewards not yet emitted between current time and the end of the reward period,
+/// with the scaled remainders below one token unit
+pub fn leftover_rewards(pool: &Account<Pool>, current_time: u64) -> ((u64, u128), (u64, u128)) {
+    let reward_period_end = pool.reward_duration_end;
+    if current_time >= reward_period_end {
+        return ((0, 0), (0, 0));
+    }
+
+    let precision = pool.get_precision();
+    if pool.has_emission_schedule() {
+        let leftover_a = scheduled_emission(
+            pool,
+            pool.reward_a_scheduled,
+            current_time,
+            reward_period_end,
+        )
+        .and_then(|scaled| unscaled_amount(scaled, precision))
+        .unwrap();
+        let leftover_b = scheduled_emission(
+            pool,
+            pool.reward_b_scheduled,
+            current_time,
+            reward_period_end,
         )
+        .and_then(|scaled| unscaled_amount(scaled, precision))
         .unwrap();
+        return (leftover_a, leftover_b);
+    }
 
-    (a, b)
+    let remaining_seconds = reward_period_end.checked_sub(current_time).unwrap();
+    let leftover_a =
+        emitted_amount(pool.get_reward_a_rate(), remaining_seconds, precision).unwrap();
+    let leftover_b =
+        emitted_amount(pool.get_reward_b_rate(), remaining_seconds, precision).unwrap();
+
+    (leftover_a, leftover_b)
 }
 
-/// Farming rate after funding
+/// Farming rate after funding. Stores the funding remainders not covered by the rates on the pool.
+#[allow(clippy::result_large_err)]
 pub fn rate_after_funding(
     pool: &mut Account<Pool>,
     funding_amount_a: u64,
@@ -67,67 +327,202 @@ pub fn rate_after_funding(
         .unix_timestamp
         .try_into()
         .unwrap();
-    let reward_period_end = pool.reward_duration_end;
+    let ((leftover_a, leftover_remainder_a), (leftover_b, leftover_remainder_b)) =
+        leftover_rewards(pool, current_time);
 
-    if current_time >= reward_period_end {
-        let a = calculate_reward_rate(funding_amount_a, pool.reward_duration).unwrap();
-        let b = calculate_reward_rate(funding_amount_b, pool.reward_duration).unwrap();
-        Ok((a, b))
-    } else {
-        let remaining_seconds = reward_period_end.checked_sub(current_time).unwrap();
-        let leftover_a: u64 = (remaining_seconds as u128)
-            .checked_mul(pool.get_reward_a_rate())
-            .unwrap()
-            .checked_div(PRECISION)
-            .unwrap()
-            .try_into()
-            .unwrap(); //back to u64
-        let leftover_b: u64 = (remaining_seconds as u128)
-            .checked_mul(pool.get_reward_b_rate())
-            .unwrap()
-            .checked_div(PRECISION)
-            .unwrap()
-            .try_into()
-            .unwrap(); //back to u64
-
-        let total_a = leftover_a.checked_add(funding_amount_a).unwrap();
-        let total_b = leftover_b.checked_add(funding_amount_b).unwrap();
-
-        let a = calculate_reward_rate(total_a, pool.reward_duration).unwrap();
-        let b = calculate_reward_rate(total_b, pool.reward_duration).unwrap();
-        Ok((a, b))
-    }
-}
-
-/// Calculate earned reward amount of staking user
-pub fn user_earned_amount(pool: &Account<Pool>, user: &Account<User>) -> (u64, u64) {
-    let a: u64 = (user.balance_staked as u128)
-        .checked_mul(
-            (pool.reward_a_per_token_stored as u128)
-                .checked_sub(user.reward_a_per_token_complete as u128)
-                .unwrap(),
-        )
-        .unwrap()
-        .checked_div(PRECISION)
-        .unwrap()
-        .checked_add(user.reward_a_per_token_pending as u128)
-        .unwrap()
-        .try_into()
-        .unwrap(); //back to u64
+    let total_a = leftover_a.checked_add(funding_amount_a).unwrap();
+    let total_b = leftover_b.checked_add(funding_amount_b).unwrap();
+    let carried_a = leftover_remainder_a
+        .checked_add(pool.reward_a_rate_remainder)
+        .ok_or(ErrorCode::MathOverflow)?;
+    let carried_b = leftover_remainder_b
+        .checked_add(pool.reward_b_rate_remainder)
+        .ok_or(ErrorCode::MathOverflow)?;
 
-    let b: u64 = (user.balance_staked as u128)
-        .checked_mul(
-            (pool.reward_b_per_token_stored as u128)
-                .checked_sub(user.reward_b_per_token_complete as u128)
-                .unwrap(),
-        )
-        .unwrap()
-        .checked_div(PRECISION)
-        .unwrap()
-        .checked_add(user.reward_b_per_token_pending as u128)
-        .unwrap()
+    let precision = pool.get_precision();
+    if pool.has_emission_schedule() {
+        // The schedule emits the whole scaled amount, the rates are its average
+        pool.reward_a_scheduled = scaled_total(total_a, precision, carried_a)?;
+        pool.reward_b_scheduled = scaled_total(total_b, precision, carried_b)?;
+        pool.reward_a_rate_remainder = 0;
+        pool.reward_b_rate_remainder = 0;
+        let (a, _) =
+            calculate_reward_rate(0, pool.reward_duration, precision, pool.reward_a_scheduled)
+                .ok_or(ErrorCode::MathOverflow)?;
+        let (b, _) =
+            calculate_reward_rate(0, pool.reward_duration, precision, pool.reward_b_scheduled)
+                .ok_or(ErrorCode::MathOverflow)?;
+        return Ok((a, b));
+    }
+
+    let (a, remainder_a) =
+        calculate_reward_rate(total_a, pool.reward_duration, precision, carried_a).unwrap();
+    let (b, remainder_b) =
+        calculate_reward_rate(total_b, pool.reward_duration, precision, carried_b).unwrap();
+    pool.reward_a_rate_remainder = remainder_a;
+    pool.reward_b_rate_remainder = remainder_b;
+    Ok((a, b))
+}
+
+/// Funding amount scaled by precision plus a carried remainder
+#[allow(clippy::result_large_err)]
+fn scaled_total(amount: u64, precision: u128, carried_remainder: u128) -> Result<u128> {
+    u128::from(amount)
+        .checked_mul(precision)
+        .and_then(|scaled| scaled.checked_add(carried_remainder))
+        .ok_or_else(|| ErrorCode::MathOverflow.into())
+}
+
+/// Farming rate after funding without moving the end of the reward period.
+/// Stores the funding remainders not covered by the rates on the pool.
+pub fn rate_after_top_up(
+    pool: &mut Account<Pool>,
+    funding_amount_a: u64,
+    funding_amount_b: u64,
+    current_time: u64,
+) -> Option<(u128, u128)> {
+    let remaining_seconds = pool.reward_duration_end.checked_sub(current_time)?;
+    let precision = pool.get_precision();
+    let (added_a, remainder_a) = calculate_reward_rate(
+        funding_amount_a,
+        remaining_seconds,
+        precision,
+        pool.reward_a_rate_remainder,
+    )?;
+    let (added_b, remainder_b) = calculate_reward_rate(
+        funding_amount_b,
+        remaining_seconds,
+        precision,
+        pool.reward_b_rate_remainder,
+    )?;
+    let a = pool.get_reward_a_rate().checked_add(added_a)?;
+    let b = pool.get_reward_b_rate().checked_add(added_b)?;
+    pool.reward_a_rate_remainder = remainder_a;
+    pool.reward_b_rate_remainder = remainder_b;
+    Some((a, b))
+}
+
+/// Amounts emitted between two times of the reward period of the pool, at the flat rates or along the emission schedule, rounded down
+pub fn emission_between(pool: &Pool, from: u64, to: u64) -> Option<(u64, u64)> {
+    if !pool.has_emission_schedule() {
+        let duration = to.saturating_sub(from);
+        let precision = pool.get_precision();
+        let (a, _) = emitted_amount(pool.get_reward_a_rate(), duration, precision)?;
+        let (b, _) = emitted_amount(pool.get_reward_b_rate(), duration, precision)?;
+        return Some((a, b));
+    }
+    let precision = pool.get_precision();
+    let (a, _) = unscaled_amount(
+        scheduled_emission(pool, pool.reward_a_scheduled, from, to)?,
+        precision,
+    )?;
+    let (b, _) = unscaled_amount(
+        scheduled_emission(pool, pool.reward_b_scheduled, from, to)?,
+        precision,
+    )?;
+    Some((a, b))
+}
+
+/// Amount emitted at rate during duration, rounded down
+pub fn emission_amount(pool: &Account<Pool>, reward_rate: u128, duration: u64) -> Option<u64> {
+    let (amount, _) = emitted_amount(reward_rate, duration, pool.get_precision())?;
+    Some(amount)
+}
+
+/// Amount of a funding emitted over duration by the rate it yields alone, rounded down
+pub fn funding_emission(funding_amount: u64, duration: u64, precision: u128) -> Option<u64> {
+    let (reward_rate, _) = calculate_reward_rate(funding_amount, duration, precision, 0)?;
+    let (amount, _) = emitted_amount(reward_rate, duration, precision)?;
+    Some(amount)
+}
+
+/// Extra stake given by a boost multiplier, rounded down
+pub fn boost_stake(balance: u64, boost_multiplier_bps: u16) -> Option<u64> {
+    if boost_multiplier_bps <= 10_000 {
+        return Some(0);
+    }
+    let boost = u128::from(balance)
+        .checked_mul(boost_multiplier_bps.checked_sub(10_000)?.into())?
+        .checked_div(10_000)?;
+    boost.try_into().ok()
+}
+
+/// Stake seconds after balance was staked from the last update until now.
+/// Nothing accrues before the first update.
+pub fn accumulate_stake_seconds(
+    stake_seconds: u128,
+    balance: u64,
+    updated_at: u64,
+    current_time: u64,
+) -> Option<u128> {
+    if updated_at == 0 || current_time <= updated_at {
+        return Some(stake_seconds);
+    }
+    let elapsed = current_time.checked_sub(updated_at)?;
+    stake_seconds.checked_add(u128::from(balance).checked_mul(elapsed.into())?)
+}
+
+/// Time weighted average balance between two stake seconds snapshots
+pub fn average_balance(
+    start_stake_seconds: u128,
+    end_stake_seconds: u128,
+    start_time: u64,
+    end_time: u64,
+) -> Option<u64> {
+    let elapsed = end_time.checked_sub(start_time)?;
+    end_stake_seconds
+        .checked_sub(start_stake_seconds)?
+        .checked_div(elapsed.into())?
         .try_into()
-        .unwrap(); //back to u64
+        .ok()
+}
+
+/// Funding required to extend the reward period by seconds at the current rate
+pub fn funding_for_extension(pool: &Account<Pool>, seconds: u64) -> Option<(u64, u64)> {
+    let precision = pool.get_precision();
+    let a = calculate_funding_amount(pool.get_reward_a_rate(), seconds, precision)?;
+    let b = calculate_funding_amount(pool.get_reward_b_rate(), seconds, precision)?;
+    Some((a, b))
+}
+
+/// Protocol fee share of a claimed reward amount, rounded down
+pub fn protocol_fee(amount: u64, protocol_fee_bps: u16) -> Option<u64> {
+    let fee = u128::from(amount)
+        .checked_mul(protocol_fee_bps.into())?
+        .checked_div(10_000)?;
+    fee.try_into().ok()
+}
+
+/// Referrer share of a claimed reward amount, rounded down
+pub fn referral_share(amount: u64, referral_fee_bps: u16) -> Option<u64> {
+    let share = u128::from(amount)
+        .checked_mul(referral_fee_bps.into())?
+        .checked_div(10_000)?;
+    share.try_into().ok()
+}
+
+/// Calculate earned reward amount of staking user, with the remainders carried to the next update
+pub fn user_earned_amount(pool: &Account<Pool>, user: &Account<User>) -> ((u64, u64), (u64, u64)) {
+    let precision = pool.get_precision();
+    let a = earned_amount(
+        user.get_effective_stake(),
+        pool.reward_a_per_token_stored,
+        user.reward_a_per_token_complete,
+        user.reward_a_per_token_pending,
+        user.reward_a_earned_remainder,
+        precision,
+    )
+    .unwrap();
+
+    let b = earned_amount(
+        user.get_effective_stake(),
+        pool.reward_b_per_token_stored,
+        user.reward_b_per_token_complete,
+        user.reward_b_per_token_pending,
+        user.reward_b_earned_remainder,
+        precision,
+    )
+    .unwrap();
 
     (a, b)
 }
@@ -140,8 +535,299 @@ mod overflow_test {
         let reward_duration = 1u64;
         let funding_amount = u64::MAX;
         println!(
-            "reward rate {}",
-            calculate_reward_rate(funding_amount, reward_duration).unwrap()
+            "reward rate {:?}",
+            calculate_reward_rate(funding_amount, reward_duration, PRECISION, 0).unwrap()
+        );
+        let max_precision = 10u128.pow(MAX_PRECISION_EXPONENT);
+        assert!(calculate_reward_rate(funding_amount, reward_duration, max_precision, 0).is_some());
+    }
+
+    #[test]
+    fn test_precision_from_decimals() {
+        assert_eq!(precision_from_decimals(9, 9, 9), 10u128.pow(12));
+        assert_eq!(precision_from_decimals(9, 6, 9), 10u128.pow(15));
+        assert_eq!(precision_from_decimals(6, 9, 9), PRECISION);
+        assert_eq!(precision_from_decimals(0, 18, 18), PRECISION);
+        assert_eq!(precision_from_decimals(9, 0, 0), 10u128.pow(18));
+        assert_eq!(precision_from_decimals(u8::MAX, 0, 0), 10u128.pow(18));
+    }
+
+    #[test]
+    fn test_low_decimal_reward_per_token() {
+        // 1_000 tokens of 6 decimals per day to 1_000_000 staked tokens of 9 decimals
+        let funding_amount = 1_000_000_000u64;
+        let reward_duration = 86_400u64;
+        let total_staked = 1_000_000_000_000_000u64;
+
+        let (legacy_rate, _) =
+            calculate_reward_rate(funding_amount, reward_duration, PRECISION, 0).unwrap();
+        assert_eq!(
+            reward_per_token_increment(1, legacy_rate, total_staked, 0).map(|(i, _)| i),
+            Some(0)
+        );
+
+        let precision = precision_from_decimals(9, 6, 6);
+        let (rate, _) =
+            calculate_reward_rate(funding_amount, reward_duration, precision, 0).unwrap();
+        let (increment, _) =
+            reward_per_token_increment(reward_duration, rate, total_staked, 0).unwrap();
+        assert!(increment > 0);
+        let (earned, _) = earned_amount(total_staked, increment, 0, 0, 0, precision).unwrap();
+        assert!(earned <= funding_amount);
+        assert!(funding_amount - earned < 1_000);
+    }
+
+    #[test]
+    fn test_earned_amount_overflow() {
+        let precision = 10u128.pow(MAX_PRECISION_EXPONENT);
+        assert_eq!(
+            earned_amount(u64::MAX, precision, 0, 0, 0, precision),
+            Some((u64::MAX, 0))
+        );
+        assert_eq!(earned_amount(u64::MAX, u128::MAX, 0, 0, 0, precision), None);
+        assert_eq!(earned_amount(u64::MAX, precision, 0, 1, 0, precision), None);
+        assert_eq!(earned_amount(1, 0, 1, 0, 0, precision), None);
+        assert_eq!(reward_per_token_increment(u64::MAX, u128::MAX, 1, 0), None);
+        assert_eq!(
+            reward_per_token_increment(u64::MAX, u128::MAX, u64::MAX, 0),
+            Some((u128::MAX, 0))
+        );
+    }
+
+    #[test]
+    fn test_protocol_fee() {
+        assert_eq!(protocol_fee(10_000, 0), Some(0));
+        assert_eq!(protocol_fee(10_000, 250), Some(250));
+        assert_eq!(protocol_fee(39, 250), Some(0));
+        assert_eq!(protocol_fee(u64::MAX, 10_000), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_referral_share() {
+        assert_eq!(referral_share(10_000, 0), Some(0));
+        assert_eq!(referral_share(9_750, 1_000), Some(975));
+        assert_eq!(referral_share(9, 1_000), Some(0));
+        assert_eq!(referral_share(u64::MAX, 5_000), Some(u64::MAX / 2));
+    }
+
+    #[test]
+    fn test_funding_amount_round_up() {
+        let (reward_rate, remainder) = calculate_reward_rate(1_000, 3, PRECISION, 0).unwrap();
+        assert_eq!(remainder, 1);
+        assert_eq!(
+            calculate_funding_amount(reward_rate, 3, PRECISION),
+            Some(1_000)
+        );
+        assert_eq!(
+            calculate_funding_amount(reward_rate, 1, PRECISION),
+            Some(334)
+        );
+        assert_eq!(
+            emitted_amount(reward_rate, 1, PRECISION),
+            Some((333, reward_rate - 333 * PRECISION))
+        );
+        assert_eq!(calculate_funding_amount(0, 100, PRECISION), Some(0));
+        assert_eq!(calculate_funding_amount(u128::MAX, 2, PRECISION), None);
+    }
+
+    #[test]
+    fn test_boost_stake() {
+        assert_eq!(boost_stake(1_000, 0), Some(0));
+        assert_eq!(boost_stake(1_000, 10_000), Some(0));
+        assert_eq!(boost_stake(1_000, 15_000), Some(500));
+        assert_eq!(boost_stake(3, 13_333), Some(0));
+        assert_eq!(
+            boost_stake(u64::MAX, MAX_BOOST_MULTIPLIER_BPS),
+            u64::MAX.checked_mul(4)
         );
     }
+
+    #[test]
+    fn test_stake_seconds() {
+        assert_eq!(accumulate_stake_seconds(0, 100, 0, 1_000), Some(0));
+        assert_eq!(accumulate_stake_seconds(5, 100, 1_000, 1_000), Some(5));
+        assert_eq!(accumulate_stake_seconds(5, 100, 1_000, 1_010), Some(1_005));
+        assert_eq!(accumulate_stake_seconds(u128::MAX, 1, 1_000, 1_010), None);
+
+        // 100 staked for 10 seconds then 400 for 30 seconds
+        let start = accumulate_stake_seconds(0, 0, 1, 1_000).unwrap();
+        let middle = accumulate_stake_seconds(start, 100, 1_000, 1_010).unwrap();
+        let end = accumulate_stake_seconds(middle, 400, 1_010, 1_040).unwrap();
+        assert_eq!(average_balance(start, end, 1_000, 1_040), Some(325));
+        assert_eq!(average_balance(middle, end, 1_010, 1_040), Some(400));
+        assert_eq!(average_balance(end, start, 1_000, 1_040), None);
+        assert_eq!(average_balance(start, end, 1_040, 1_040), None);
+    }
+
+    #[test]
+    fn test_funding_emission() {
+        assert_eq!(funding_emission(0, 86_400, PRECISION), Some(0));
+        assert_eq!(funding_emission(1, 86_400, PRECISION), Some(0));
+        assert_eq!(funding_emission(1, 86_400, 10u128.pow(12)), Some(0));
+        assert_eq!(funding_emission(86_400, 86_400, PRECISION), Some(86_400));
+        assert_eq!(funding_emission(1_000, 3, PRECISION), Some(999));
+        assert_eq!(funding_emission(1, 1, PRECISION), Some(1));
+    }
+
+    #[test]
+    fn test_linear_decay_weight() {
+        // weight 3 decaying to 1 over 10 seconds emits 20, scaled by 20
+        assert_eq!(linear_decay_weight(3, 1, 0, 10), Some(U256::zero()));
+        assert_eq!(linear_decay_weight(3, 1, 10, 10), Some(U256::from(400)));
+        // the first half emits 2.5 * 5 = 12.5
+        assert_eq!(linear_decay_weight(3, 1, 5, 10), Some(U256::from(250)));
+        assert_eq!(linear_decay_weight(1, 3, 5, 10), Some(U256::from(150)));
+        assert_eq!(linear_decay_weight(2, 2, 5, 10), Some(U256::from(200)));
+    }
+
+    #[test]
+    fn test_stepwise_weight() {
+        let steps = [
+            EmissionStep {
+                start_offset: 0,
+                weight: 4,
+            },
+            EmissionStep {
+                start_offset: 10,
+                weight: 1,
+            },
+            EmissionStep {
+                start_offset: 20,
+                weight: 0,
+            },
+        ];
+        assert_eq!(stepwise_weight(&steps, 0), Some(U256::zero()));
+        assert_eq!(stepwise_weight(&steps, 5), Some(U256::from(20)));
+        assert_eq!(stepwise_weight(&steps, 15), Some(U256::from(45)));
+        assert_eq!(stepwise_weight(&steps, 1_000), Some(U256::from(50)));
+    }
+
+    #[test]
+    fn test_scheduled_share_adds_up() {
+        let mut rng = XorShift(0x2545_f491_4f6c_dd1d);
+        for _ in 0..200 {
+            let duration = 1 + rng.below(1_000_000);
+            let start_weight = 1 + rng.below(u16::MAX.into()) as u16;
+            let end_weight = rng.below(u16::MAX.into()) as u16;
+            let scheduled_amount = u128::from(rng.below(u64::MAX)) * PRECISION;
+            let total_weight =
+                linear_decay_weight(start_weight, end_weight, duration, duration).unwrap();
+
+            let mut elapsed = 0u64;
+            let mut emitted = U256::zero();
+            let mut previous_share = U256::zero();
+            while elapsed < duration {
+                let time_period = std::cmp::min(1 + rng.below(duration), duration - elapsed);
+                elapsed += time_period;
+                let share = scheduled_share(
+                    scheduled_amount,
+                    linear_decay_weight(start_weight, end_weight, elapsed, duration).unwrap(),
+                    total_weight,
+                )
+                .unwrap();
+                emitted = emitted + share - previous_share;
+                previous_share = share;
+            }
+            assert_eq!(emitted, U256::from(scheduled_amount));
+        }
+    }
+
+    /// Deterministic xorshift generator for the property tests
+    struct XorShift(u64);
+
+    impl XorShift {
+        fn next(&mut self) -> u64 {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            self.0
+        }
+
+        #[allow(clippy::arithmetic_side_effects)]
+        fn below(&mut self, bound: u64) -> u64 {
+            self.next() % bound
+        }
+    }
+
+    #[test]
+    fn test_rewards_accounted_exactly() {
+        let mut rng = XorShift(0x9e37_79b9_7f4a_7c15);
+        for _ in 0..200 {
+            let precision = 10u128.pow(9 + rng.below(10) as u32);
+            let reward_duration = 1 + rng.below(1_000_000);
+            let funding_amount = rng.below(1_000_000_000_000);
+
+            let (rate, rate_remainder) =
+                calculate_reward_rate(funding_amount, reward_duration, precision, 0).unwrap();
+
+            // (balance, reward per token complete, earned, earned remainder) per user
+            let mut users = [(0u64, 0u128, 0u64, 0u64); 4];
+            for user in users.iter_mut() {
+                user.0 = 1 + rng.below(1_000_000_000_000);
+            }
+            let mut reward_per_token_stored = 0u128;
+            let mut reward_per_token_remainder = 0u128;
+            let mut elapsed = 0u64;
+
+            while elapsed < reward_duration {
+                let time_period =
+                    std::cmp::min(1 + rng.below(reward_duration), reward_duration - elapsed);
+                elapsed += time_period;
+                let total_staked: u64 = users.iter().map(|u| u.0).sum();
+                let (increment, remainder) = reward_per_token_increment(
+                    time_period,
+                    rate,
+                    total_staked,
+                    reward_per_token_remainder,
+                )
+                .unwrap();
+                reward_per_token_stored += increment;
+                reward_per_token_remainder = remainder;
+
+                // one user updates and changes its balance
+                let user = &mut users[rng.below(4) as usize];
+                let (earned, earned_remainder) = earned_amount(
+                    user.0,
+                    reward_per_token_stored,
+                    user.1,
+                    user.2,
+                    user.3,
+                    precision,
+                )
+                .unwrap();
+                *user = (
+                    1 + rng.below(1_000_000_000_000),
+                    reward_per_token_stored,
+                    earned,
+                    earned_remainder,
+                );
+            }
+
+            let mut accounted = U192::from(reward_per_token_remainder)
+                .checked_add(rate_remainder.into())
+                .unwrap();
+            let mut total_earned = 0u64;
+            for user in users.iter() {
+                let (earned, earned_remainder) = earned_amount(
+                    user.0,
+                    reward_per_token_stored,
+                    user.1,
+                    user.2,
+                    user.3,
+                    precision,
+                )
+                .unwrap();
+                total_earned += earned;
+                accounted = accounted
+                    + U192::from(earned) * U192::from(precision)
+                    + U192::from(earned_remainder);
+            }
+
+            assert_eq!(
+                accounted,
+                U192::from(funding_amount) * U192::from(precision)
+            );
+            assert!(total_earned <= funding_amount);
+        }
+    }
 }
@@ -0,0 +1,288 @@
+//! Helpers shared by the program tests
+#![allow(dead_code)]
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::entrypoint;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use farming::{ErrorCode, GlobalConfig};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    instruction::{Instruction, InstructionError},
+    program_pack::Pack,
+    signature::{Keypair, Signer},
+    system_instruction, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+pub fn farming_entry<'info>(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'info>],
+    data: &[u8],
+) -> entrypoint::ProgramResult {
+    // The anchor entrypoint ties the account infos to the slice lifetime
+    let accounts: &'info [AccountInfo<'info>] = Box::leak(Box::new(accounts.to_vec()));
+    farming::entry(program_id, accounts, data)
+}
+
+/// Global config as initialize_global_config leaves it, which needs an upgradeable program
+pub fn global_config_account(bump: u8, protocol_fee_bps: u16) -> SolanaAccount {
+    let global_config = GlobalConfig {
+        admin: Pubkey::new_unique(),
+        protocol_fee_bps,
+        treasury: Pubkey::new_unique(),
+        bump,
+    };
+    let mut data = vec![];
+    global_config.try_serialize(&mut data).unwrap();
+    data.resize(8 + 128, 0);
+    SolanaAccount {
+        lamports: 1_000_000_000,
+        data,
+        owner: farming::id(),
+        ..SolanaAccount::default()
+    }
+}
+
+pub async fn process(
+    context: &mut ProgramTestContext,
+    instructions: &[Instruction],
+    signers: &[&Keypair],
+) -> std::result::Result<(), BanksClientError> {
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+    let transaction = Transaction::new_signed_with_payer(
+        instructions,
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(transaction).await
+}
+
+pub async fn create_mint(context: &mut ProgramTestContext, mint: &Keypair) {
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &mint.pubkey(),
+            rent.minimum_balance(spl_token::state::Mint::LEN),
+            spl_token::state::Mint::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_mint(
+            &spl_token::ID,
+            &mint.pubkey(),
+            &context.payer.pubkey(),
+            None,
+            0,
+        )
+        .unwrap(),
+    ];
+    process(context, &instructions, &[mint]).await.unwrap();
+}
+
+pub async fn create_token_account(
+    context: &mut ProgramTestContext,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let account = Keypair::new();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let instructions = [
+        system_instruction::create_account(
+            &context.payer.pubkey(),
+            &account.pubkey(),
+            rent.minimum_balance(spl_token::state::Account::LEN),
+            spl_token::state::Account::LEN as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_account(&spl_token::ID, &account.pubkey(), mint, owner)
+            .unwrap(),
+        spl_token::instruction::mint_to(
+            &spl_token::ID,
+            mint,
+            &account.pubkey(),
+            &context.payer.pubkey(),
+            &[],
+            amount,
+        )
+        .unwrap(),
+    ];
+    process(context, &instructions, &[&account]).await.unwrap();
+    account.pubkey()
+}
+
+pub async fn get_account<T: AccountDeserialize>(
+    context: &mut ProgramTestContext,
+    key: Pubkey,
+) -> T {
+    let account = context
+        .banks_client
+        .get_account(key)
+        .await
+        .unwrap()
+        .unwrap();
+    T::try_deserialize(&mut account.data.as_slice()).unwrap()
+}
+
+pub fn assert_error(result: std::result::Result<(), BanksClientError>, error: ErrorCode) {
+    match result.unwrap_err().unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, u32::from(error));
+        }
+        err => panic!("unexpected error {:?}", err),
+    }
+}
+
+/// Farming program with the global config every pool snapshots its protocol fee from
+pub fn farming_program_test() -> ProgramTest {
+    let mut program_test = ProgramTest::new("farming", farming::id(), processor!(farming_entry));
+    let (global_config, global_config_bump) =
+        Pubkey::find_program_address(&[farming::GLOBAL_CONFIG_SEED], &farming::id());
+    program_test.add_account(global_config, global_config_account(global_config_bump, 0));
+    program_test
+}
+
+/// Addresses of a single reward pool
+pub struct PoolKeys {
+    pub pool: Pubkey,
+    pub staking_vault: Pubkey,
+    pub reward_vault: Pubkey,
+}
+
+/// Creates a single reward pool of `authority` and `base`, signed by both
+pub fn initialize_pool(
+    authority: &Pubkey,
+    base: &Pubkey,
+    staking_mint: &Pubkey,
+    reward_mint: &Pubkey,
+    reward_duration: u64,
+) -> (Instruction, PoolKeys) {
+    let program_id = farming::id();
+    let (global_config, _) =
+        Pubkey::find_program_address(&[farming::GLOBAL_CONFIG_SEED], &program_id);
+    let (pool, _) = Pubkey::find_program_address(
+        &[
+            reward_duration.to_be_bytes().as_ref(),
+            staking_mint.as_ref(),
+            reward_mint.as_ref(),
+            reward_mint.as_ref(),
+            base.as_ref(),
+        ],
+        &program_id,
+    );
+    let (staking_vault, _) =
+        Pubkey::find_program_address(&[b"staking", pool.as_ref()], &program_id);
+    let (reward_vault, _) =
+        Pubkey::find_program_address(&[b"reward_a", pool.as_ref()], &program_id);
+    let (pool_registry, _) = Pubkey::find_program_address(
+        &[farming::POOL_REGISTRY_SEED, staking_mint.as_ref()],
+        &program_id,
+    );
+    let instruction = Instruction {
+        program_id,
+        accounts: farming::accounts::InitializePool {
+            pool,
+            staking_mint: *staking_mint,
+            staking_vault,
+            reward_a_mint: *reward_mint,
+            reward_a_vault: reward_vault,
+            reward_b_mint: *reward_mint,
+            reward_b_vault: None,
+            authority: *authority,
+            base: *base,
+            global_config: Some(global_config),
+            pool_registry,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::InitializePool { reward_duration }.data(),
+    };
+    (
+        instruction,
+        PoolKeys {
+            pool,
+            staking_vault,
+            reward_vault,
+        },
+    )
+}
+
+/// User PDA of `owner` in `pool`
+pub fn user_address(pool: &Pubkey, owner: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[owner.as_ref(), pool.as_ref()], &farming::id()).0
+}
+
+pub fn create_user(pool: &Pubkey, owner: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::CreateUser {
+            pool: *pool,
+            user: user_address(pool, owner),
+            owner: *owner,
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateUser {}.data(),
+    }
+}
+
+/// Instruction using the [Deposit](farming::accounts::Deposit) accounts of `owner`, like deposit and the withdrawals
+pub fn stake_instruction(
+    keys: &PoolKeys,
+    owner: &Pubkey,
+    stake_from_account: &Pubkey,
+    data: Vec<u8>,
+) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::Deposit {
+            pool: keys.pool,
+            staking_vault: keys.staking_vault,
+            user: user_address(&keys.pool, owner),
+            owner: *owner,
+            stake_from_account: *stake_from_account,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None),
+        data,
+    }
+}
+
+/// Funds `wallets` from the payer
+pub async fn fund_wallets(context: &mut ProgramTestContext, wallets: &[&Keypair]) {
+    for wallet in wallets {
+        let transfer =
+            system_instruction::transfer(&context.payer.pubkey(), &wallet.pubkey(), 1_000_000_000);
+        process(context, &[transfer], &[]).await.unwrap();
+    }
+}
+
+pub async fn advance_clock(context: &mut ProgramTestContext, seconds: u64) {
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += seconds as i64;
+    context.set_sysvar(&clock);
+}
+
+/// Claim instruction. The owner is an unchecked account, signing unless the delegate does
+pub fn claim_instruction(claim: farming::accounts::ClaimReward) -> Instruction {
+    let mut accounts = claim.to_account_metas(None);
+    if claim.delegate.is_none() {
+        for meta in accounts
+            .iter_mut()
+            .filter(|meta| meta.pubkey == claim.owner)
+        {
+            meta.is_signer = true;
+        }
+    }
+    Instruction {
+        program_id: farming::id(),
+        accounts,
+        data: farming::instruction::Claim {}.data(),
+    }
+}
@@ -0,0 +1,259 @@
+//! Native SOL reward tests.
+//!
+//! Funding and claiming move lamports straight from and to the signing wallets, so the funder,
+//! the owner and the claim delegate are all distinct from the transaction fee payer here.
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use common::*;
+use farming::User;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+const REWARD_DURATION: u64 = 100;
+const DEPOSIT_AMOUNT: u64 = 1_000;
+const FUND_AMOUNT: u64 = 1_000_000;
+
+struct NativeFixture {
+    context: ProgramTestContext,
+    owner: Keypair,
+    funder: Keypair,
+    delegate: Keypair,
+    pool: Pubkey,
+    staking_vault: Pubkey,
+    reward_vault: Pubkey,
+    native_vault: Pubkey,
+    user: Pubkey,
+}
+
+/// Deposit into a single native SOL reward pool with an authorized funder and a claim delegate
+async fn setup() -> NativeFixture {
+    let mut context = farming_program_test().start_with_context().await;
+
+    let owner = Keypair::new();
+    let authority = Keypair::new();
+    let funder = Keypair::new();
+    let delegate = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&owner, &authority, &funder]).await;
+
+    let staking_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    let (initialize_pool, keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &staking_mint.pubkey(),
+        &spl_token::native_mint::ID,
+        REWARD_DURATION,
+    );
+    let PoolKeys {
+        pool,
+        staking_vault,
+        reward_vault,
+    } = keys;
+    let program_id = farming::id();
+    let (native_vault, _) = Pubkey::find_program_address(
+        &[farming::NATIVE_REWARD_VAULT_SEED, pool.as_ref()],
+        &program_id,
+    );
+    let enable_native_reward = Instruction {
+        program_id,
+        accounts: farming::accounts::EnableNativeReward {
+            pool,
+            native_vault,
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::EnableNativeReward {
+            reward_a: true,
+            reward_b: false,
+        }
+        .data(),
+    };
+    let authorize_funder = Instruction {
+        program_id,
+        accounts: farming::accounts::FunderChange {
+            pool,
+            authority: authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::AuthorizeFunder {
+            funder_to_add: funder.pubkey(),
+        }
+        .data(),
+    };
+    process(
+        &mut context,
+        &[initialize_pool, enable_native_reward, authorize_funder],
+        &[&authority, &base],
+    )
+    .await
+    .unwrap();
+
+    let user = user_address(&pool, &owner.pubkey());
+    let create_user = create_user(&pool, &owner.pubkey());
+    let owner_staking_account = create_token_account(
+        &mut context,
+        &staking_mint.pubkey(),
+        &owner.pubkey(),
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    let deposit = stake_instruction(
+        &keys,
+        &owner.pubkey(),
+        &owner_staking_account,
+        farming::instruction::Deposit {
+            amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    );
+    let set_claim_delegate = Instruction {
+        program_id,
+        accounts: farming::accounts::SetClaimDelegate {
+            pool,
+            user,
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetClaimDelegate {
+            delegate: delegate.pubkey(),
+            reward_a_destination: Pubkey::default(),
+            reward_b_destination: Pubkey::default(),
+        }
+        .data(),
+    };
+    process(
+        &mut context,
+        &[create_user, deposit, set_claim_delegate],
+        &[&owner],
+    )
+    .await
+    .unwrap();
+
+    NativeFixture {
+        context,
+        owner,
+        funder,
+        delegate,
+        pool,
+        staking_vault,
+        reward_vault,
+        native_vault,
+        user,
+    }
+}
+
+fn fund(fixture: &NativeFixture, amount: u64) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::Fund {
+            pool: fixture.pool,
+            staking_vault: fixture.staking_vault,
+            reward_a_vault: fixture.reward_vault,
+            reward_b_vault: fixture.reward_vault,
+            funder: fixture.funder.pubkey(),
+            from_a: None,
+            from_b: None,
+            token_program: spl_token::ID,
+            native_vault: Some(fixture.native_vault),
+            system_program: Some(system_program::ID),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::Fund {
+            amount_a: amount,
+            amount_b: 0,
+        }
+        .data(),
+    }
+}
+
+fn claim(fixture: &NativeFixture, delegate: Option<Pubkey>) -> Instruction {
+    claim_instruction(farming::accounts::ClaimReward {
+        pool: fixture.pool,
+        staking_vault: fixture.staking_vault,
+        reward_a_vault: fixture.reward_vault,
+        reward_b_vault: None,
+        user: fixture.user,
+        owner: fixture.owner.pubkey(),
+        reward_a_account: None,
+        reward_b_account: None,
+        token_program: spl_token::ID,
+        global_config: None,
+        treasury_a_account: None,
+        treasury_b_account: None,
+        native_vault: Some(fixture.native_vault),
+        treasury: None,
+        system_program: Some(system_program::ID),
+        delegate,
+        referrer: None,
+    })
+}
+
+async fn lamports(fixture: &mut NativeFixture, key: Pubkey) -> u64 {
+    fixture.context.banks_client.get_balance(key).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_fund_native_reward_from_funder() {
+    let mut fixture = setup().await;
+    let funder = fixture.funder.pubkey();
+    let native_vault = fixture.native_vault;
+    let funder_before = lamports(&mut fixture, funder).await;
+    let vault_before = lamports(&mut fixture, native_vault).await;
+
+    let ix = fund(&fixture, FUND_AMOUNT);
+    process(&mut fixture.context, &[ix], &[&fixture.funder])
+        .await
+        .unwrap();
+
+    // The transaction fee is paid by the payer, so the funder only loses the funded lamports
+    assert_eq!(
+        lamports(&mut fixture, funder).await,
+        funder_before - FUND_AMOUNT
+    );
+    assert_eq!(
+        lamports(&mut fixture, native_vault).await,
+        vault_before + FUND_AMOUNT
+    );
+}
+
+#[tokio::test]
+async fn test_claim_native_reward_as_owner_and_delegate() {
+    let mut fixture = setup().await;
+    let owner = fixture.owner.pubkey();
+    let delegate = fixture.delegate.pubkey();
+    let ix = fund(&fixture, FUND_AMOUNT);
+    process(&mut fixture.context, &[ix], &[&fixture.funder])
+        .await
+        .unwrap();
+
+    advance_clock(&mut fixture.context, REWARD_DURATION / 2).await;
+    let owner_before = lamports(&mut fixture, owner).await;
+    let ix = claim(&fixture, None);
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+    let claimed_by_owner = lamports(&mut fixture, owner).await - owner_before;
+    assert!(claimed_by_owner > 0);
+
+    // The delegate signs alone and the rewards still go to the owner wallet
+    advance_clock(&mut fixture.context, REWARD_DURATION).await;
+    let ix = claim(&fixture, Some(delegate));
+    process(&mut fixture.context, &[ix], &[&fixture.delegate])
+        .await
+        .unwrap();
+    let claimed = lamports(&mut fixture, owner).await - owner_before;
+    assert!(claimed > claimed_by_owner);
+    // Only the rounding dust of the reward rate stays in the vault
+    assert!(claimed <= FUND_AMOUNT && FUND_AMOUNT - claimed < REWARD_DURATION);
+
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.reward_a_per_token_pending, 0);
+}
@@ -0,0 +1,44 @@
+//! Pool registry tests.
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use farming::PoolRegistry;
+use solana_program_test::tokio;
+use solana_sdk::signature::{Keypair, Signer};
+
+const REWARD_DURATION: u64 = 10;
+
+#[tokio::test]
+async fn test_registry_lists_pools_of_the_same_staking_mint() {
+    let mut context = farming_program_test().start_with_context().await;
+    let authority = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&authority]).await;
+    let staking_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+
+    let mut pools = vec![];
+    for reward_duration in [REWARD_DURATION, REWARD_DURATION + 1] {
+        let reward_mint = Keypair::new();
+        create_mint(&mut context, &reward_mint).await;
+        let (ix, keys) = initialize_pool(
+            &authority.pubkey(),
+            &base.pubkey(),
+            &staking_mint.pubkey(),
+            &reward_mint.pubkey(),
+            reward_duration,
+        );
+        process(&mut context, &[ix], &[&authority, &base])
+            .await
+            .unwrap();
+        pools.push(keys.pool);
+    }
+
+    let (pool_registry, _) = Pubkey::find_program_address(
+        &[farming::POOL_REGISTRY_SEED, staking_mint.pubkey().as_ref()],
+        &farming::id(),
+    );
+    let registry: PoolRegistry = get_account(&mut context, pool_registry).await;
+    assert_eq!(registry.pools, pools);
+}
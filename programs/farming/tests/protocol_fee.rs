@@ -0,0 +1,63 @@
+//! Protocol fee snapshot tests.
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use farming::Pool;
+use solana_program_test::{processor, tokio, ProgramTest};
+use solana_sdk::signature::{Keypair, Signer};
+
+const REWARD_DURATION: u64 = 10;
+const PROTOCOL_FEE_BPS: u16 = 500;
+
+#[tokio::test]
+async fn test_initialize_pool_without_global_config_charges_no_fee() {
+    let mut program_test = ProgramTest::new("farming", farming::id(), processor!(farming_entry));
+    let (global_config, global_config_bump) =
+        Pubkey::find_program_address(&[farming::GLOBAL_CONFIG_SEED], &farming::id());
+    program_test.add_account(
+        global_config,
+        global_config_account(global_config_bump, PROTOCOL_FEE_BPS),
+    );
+    let mut context = program_test.start_with_context().await;
+    let authority = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&authority]).await;
+    let staking_mint = Keypair::new();
+    let other_staking_mint = Keypair::new();
+    let reward_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    create_mint(&mut context, &other_staking_mint).await;
+    create_mint(&mut context, &reward_mint).await;
+
+    let (with_config, keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &staking_mint.pubkey(),
+        &reward_mint.pubkey(),
+        REWARD_DURATION,
+    );
+    let (mut without_config, other_keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &other_staking_mint.pubkey(),
+        &reward_mint.pubkey(),
+        REWARD_DURATION,
+    );
+    // An omitted optional account is passed as the program id
+    for meta in without_config.accounts.iter_mut() {
+        if meta.pubkey == global_config {
+            meta.pubkey = farming::id();
+        }
+    }
+    for ix in [with_config, without_config] {
+        process(&mut context, &[ix], &[&authority, &base])
+            .await
+            .unwrap();
+    }
+
+    let pool: Pool = get_account(&mut context, keys.pool).await;
+    assert_eq!(pool.protocol_fee_bps, PROTOCOL_FEE_BPS);
+    let pool: Pool = get_account(&mut context, other_keys.pool).await;
+    assert_eq!(pool.protocol_fee_bps, 0);
+}
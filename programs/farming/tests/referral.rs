@@ -0,0 +1,264 @@
+//! Referral fee tests.
+//!
+//! Raising the fee only binds stake deposited afterwards, lowering it applies to every user.
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token::{spl_token, TokenAccount};
+use common::*;
+use farming::{ErrorCode, Referrer, User, MAX_REFERRAL_FEE_BPS};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+const REWARD_DURATION: u64 = 100;
+const DEPOSIT_AMOUNT: u64 = 1_000;
+const FUND_AMOUNT: u64 = 1_000_000;
+const REFERRAL_FEE_BPS: u16 = 500;
+
+struct ReferralFixture {
+    context: ProgramTestContext,
+    authority: Keypair,
+    owner: Keypair,
+    keys: PoolKeys,
+    user: Pubkey,
+    referrer: Pubkey,
+    owner_reward_account: Pubkey,
+}
+
+/// Funded pool with a referred user who deposited under the referral fee
+async fn setup() -> ReferralFixture {
+    let mut context = farming_program_test().start_with_context().await;
+    let owner = Keypair::new();
+    let authority = Keypair::new();
+    let referrer_owner = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&owner, &authority, &referrer_owner]).await;
+
+    let staking_mint = Keypair::new();
+    let reward_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    create_mint(&mut context, &reward_mint).await;
+    let (initialize_pool, keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &staking_mint.pubkey(),
+        &reward_mint.pubkey(),
+        REWARD_DURATION,
+    );
+    process(&mut context, &[initialize_pool], &[&authority, &base])
+        .await
+        .unwrap();
+
+    let mut fixture = ReferralFixture {
+        context,
+        user: user_address(&keys.pool, &owner.pubkey()),
+        referrer: Pubkey::find_program_address(
+            &[
+                farming::REFERRER_SEED,
+                keys.pool.as_ref(),
+                referrer_owner.pubkey().as_ref(),
+            ],
+            &farming::id(),
+        )
+        .0,
+        owner_reward_account: Pubkey::default(),
+        authority,
+        owner,
+        keys,
+    };
+    set_fee(&mut fixture, REFERRAL_FEE_BPS).await.unwrap();
+
+    let program_id = farming::id();
+    let create_referrer = Instruction {
+        program_id,
+        accounts: farming::accounts::CreateReferrer {
+            pool: fixture.keys.pool,
+            referrer: fixture.referrer,
+            owner: referrer_owner.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateReferrer {}.data(),
+    };
+    process(&mut fixture.context, &[create_referrer], &[&referrer_owner])
+        .await
+        .unwrap();
+
+    let owner_staking_account = create_token_account(
+        &mut fixture.context,
+        &staking_mint.pubkey(),
+        &fixture.owner.pubkey(),
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    fixture.owner_reward_account = create_token_account(
+        &mut fixture.context,
+        &reward_mint.pubkey(),
+        &fixture.owner.pubkey(),
+        0,
+    )
+    .await;
+    let set_referrer = Instruction {
+        program_id,
+        accounts: farming::accounts::SetReferrer {
+            pool: fixture.keys.pool,
+            user: fixture.user,
+            referrer: fixture.referrer,
+            referral: Pubkey::find_program_address(
+                &[
+                    farming::REFERRAL_SEED,
+                    fixture.keys.pool.as_ref(),
+                    fixture.owner.pubkey().as_ref(),
+                ],
+                &program_id,
+            )
+            .0,
+            owner: fixture.owner.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetReferrer {}.data(),
+    };
+    let deposit = stake_instruction(
+        &fixture.keys,
+        &fixture.owner.pubkey(),
+        &owner_staking_account,
+        farming::instruction::Deposit {
+            amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    );
+    process(
+        &mut fixture.context,
+        &[
+            create_user(&fixture.keys.pool, &fixture.owner.pubkey()),
+            set_referrer,
+            deposit,
+        ],
+        &[&fixture.owner],
+    )
+    .await
+    .unwrap();
+
+    let funder_reward_account = create_token_account(
+        &mut fixture.context,
+        &reward_mint.pubkey(),
+        &fixture.authority.pubkey(),
+        FUND_AMOUNT,
+    )
+    .await;
+    let fund = Instruction {
+        program_id,
+        accounts: farming::accounts::Fund {
+            pool: fixture.keys.pool,
+            staking_vault: fixture.keys.staking_vault,
+            reward_a_vault: fixture.keys.reward_vault,
+            reward_b_vault: fixture.keys.reward_vault,
+            funder: fixture.authority.pubkey(),
+            from_a: Some(funder_reward_account),
+            from_b: None,
+            token_program: spl_token::ID,
+            native_vault: None,
+            system_program: None,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::Fund {
+            amount_a: FUND_AMOUNT,
+            amount_b: 0,
+        }
+        .data(),
+    };
+    process(&mut fixture.context, &[fund], &[&fixture.authority])
+        .await
+        .unwrap();
+    fixture
+}
+
+async fn set_fee(
+    fixture: &mut ReferralFixture,
+    referral_fee_bps: u16,
+) -> std::result::Result<(), solana_program_test::BanksClientError> {
+    let ix = Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::SetReferralFee {
+            pool: fixture.keys.pool,
+            authority: fixture.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetReferralFee { referral_fee_bps }.data(),
+    };
+    process(&mut fixture.context, &[ix], &[&fixture.authority]).await
+}
+
+/// Claims the whole campaign and returns the rewards received by the owner and accrued to the referrer
+async fn claim_all_rewards(fixture: &mut ReferralFixture) -> (u64, u64) {
+    advance_clock(&mut fixture.context, REWARD_DURATION).await;
+    let ix = claim_instruction(farming::accounts::ClaimReward {
+        pool: fixture.keys.pool,
+        staking_vault: fixture.keys.staking_vault,
+        reward_a_vault: fixture.keys.reward_vault,
+        reward_b_vault: None,
+        user: fixture.user,
+        owner: fixture.owner.pubkey(),
+        reward_a_account: Some(fixture.owner_reward_account),
+        reward_b_account: None,
+        token_program: spl_token::ID,
+        global_config: None,
+        treasury_a_account: None,
+        treasury_b_account: None,
+        native_vault: None,
+        treasury: None,
+        system_program: None,
+        delegate: None,
+        referrer: Some(fixture.referrer),
+    });
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+    let received: TokenAccount =
+        get_account(&mut fixture.context, fixture.owner_reward_account).await;
+    let referrer: Referrer = get_account(&mut fixture.context, fixture.referrer).await;
+    (received.amount, referrer.reward_a_accrued)
+}
+
+#[tokio::test]
+async fn test_referral_fee_is_capped() {
+    let mut fixture = setup().await;
+    let result = set_fee(&mut fixture, MAX_REFERRAL_FEE_BPS + 1).await;
+    assert_error(result, ErrorCode::InvalidReferralFee);
+
+    set_fee(&mut fixture, MAX_REFERRAL_FEE_BPS).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_raised_referral_fee_applies_to_later_deposits() {
+    let mut fixture = setup().await;
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.referral_fee_bps, REFERRAL_FEE_BPS);
+
+    // Rewards accrued under the fee in force at the deposit keep paying that fee
+    set_fee(&mut fixture, MAX_REFERRAL_FEE_BPS).await.unwrap();
+    let (received, accrued) = claim_all_rewards(&mut fixture).await;
+    assert!(accrued > 0);
+    assert_eq!(
+        accrued,
+        (received + accrued) * u64::from(REFERRAL_FEE_BPS) / 10_000
+    );
+}
+
+#[tokio::test]
+async fn test_lowered_referral_fee_applies_to_every_user() {
+    let mut fixture = setup().await;
+    let lowered_fee_bps = REFERRAL_FEE_BPS / 5;
+    set_fee(&mut fixture, lowered_fee_bps).await.unwrap();
+    let (received, accrued) = claim_all_rewards(&mut fixture).await;
+    assert!(accrued > 0);
+    assert_eq!(
+        accrued,
+        (received + accrued) * u64::from(lowered_fee_bps) / 10_000
+    );
+}
@@ -0,0 +1,52 @@
+//! Single reward pool creation tests.
+mod common;
+
+use anchor_lang::prelude::*;
+use common::*;
+use farming::{ErrorCode, Pool, RewardMode};
+use solana_program_test::tokio;
+use solana_sdk::{
+    instruction::AccountMeta,
+    signature::{Keypair, Signer},
+};
+
+const REWARD_DURATION: u64 = 10;
+
+#[tokio::test]
+async fn test_single_reward_pool_uses_reward_a_vault() {
+    let mut context = farming_program_test().start_with_context().await;
+    let authority = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&authority]).await;
+    let staking_mint = Keypair::new();
+    let reward_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    create_mint(&mut context, &reward_mint).await;
+    let (initialize_pool, keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &staking_mint.pubkey(),
+        &reward_mint.pubkey(),
+        REWARD_DURATION,
+    );
+
+    // A reward B vault is not accepted next to the shared reward mint
+    let (reward_b_vault, _) =
+        Pubkey::find_program_address(&[b"reward_b", keys.pool.as_ref()], &farming::id());
+    let mut with_reward_b_vault = initialize_pool.clone();
+    for meta in with_reward_b_vault.accounts.iter_mut() {
+        if meta.pubkey == farming::id() && !meta.is_writable {
+            *meta = AccountMeta::new(reward_b_vault, false);
+            break;
+        }
+    }
+    let result = process(&mut context, &[with_reward_b_vault], &[&authority, &base]).await;
+    assert_error(result, ErrorCode::UnexpectedRewardVault);
+
+    process(&mut context, &[initialize_pool], &[&authority, &base])
+        .await
+        .unwrap();
+    let pool: Pool = get_account(&mut context, keys.pool).await;
+    assert_eq!(pool.reward_mode, RewardMode::Single);
+    assert_eq!(pool.reward_b_vault, keys.reward_vault);
+}
@@ -0,0 +1,310 @@
+//! Stake hub tests.
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use common::*;
+use farming::{ErrorCode, HubUser, Pool, StakeHub, User};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    signature::{Keypair, Signer},
+    sysvar,
+};
+
+const REWARD_DURATION: u64 = 10;
+const DEPOSIT_AMOUNT: u64 = 1_000;
+
+struct HubFixture {
+    context: ProgramTestContext,
+    authority: Keypair,
+    owner: Keypair,
+    stake_hub: Pubkey,
+    staking_vault: Pubkey,
+    hub_user: Pubkey,
+    owner_staking_account: Pubkey,
+    keys: PoolKeys,
+}
+
+/// Pool attached to a stake hub, whose only user staked in the hub and joined the pool
+async fn setup() -> HubFixture {
+    let mut context = farming_program_test().start_with_context().await;
+    let owner = Keypair::new();
+    let authority = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&owner, &authority]).await;
+
+    let staking_mint = Keypair::new();
+    let reward_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    create_mint(&mut context, &reward_mint).await;
+
+    let program_id = farming::id();
+    let (stake_hub, _) = Pubkey::find_program_address(
+        &[
+            farming::STAKE_HUB_SEED,
+            staking_mint.pubkey().as_ref(),
+            base.pubkey().as_ref(),
+        ],
+        &program_id,
+    );
+    let (staking_vault, _) =
+        Pubkey::find_program_address(&[b"staking", stake_hub.as_ref()], &program_id);
+    let initialize_stake_hub = Instruction {
+        program_id,
+        accounts: farming::accounts::InitializeStakeHub {
+            stake_hub,
+            staking_mint: staking_mint.pubkey(),
+            staking_vault,
+            authority: authority.pubkey(),
+            base: base.pubkey(),
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::InitializeStakeHub {}.data(),
+    };
+    let (initialize_pool, keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &staking_mint.pubkey(),
+        &reward_mint.pubkey(),
+        REWARD_DURATION,
+    );
+    process(
+        &mut context,
+        &[initialize_stake_hub, initialize_pool],
+        &[&authority, &base],
+    )
+    .await
+    .unwrap();
+    let attach_pool = attach_pool(&authority.pubkey(), &stake_hub, &keys);
+    process(&mut context, &[attach_pool], &[&authority])
+        .await
+        .unwrap();
+
+    let (hub_user, _) = Pubkey::find_program_address(
+        &[
+            farming::HUB_USER_SEED,
+            stake_hub.as_ref(),
+            owner.pubkey().as_ref(),
+        ],
+        &program_id,
+    );
+    let create_hub_user = Instruction {
+        program_id,
+        accounts: farming::accounts::CreateHubUser {
+            stake_hub,
+            hub_user,
+            owner: owner.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateHubUser {}.data(),
+    };
+    let join_hub_pool = Instruction {
+        program_id,
+        accounts: farming::accounts::HubPoolMembership {
+            stake_hub,
+            hub_user,
+            pool: keys.pool,
+            user: user_address(&keys.pool, &owner.pubkey()),
+            owner: owner.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::JoinHubPool {}.data(),
+    };
+    let owner_staking_account = create_token_account(
+        &mut context,
+        &staking_mint.pubkey(),
+        &owner.pubkey(),
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    let create_user = create_user(&keys.pool, &owner.pubkey());
+    process(
+        &mut context,
+        &[create_hub_user, create_user, join_hub_pool],
+        &[&owner],
+    )
+    .await
+    .unwrap();
+
+    let mut fixture = HubFixture {
+        context,
+        authority,
+        owner,
+        stake_hub,
+        staking_vault,
+        hub_user,
+        owner_staking_account,
+        keys,
+    };
+    let joined = vec![fixture.keys.pool];
+    let hub_deposit = hub_stake(
+        &fixture,
+        &joined,
+        farming::instruction::HubDeposit {
+            amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    );
+    process(&mut fixture.context, &[hub_deposit], &[&fixture.owner])
+        .await
+        .unwrap();
+    fixture
+}
+
+fn attach_pool(authority: &Pubkey, stake_hub: &Pubkey, keys: &PoolKeys) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::AttachPool {
+            stake_hub: *stake_hub,
+            authority: *authority,
+            pool: keys.pool,
+            staking_vault: keys.staking_vault,
+            pool_authority: *authority,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::AttachPool {}.data(),
+    }
+}
+
+/// Hub deposit or withdraw mirrored in the `joined` pools
+fn hub_stake(fixture: &HubFixture, joined: &[Pubkey], data: Vec<u8>) -> Instruction {
+    let mut accounts = farming::accounts::HubStake {
+        stake_hub: fixture.stake_hub,
+        staking_vault: fixture.staking_vault,
+        hub_user: fixture.hub_user,
+        owner: fixture.owner.pubkey(),
+        stake_from_account: fixture.owner_staking_account,
+        token_program: spl_token::ID,
+    }
+    .to_account_metas(None);
+    for pool in joined {
+        accounts.push(AccountMeta::new(*pool, false));
+        accounts.push(AccountMeta::new(
+            user_address(pool, &fixture.owner.pubkey()),
+            false,
+        ));
+    }
+    Instruction {
+        program_id: farming::id(),
+        accounts,
+        data,
+    }
+}
+
+fn detach_pool(fixture: &HubFixture) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::DetachPool {
+            stake_hub: fixture.stake_hub,
+            authority: fixture.authority.pubkey(),
+            pool: fixture.keys.pool,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::DetachPool {}.data(),
+    }
+}
+
+fn remove_hub_pool_member(fixture: &HubFixture) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::RemoveHubPoolMember {
+            stake_hub: fixture.stake_hub,
+            authority: fixture.authority.pubkey(),
+            hub_user: fixture.hub_user,
+            pool: fixture.keys.pool,
+            user: user_address(&fixture.keys.pool, &fixture.owner.pubkey()),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::RemoveHubPoolMember {}.data(),
+    }
+}
+
+#[tokio::test]
+async fn test_detach_pool_after_removing_members() {
+    let mut fixture = setup().await;
+
+    // The mirrored hub balance must be removed first
+    let ix = detach_pool(&fixture);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.authority]).await;
+    assert_error(result, ErrorCode::PoolHasStake);
+
+    let ix = remove_hub_pool_member(&fixture);
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+    let user: User = get_account(
+        &mut fixture.context,
+        user_address(&fixture.keys.pool, &fixture.owner.pubkey()),
+    )
+    .await;
+    assert_eq!(user.balance_staked, 0);
+    let hub_user: HubUser = get_account(&mut fixture.context, fixture.hub_user).await;
+    assert_eq!(hub_user.joined_pools[0], Pubkey::default());
+
+    let ix = detach_pool(&fixture);
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+    let pool: Pool = get_account(&mut fixture.context, fixture.keys.pool).await;
+    assert!(!pool.is_attached_to_hub());
+    assert_eq!(pool.total_staked, 0);
+    let stake_hub: StakeHub = get_account(&mut fixture.context, fixture.stake_hub).await;
+    assert_eq!(stake_hub.pools[0], Pubkey::default());
+
+    // The hub stake is no longer mirrored in the detached pool
+    let ix = hub_stake(
+        &fixture,
+        &[],
+        farming::instruction::HubWithdraw {
+            amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_detached_slot_is_not_joined_after_reattach() {
+    let mut fixture = setup().await;
+    let ix = remove_hub_pool_member(&fixture);
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+    let ix = detach_pool(&fixture);
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+
+    // The pool takes the freed slot again, without its former members
+    let ix = attach_pool(
+        &fixture.authority.pubkey(),
+        &fixture.stake_hub,
+        &fixture.keys,
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+    let ix = hub_stake(
+        &fixture,
+        &[fixture.keys.pool],
+        farming::instruction::HubWithdraw { amount: 1 }.data(),
+    );
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::InvalidHubPoolAccounts);
+    let ix = hub_stake(
+        &fixture,
+        &[],
+        farming::instruction::HubWithdraw { amount: 1 }.data(),
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,277 @@
+//! Unbonding period and minimum stake duration tests.
+//!
+//! Raising either only binds stake deposited afterwards, lowering it applies to every user.
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use common::*;
+use farming::{ErrorCode, User, MAX_MIN_STAKE_DURATION, MAX_UNBONDING_PERIOD};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction,
+    signature::{Keypair, Signer},
+};
+
+const REWARD_DURATION: u64 = 10;
+const DEPOSIT_AMOUNT: u64 = 1_000;
+const UNBONDING_PERIOD: u64 = 3_600;
+const MIN_STAKE_DURATION: u64 = 3_600;
+
+struct StakeFixture {
+    context: ProgramTestContext,
+    authority: Keypair,
+    owner: Keypair,
+    keys: PoolKeys,
+    user: Pubkey,
+    owner_staking_account: Pubkey,
+}
+
+/// Single reward pool with a user holding staking tokens, nothing deposited yet
+async fn setup() -> StakeFixture {
+    let mut context = farming_program_test().start_with_context().await;
+    let owner = Keypair::new();
+    let authority = Keypair::new();
+    let base = Keypair::new();
+    fund_wallets(&mut context, &[&owner, &authority]).await;
+
+    let staking_mint = Keypair::new();
+    let reward_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    create_mint(&mut context, &reward_mint).await;
+    let (initialize_pool, keys) = initialize_pool(
+        &authority.pubkey(),
+        &base.pubkey(),
+        &staking_mint.pubkey(),
+        &reward_mint.pubkey(),
+        REWARD_DURATION,
+    );
+    process(&mut context, &[initialize_pool], &[&authority, &base])
+        .await
+        .unwrap();
+
+    let owner_staking_account = create_token_account(
+        &mut context,
+        &staking_mint.pubkey(),
+        &owner.pubkey(),
+        2 * DEPOSIT_AMOUNT,
+    )
+    .await;
+    let create_user = create_user(&keys.pool, &owner.pubkey());
+    process(&mut context, &[create_user], &[&owner])
+        .await
+        .unwrap();
+
+    StakeFixture {
+        context,
+        user: user_address(&keys.pool, &owner.pubkey()),
+        authority,
+        owner,
+        keys,
+        owner_staking_account,
+    }
+}
+
+fn set_unbonding_period(fixture: &StakeFixture, unbonding_period: u64) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::SetUnbondingPeriod {
+            pool: fixture.keys.pool,
+            authority: fixture.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetUnbondingPeriod { unbonding_period }.data(),
+    }
+}
+
+fn stake_instruction_of(fixture: &StakeFixture, data: Vec<u8>) -> Instruction {
+    stake_instruction(
+        &fixture.keys,
+        &fixture.owner.pubkey(),
+        &fixture.owner_staking_account,
+        data,
+    )
+}
+
+fn set_min_stake_duration(fixture: &StakeFixture, min_stake_duration: u64) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::SetMinStakeDuration {
+            pool: fixture.keys.pool,
+            authority: fixture.authority.pubkey(),
+        }
+        .to_account_metas(None),
+        data: farming::instruction::SetMinStakeDuration { min_stake_duration }.data(),
+    }
+}
+
+/// Claim of a user without pending rewards, only checking the minimum stake duration
+fn claim(fixture: &StakeFixture) -> Instruction {
+    claim_instruction(farming::accounts::ClaimReward {
+        pool: fixture.keys.pool,
+        staking_vault: fixture.keys.staking_vault,
+        reward_a_vault: fixture.keys.reward_vault,
+        reward_b_vault: None,
+        user: fixture.user,
+        owner: fixture.owner.pubkey(),
+        reward_a_account: None,
+        reward_b_account: None,
+        token_program: spl_token::ID,
+        global_config: None,
+        treasury_a_account: None,
+        treasury_b_account: None,
+        native_vault: None,
+        treasury: None,
+        system_program: None,
+        delegate: None,
+        referrer: None,
+    })
+}
+
+async fn deposit(fixture: &mut StakeFixture) {
+    let ix = stake_instruction_of(
+        fixture,
+        farming::instruction::Deposit {
+            amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+}
+
+async fn set_period(fixture: &mut StakeFixture, unbonding_period: u64) {
+    let ix = set_unbonding_period(fixture, unbonding_period);
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+}
+
+async fn set_duration(fixture: &mut StakeFixture, min_stake_duration: u64) {
+    let ix = set_min_stake_duration(fixture, min_stake_duration);
+    process(&mut fixture.context, &[ix], &[&fixture.authority])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_unbonding_period_is_capped() {
+    let mut fixture = setup().await;
+    let ix = set_unbonding_period(&fixture, MAX_UNBONDING_PERIOD + 1);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.authority]).await;
+    assert_error(result, ErrorCode::UnbondingPeriodTooLong);
+
+    set_period(&mut fixture, MAX_UNBONDING_PERIOD).await;
+}
+
+#[tokio::test]
+async fn test_raised_unbonding_period_applies_to_later_deposits() {
+    let mut fixture = setup().await;
+    deposit(&mut fixture).await;
+    set_period(&mut fixture, UNBONDING_PERIOD).await;
+
+    // Stake deposited without an unbonding period can still be withdrawn directly
+    let ix = stake_instruction_of(
+        &fixture,
+        farming::instruction::Withdraw {
+            spt_amount: DEPOSIT_AMOUNT / 2,
+        }
+        .data(),
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+
+    // A new deposit binds the whole stake to the current period
+    deposit(&mut fixture).await;
+    let ix = stake_instruction_of(
+        &fixture,
+        farming::instruction::Withdraw { spt_amount: 1 }.data(),
+    );
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::UnbondingRequired);
+
+    // Raising the period again does not extend the period of the deposited stake
+    set_period(&mut fixture, MAX_UNBONDING_PERIOD).await;
+    let ix = stake_instruction_of(
+        &fixture,
+        farming::instruction::RequestWithdraw { spt_amount: 1 }.data(),
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+    let clock: Clock = fixture.context.banks_client.get_sysvar().await.unwrap();
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.unbonding_period, UNBONDING_PERIOD);
+    assert_eq!(
+        user.unbonding_end,
+        clock.unix_timestamp as u64 + UNBONDING_PERIOD
+    );
+}
+
+#[tokio::test]
+async fn test_lowered_unbonding_period_applies_to_every_user() {
+    let mut fixture = setup().await;
+    set_period(&mut fixture, UNBONDING_PERIOD).await;
+    deposit(&mut fixture).await;
+    set_period(&mut fixture, 0).await;
+
+    let ix = stake_instruction_of(
+        &fixture,
+        farming::instruction::Withdraw {
+            spt_amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    );
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.balance_staked, 0);
+}
+
+#[tokio::test]
+async fn test_min_stake_duration_is_capped() {
+    let mut fixture = setup().await;
+    let ix = set_min_stake_duration(&fixture, MAX_MIN_STAKE_DURATION + 1);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.authority]).await;
+    assert_error(result, ErrorCode::MinStakeDurationTooLong);
+
+    set_duration(&mut fixture, MAX_MIN_STAKE_DURATION).await;
+}
+
+#[tokio::test]
+async fn test_raised_min_stake_duration_applies_to_later_deposits() {
+    let mut fixture = setup().await;
+    set_duration(&mut fixture, MIN_STAKE_DURATION).await;
+    deposit(&mut fixture).await;
+    let ix = claim(&fixture);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::MinStakeDurationNotReached);
+
+    // Raising the duration does not push back the rewards of the deposited stake
+    set_duration(&mut fixture, MAX_MIN_STAKE_DURATION).await;
+    advance_clock(&mut fixture.context, MIN_STAKE_DURATION).await;
+    let ix = claim(&fixture);
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+
+    // A new deposit binds the whole stake to the current duration
+    deposit(&mut fixture).await;
+    advance_clock(&mut fixture.context, MIN_STAKE_DURATION).await;
+    let ix = claim(&fixture);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::MinStakeDurationNotReached);
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.min_stake_duration, MAX_MIN_STAKE_DURATION);
+
+    // Lowering the duration applies to every user
+    set_duration(&mut fixture, 0).await;
+    let ix = claim(&fixture);
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+}
@@ -0,0 +1,767 @@
+//! Voter weight plugin tests.
+//!
+//! The stake lock tests preload a realm laid out as spl-governance stores it, owned by an
+//! executable stand-in of the governance program, which is all the plugin reads.
+//! `test_cast_vote_with_voter_weight_record` loads the spl-governance v3 program itself from
+//! `tests/fixtures/spl_governance.so` and votes on a proposal with the plugin's voter weight record.
+//! spl-governance 3.x cannot be linked as a native processor against solana 1.16, so CI dumps the
+//! mainnet deployment there before running the tests. Do the same locally:
+//!
+//! `solana program dump -u m GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZW tests/fixtures/spl_governance.so`
+mod common;
+
+use anchor_lang::prelude::*;
+use anchor_lang::{system_program, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use common::*;
+use farming::{ErrorCode, Pool, User, VoterWeightRecord};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::Account as SolanaAccount,
+    bpf_loader,
+    instruction::Instruction,
+    pubkey,
+    signature::{Keypair, Signer},
+    system_instruction, sysvar,
+};
+use std::convert::TryInto;
+
+const REWARD_DURATION: u64 = 10;
+const VOTE_LOCK_DURATION: u64 = 3_600;
+const DEPOSIT_AMOUNT: u64 = 1_000;
+
+/// Account type of realms created by spl-governance v2 and later
+const REALM_V2_ACCOUNT_TYPE: u8 = 16;
+
+/// spl-governance mainnet deployment, loaded from `tests/fixtures/spl_governance.so`
+const SPL_GOVERNANCE_ID: Pubkey = pubkey!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZW");
+const REALM_NAME: &str = "DAO!";
+/// Max voter weight source counting the whole community mint supply
+const SUPPLY_FRACTION_BASE: u64 = 10_000_000_000;
+const VOTING_BASE_TIME: u32 = 3_600;
+/// Account type of vote records created by spl-governance v3
+const VOTE_RECORD_V2_ACCOUNT_TYPE: u8 = 12;
+
+fn realm_data(community_mint: &Pubkey, authority: &Pubkey) -> Vec<u8> {
+    let mut data = vec![REALM_V2_ACCOUNT_TYPE];
+    data.extend_from_slice(community_mint.as_ref());
+    // legacy flags, reserved, min community weight to create governance
+    data.extend_from_slice(&[0u8; 1 + 1 + 6 + 8]);
+    // max voter weight source
+    data.push(0);
+    data.extend_from_slice(&10_000_000_000u64.to_le_bytes());
+    // no council mint
+    data.push(0);
+    // reserved, legacy voting proposal count
+    data.extend_from_slice(&[0u8; 6 + 2]);
+    data.push(1);
+    data.extend_from_slice(authority.as_ref());
+    // name
+    data.extend_from_slice(&4u32.to_le_bytes());
+    data.extend_from_slice(b"DAO!");
+    data
+}
+
+struct VoterFixture {
+    context: ProgramTestContext,
+    owner: Keypair,
+    pool: Pubkey,
+    staking_vault: Pubkey,
+    reward_vault: Pubkey,
+    user: Pubkey,
+    owner_staking_account: Pubkey,
+    registrar: Pubkey,
+    voter_weight_record: Pubkey,
+}
+
+/// Deposit into a single reward pool bound to a preloaded realm, with the voter weight record created
+async fn setup() -> VoterFixture {
+    let governance_program_id = Pubkey::new_unique();
+    let realm = Pubkey::new_unique();
+    let realm_authority = Keypair::new();
+    let governing_token_mint = Keypair::new();
+
+    let mut program_test = farming_program_test();
+    program_test.add_account(
+        governance_program_id,
+        SolanaAccount {
+            lamports: 1_000_000_000,
+            executable: true,
+            owner: bpf_loader::ID,
+            ..SolanaAccount::default()
+        },
+    );
+    program_test.add_account(
+        realm,
+        SolanaAccount {
+            lamports: 1_000_000_000,
+            data: realm_data(&governing_token_mint.pubkey(), &realm_authority.pubkey()),
+            owner: governance_program_id,
+            ..SolanaAccount::default()
+        },
+    );
+    let mut context = program_test.start_with_context().await;
+    create_mint(&mut context, &governing_token_mint).await;
+
+    setup_pool(
+        context,
+        governance_program_id,
+        realm,
+        &realm_authority,
+        &governing_token_mint.pubkey(),
+    )
+    .await
+}
+
+/// Deposit into a single reward pool bound to `realm`, with the voter weight record created
+async fn setup_pool(
+    mut context: ProgramTestContext,
+    governance_program_id: Pubkey,
+    realm: Pubkey,
+    realm_authority: &Keypair,
+    governing_token_mint: &Pubkey,
+) -> VoterFixture {
+    let owner = Keypair::new();
+    let authority = Keypair::new();
+    let base = Keypair::new();
+    for wallet in [&owner, &authority, realm_authority] {
+        let transfer =
+            system_instruction::transfer(&context.payer.pubkey(), &wallet.pubkey(), 1_000_000_000);
+        process(&mut context, &[transfer], &[]).await.unwrap();
+    }
+
+    let staking_mint = Keypair::new();
+    let reward_mint = Keypair::new();
+    create_mint(&mut context, &staking_mint).await;
+    create_mint(&mut context, &reward_mint).await;
+
+    let program_id = farming::id();
+    let (global_config, _) =
+        Pubkey::find_program_address(&[farming::GLOBAL_CONFIG_SEED], &program_id);
+    let (pool, _) = Pubkey::find_program_address(
+        &[
+            REWARD_DURATION.to_be_bytes().as_ref(),
+            staking_mint.pubkey().as_ref(),
+            reward_mint.pubkey().as_ref(),
+            reward_mint.pubkey().as_ref(),
+            base.pubkey().as_ref(),
+        ],
+        &program_id,
+    );
+    let (staking_vault, _) =
+        Pubkey::find_program_address(&[b"staking", pool.as_ref()], &program_id);
+    let (reward_vault, _) =
+        Pubkey::find_program_address(&[b"reward_a", pool.as_ref()], &program_id);
+    let (pool_registry, _) = Pubkey::find_program_address(
+        &[farming::POOL_REGISTRY_SEED, staking_mint.pubkey().as_ref()],
+        &program_id,
+    );
+    let initialize_pool = Instruction {
+        program_id,
+        accounts: farming::accounts::InitializePool {
+            pool,
+            staking_mint: staking_mint.pubkey(),
+            staking_vault,
+            reward_a_mint: reward_mint.pubkey(),
+            reward_a_vault: reward_vault,
+            reward_b_mint: reward_mint.pubkey(),
+            reward_b_vault: None,
+            authority: authority.pubkey(),
+            base: base.pubkey(),
+            global_config: Some(global_config),
+            pool_registry,
+            system_program: system_program::ID,
+            token_program: spl_token::ID,
+            rent: sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::InitializePool {
+            reward_duration: REWARD_DURATION,
+        }
+        .data(),
+    };
+    process(&mut context, &[initialize_pool], &[&authority, &base])
+        .await
+        .unwrap();
+
+    let (user, _) =
+        Pubkey::find_program_address(&[owner.pubkey().as_ref(), pool.as_ref()], &program_id);
+    let create_user = Instruction {
+        program_id,
+        accounts: farming::accounts::CreateUser {
+            pool,
+            user,
+            owner: owner.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateUser {}.data(),
+    };
+    let owner_staking_account = create_token_account(
+        &mut context,
+        &staking_mint.pubkey(),
+        &owner.pubkey(),
+        DEPOSIT_AMOUNT,
+    )
+    .await;
+    let deposit = Instruction {
+        program_id,
+        accounts: farming::accounts::Deposit {
+            pool,
+            staking_vault,
+            user,
+            owner: owner.pubkey(),
+            stake_from_account: owner_staking_account,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::Deposit {
+            amount: DEPOSIT_AMOUNT,
+        }
+        .data(),
+    };
+    process(&mut context, &[create_user, deposit], &[&owner])
+        .await
+        .unwrap();
+
+    let (registrar, _) = Pubkey::find_program_address(
+        &[
+            farming::REGISTRAR_SEED,
+            realm.as_ref(),
+            governing_token_mint.as_ref(),
+        ],
+        &program_id,
+    );
+    let create_registrar = Instruction {
+        program_id,
+        accounts: farming::accounts::CreateRegistrar {
+            registrar,
+            realm,
+            governance_program_id,
+            governing_token_mint: *governing_token_mint,
+            realm_authority: realm_authority.pubkey(),
+            pool,
+            payer: realm_authority.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateRegistrar {
+            vote_lock_duration: VOTE_LOCK_DURATION,
+        }
+        .data(),
+    };
+    let (voter_weight_record, _) = Pubkey::find_program_address(
+        &[
+            farming::VOTER_WEIGHT_RECORD_SEED,
+            registrar.as_ref(),
+            owner.pubkey().as_ref(),
+        ],
+        &program_id,
+    );
+    let create_voter_weight_record = Instruction {
+        program_id,
+        accounts: farming::accounts::CreateVoterWeightRecord {
+            registrar,
+            voter_weight_record,
+            governing_token_owner: owner.pubkey(),
+            payer: owner.pubkey(),
+            system_program: system_program::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::CreateVoterWeightRecord {}.data(),
+    };
+    process(&mut context, &[create_registrar], &[realm_authority])
+        .await
+        .unwrap();
+    process(&mut context, &[create_voter_weight_record], &[&owner])
+        .await
+        .unwrap();
+
+    VoterFixture {
+        context,
+        owner,
+        pool,
+        staking_vault,
+        reward_vault,
+        user,
+        owner_staking_account,
+        registrar,
+        voter_weight_record,
+    }
+}
+
+fn update_voter_weight_record(
+    fixture: &VoterFixture,
+    governing_token_owner: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::UpdateVoterWeightRecord {
+            registrar: fixture.registrar,
+            voter_weight_record: fixture.voter_weight_record,
+            user: fixture.user,
+            governing_token_owner: *governing_token_owner,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::UpdateVoterWeightRecord {}.data(),
+    }
+}
+
+fn withdraw(fixture: &VoterFixture, amount: u64) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::Deposit {
+            pool: fixture.pool,
+            staking_vault: fixture.staking_vault,
+            user: fixture.user,
+            owner: fixture.owner.pubkey(),
+            stake_from_account: fixture.owner_staking_account,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::Withdraw { spt_amount: amount }.data(),
+    }
+}
+
+fn request_withdraw(fixture: &VoterFixture, amount: u64) -> Instruction {
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::Deposit {
+            pool: fixture.pool,
+            staking_vault: fixture.staking_vault,
+            user: fixture.user,
+            owner: fixture.owner.pubkey(),
+            stake_from_account: fixture.owner_staking_account,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None),
+        data: farming::instruction::RequestWithdraw { spt_amount: amount }.data(),
+    }
+}
+
+fn transfer_position(fixture: &VoterFixture, recipient: &Pubkey) -> Instruction {
+    let (recipient_user, _) =
+        Pubkey::find_program_address(&[recipient.as_ref(), fixture.pool.as_ref()], &farming::id());
+    Instruction {
+        program_id: farming::id(),
+        accounts: farming::accounts::TransferPosition {
+            recipient: *recipient,
+            recipient_user,
+            payer: fixture.owner.pubkey(),
+            system_program: system_program::ID,
+            claim: farming::accounts::ClaimReward {
+                pool: fixture.pool,
+                staking_vault: fixture.staking_vault,
+                reward_a_vault: fixture.reward_vault,
+                reward_b_vault: None,
+                user: fixture.user,
+                owner: fixture.owner.pubkey(),
+                reward_a_account: None,
+                reward_b_account: None,
+                token_program: spl_token::ID,
+                global_config: None,
+                treasury_a_account: None,
+                treasury_b_account: None,
+                native_vault: None,
+                treasury: None,
+                system_program: None,
+                delegate: None,
+                referrer: None,
+            },
+        }
+        .to_account_metas(None),
+        data: farming::instruction::TransferPosition {}.data(),
+    }
+}
+
+/// Accounts of a proposal created through spl-governance by the fixture owner
+struct GovernanceProposal {
+    realm: Pubkey,
+    governing_token_mint: Pubkey,
+    governance: Pubkey,
+    proposal: Pubkey,
+    token_owner_record: Pubkey,
+}
+
+fn governance_address(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &SPL_GOVERNANCE_ID).0
+}
+
+fn realm_config_address(realm: &Pubkey) -> Pubkey {
+    governance_address(&[b"realm-config", realm.as_ref()])
+}
+
+/// Realm whose community voter weight comes from the farming plugin
+fn governance_create_realm(
+    payer: &Pubkey,
+    realm_authority: &Pubkey,
+    community_mint: &Pubkey,
+) -> Instruction {
+    let realm = governance_address(&[b"governance", REALM_NAME.as_bytes()]);
+    let data = (
+        0u8, // CreateRealm
+        REALM_NAME.to_string(),
+        // no council mint, min community weight to create governance
+        (false, 1u64),
+        // max voter weight source: full community mint supply
+        (0u8, SUPPLY_FRACTION_BASE),
+        // community voter weight addin, no max voter weight addin, liquid token
+        (true, false, 0u8),
+        (false, false, 0u8),
+    );
+    Instruction {
+        program_id: SPL_GOVERNANCE_ID,
+        accounts: vec![
+            AccountMeta::new(realm, false),
+            AccountMeta::new_readonly(*realm_authority, false),
+            AccountMeta::new_readonly(*community_mint, false),
+            AccountMeta::new(
+                governance_address(&[b"governance", realm.as_ref(), community_mint.as_ref()]),
+                false,
+            ),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(spl_token::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new(realm_config_address(&realm), false),
+            AccountMeta::new_readonly(farming::id(), false),
+        ],
+        data: data.try_to_vec().unwrap(),
+    }
+}
+
+fn governance_create_token_owner_record(
+    proposal: &GovernanceProposal,
+    owner: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: SPL_GOVERNANCE_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(proposal.realm, false),
+            AccountMeta::new_readonly(*owner, false),
+            AccountMeta::new(proposal.token_owner_record, false),
+            AccountMeta::new_readonly(proposal.governing_token_mint, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ],
+        // CreateTokenOwnerRecord
+        data: vec![23],
+    }
+}
+
+fn governance_create_governance(
+    proposal: &GovernanceProposal,
+    governed_account: &Pubkey,
+    realm_authority: &Pubkey,
+    payer: &Pubkey,
+) -> Instruction {
+    let data = (
+        4u8, // CreateGovernance
+        // community vote threshold of 60% yes, min community weight to create proposal
+        (0u8, 60u8, 1u64),
+        // min transaction hold up time, voting base time, strict community vote tipping
+        (0u32, VOTING_BASE_TIME, 0u8),
+        // council vote and veto thresholds disabled, min council weight, council vote tipping
+        (2u8, 2u8, 1u64, 0u8),
+        // community veto threshold disabled, voting cool off time, deposit exempt proposal count
+        (2u8, 0u32, 10u8),
+    );
+    Instruction {
+        program_id: SPL_GOVERNANCE_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(proposal.realm, false),
+            AccountMeta::new(proposal.governance, false),
+            AccountMeta::new_readonly(*governed_account, false),
+            AccountMeta::new_readonly(proposal.token_owner_record, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(*realm_authority, true),
+            AccountMeta::new_readonly(realm_config_address(&proposal.realm), false),
+        ],
+        data: data.try_to_vec().unwrap(),
+    }
+}
+
+fn governance_create_proposal(
+    proposal: &GovernanceProposal,
+    proposal_seed: &Pubkey,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    voter_weight_record: &Pubkey,
+) -> Instruction {
+    let data = (
+        6u8, // CreateProposal
+        "Raise the reward rate".to_string(),
+        String::new(),
+        // single choice vote on one option with the deny option
+        0u8,
+        vec!["Approve".to_string()],
+        true,
+        *proposal_seed,
+    );
+    Instruction {
+        program_id: SPL_GOVERNANCE_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(proposal.realm, false),
+            AccountMeta::new(proposal.proposal, false),
+            AccountMeta::new(proposal.governance, false),
+            AccountMeta::new(proposal.token_owner_record, false),
+            AccountMeta::new_readonly(proposal.governing_token_mint, false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(realm_config_address(&proposal.realm), false),
+            AccountMeta::new_readonly(*voter_weight_record, false),
+            AccountMeta::new(
+                governance_address(&[
+                    b"proposal-deposit",
+                    proposal.proposal.as_ref(),
+                    payer.as_ref(),
+                ]),
+                false,
+            ),
+        ],
+        data: data.try_to_vec().unwrap(),
+    }
+}
+
+fn governance_sign_off_proposal(proposal: &GovernanceProposal, owner: &Pubkey) -> Instruction {
+    Instruction {
+        program_id: SPL_GOVERNANCE_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(proposal.realm, false),
+            AccountMeta::new_readonly(proposal.governance, false),
+            AccountMeta::new(proposal.proposal, false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new_readonly(proposal.token_owner_record, false),
+        ],
+        // SignOffProposal
+        data: vec![12],
+    }
+}
+
+fn governance_cast_vote(
+    proposal: &GovernanceProposal,
+    owner: &Pubkey,
+    payer: &Pubkey,
+    voter_weight_record: &Pubkey,
+) -> Instruction {
+    // CastVote approving the only option with full weight
+    let data = (13u8, 0u8, vec![(0u8, 100u8)]);
+    Instruction {
+        program_id: SPL_GOVERNANCE_ID,
+        accounts: vec![
+            AccountMeta::new_readonly(proposal.realm, false),
+            AccountMeta::new(proposal.governance, false),
+            AccountMeta::new(proposal.proposal, false),
+            AccountMeta::new(proposal.token_owner_record, false),
+            AccountMeta::new(proposal.token_owner_record, false),
+            AccountMeta::new_readonly(*owner, true),
+            AccountMeta::new(vote_record_address(proposal), false),
+            AccountMeta::new_readonly(proposal.governing_token_mint, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(realm_config_address(&proposal.realm), false),
+            AccountMeta::new_readonly(*voter_weight_record, false),
+        ],
+        data: data.try_to_vec().unwrap(),
+    }
+}
+
+fn vote_record_address(proposal: &GovernanceProposal) -> Pubkey {
+    governance_address(&[
+        b"governance",
+        proposal.proposal.as_ref(),
+        proposal.token_owner_record.as_ref(),
+    ])
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_locks_stake() {
+    let mut fixture = setup().await;
+    let owner = fixture.owner.pubkey();
+
+    let update = update_voter_weight_record(&fixture, &owner);
+    process(&mut fixture.context, &[update], &[&fixture.owner])
+        .await
+        .unwrap();
+
+    let clock: Clock = fixture.context.banks_client.get_sysvar().await.unwrap();
+    let record: VoterWeightRecord =
+        get_account(&mut fixture.context, fixture.voter_weight_record).await;
+    assert_eq!(record.voter_weight, DEPOSIT_AMOUNT);
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(
+        user.vote_locked_until,
+        clock.unix_timestamp as u64 + VOTE_LOCK_DURATION
+    );
+
+    let ix = withdraw(&fixture, 1);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::StakeVoteLocked);
+    let ix = request_withdraw(&fixture, 1);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::StakeVoteLocked);
+    let ix = transfer_position(&fixture, &Pubkey::new_unique());
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::StakeVoteLocked);
+}
+
+#[tokio::test]
+async fn test_stake_unlocks_after_vote_lock_duration() {
+    let mut fixture = setup().await;
+    let owner = fixture.owner.pubkey();
+
+    let update = update_voter_weight_record(&fixture, &owner);
+    process(&mut fixture.context, &[update], &[&fixture.owner])
+        .await
+        .unwrap();
+
+    let mut clock: Clock = fixture.context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += VOTE_LOCK_DURATION as i64;
+    fixture.context.set_sysvar(&clock);
+
+    let ix = withdraw(&fixture, DEPOSIT_AMOUNT);
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.balance_staked, 0);
+    let pool: Pool = get_account(&mut fixture.context, fixture.pool).await;
+    assert_eq!(pool.total_staked, 0);
+}
+
+#[tokio::test]
+async fn test_update_voter_weight_requires_owner() {
+    let mut fixture = setup().await;
+    let other = Keypair::new();
+
+    let update = update_voter_weight_record(&fixture, &other.pubkey());
+    let result = process(&mut fixture.context, &[update], &[&other]).await;
+    assert_error(result, ErrorCode::InvalidVoterUser);
+
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert_eq!(user.vote_locked_until, 0);
+    let ix = withdraw(&fixture, DEPOSIT_AMOUNT);
+    process(&mut fixture.context, &[ix], &[&fixture.owner])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_cast_vote_with_voter_weight_record() {
+    let realm_authority = Keypair::new();
+    let governing_token_mint = Keypair::new();
+
+    let mut program_test = farming_program_test();
+    program_test.add_program("spl_governance", SPL_GOVERNANCE_ID, None);
+    let mut context = program_test.start_with_context().await;
+    create_mint(&mut context, &governing_token_mint).await;
+    let create_realm = governance_create_realm(
+        &context.payer.pubkey(),
+        &realm_authority.pubkey(),
+        &governing_token_mint.pubkey(),
+    );
+    process(&mut context, &[create_realm], &[]).await.unwrap();
+
+    let realm = governance_address(&[b"governance", REALM_NAME.as_bytes()]);
+    let mut fixture = setup_pool(
+        context,
+        SPL_GOVERNANCE_ID,
+        realm,
+        &realm_authority,
+        &governing_token_mint.pubkey(),
+    )
+    .await;
+    let owner = fixture.owner.pubkey();
+    let payer = fixture.context.payer.pubkey();
+
+    let governed_account = Pubkey::new_unique();
+    let governance = governance_address(&[
+        b"account-governance",
+        realm.as_ref(),
+        governed_account.as_ref(),
+    ]);
+    let proposal_seed = Pubkey::new_unique();
+    let proposal = GovernanceProposal {
+        realm,
+        governing_token_mint: governing_token_mint.pubkey(),
+        governance,
+        proposal: governance_address(&[
+            b"governance",
+            governance.as_ref(),
+            governing_token_mint.pubkey().as_ref(),
+            proposal_seed.as_ref(),
+        ]),
+        token_owner_record: governance_address(&[
+            b"governance",
+            realm.as_ref(),
+            governing_token_mint.pubkey().as_ref(),
+            owner.as_ref(),
+        ]),
+    };
+
+    let create_token_owner_record = governance_create_token_owner_record(&proposal, &owner, &payer);
+    let create_governance = governance_create_governance(
+        &proposal,
+        &governed_account,
+        &realm_authority.pubkey(),
+        &payer,
+    );
+    process(
+        &mut fixture.context,
+        &[create_token_owner_record, create_governance],
+        &[&realm_authority],
+    )
+    .await
+    .unwrap();
+
+    // The record expires at the current slot, so every use refreshes it in the same transaction
+    let update = update_voter_weight_record(&fixture, &owner);
+    let create_proposal = governance_create_proposal(
+        &proposal,
+        &proposal_seed,
+        &owner,
+        &payer,
+        &fixture.voter_weight_record,
+    );
+    let sign_off = governance_sign_off_proposal(&proposal, &owner);
+    process(
+        &mut fixture.context,
+        &[update, create_proposal, sign_off],
+        &[&fixture.owner],
+    )
+    .await
+    .unwrap();
+
+    let update = update_voter_weight_record(&fixture, &owner);
+    let cast_vote = governance_cast_vote(&proposal, &owner, &payer, &fixture.voter_weight_record);
+    process(
+        &mut fixture.context,
+        &[update, cast_vote],
+        &[&fixture.owner],
+    )
+    .await
+    .unwrap();
+
+    let vote_record = fixture
+        .context
+        .banks_client
+        .get_account(vote_record_address(&proposal))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(vote_record.owner, SPL_GOVERNANCE_ID);
+    assert_eq!(vote_record.data[0], VOTE_RECORD_V2_ACCOUNT_TYPE);
+    // account type, proposal, governing token owner, is relinquished
+    assert_eq!(&vote_record.data[33..65], owner.as_ref());
+    let voter_weight = u64::from_le_bytes(vote_record.data[66..74].try_into().unwrap());
+    assert_eq!(voter_weight, DEPOSIT_AMOUNT);
+
+    let user: User = get_account(&mut fixture.context, fixture.user).await;
+    assert!(user.vote_locked_until > 0);
+    let ix = withdraw(&fixture, DEPOSIT_AMOUNT);
+    let result = process(&mut fixture.context, &[ix], &[&fixture.owner]).await;
+    assert_error(result, ErrorCode::StakeVoteLocked);
+}
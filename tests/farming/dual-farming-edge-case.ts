@@ -8,11 +8,14 @@ import {
 } from "@solana/web3.js";
 import { Farming } from "../../target/types/farming";
 import {
+  getGlobalConfigPda,
   getPoolPda,
+  getPoolRegistryPda,
   getRewardAVaultPda,
   getRewardBVaultPda,
   getStakingVaultPda,
   getUserPda,
+  initializeGlobalConfigIfNeeded,
   REWARD_DURATION,
 } from "./utils";
 import assert from "assert";
@@ -67,6 +70,8 @@ describe("dual-farming-edge-case", () => {
   let funderRewardBATA: anchor.web3.PublicKey = null;
 
   before(async () => {
+    await initializeGlobalConfigIfNeeded(program);
+
     let sig = await program.provider.connection.requestAirdrop(
       ADMIN_KEYPAIR.publicKey,
       100 * LAMPORTS_PER_SOL
@@ -208,7 +213,9 @@ describe("dual-farming-edge-case", () => {
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
         base: BASE_KEYPAIR.publicKey,
+        globalConfig: (await getGlobalConfigPda(program))[0],
         pool: farmingPoolAddress,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         rewardAMint,
         rewardBMint,
@@ -350,10 +357,12 @@ describe("dual-farming-edge-case", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -505,14 +514,22 @@ describe("dual-farming-edge-case", () => {
     await program.methods
       .claim()
       .accounts({
+        delegate: program.programId,
+        globalConfig: program.programId,
+        nativeVault: program.programId,
         owner: USER_KEYPAIR.publicKey,
         pool: farmingPoolAddress,
+        referrer: program.programId,
         rewardAAccount: userRewardAATA,
         rewardBAccount: userRewardBATA,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: program.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        treasury: program.programId,
+        treasuryAAccount: program.programId,
+        treasuryBAccount: program.programId,
         user: userStakingAddress,
       })
       .signers([USER_KEYPAIR])
@@ -591,10 +608,12 @@ describe("dual-farming-edge-case", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: beforePoolState.rewardAVault,
         rewardBVault: beforePoolState.rewardBVault,
         stakingVault: beforePoolState.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -710,14 +729,22 @@ describe("dual-farming-edge-case", () => {
     await program.methods
       .claim()
       .accounts({
+        delegate: program.programId,
+        globalConfig: program.programId,
+        nativeVault: program.programId,
         owner: USER_KEYPAIR.publicKey,
         pool: farmingPoolAddress,
+        referrer: program.programId,
         rewardAAccount: userRewardAATA,
         rewardBAccount: userRewardBATA,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: program.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        treasury: program.programId,
+        treasuryAAccount: program.programId,
+        treasuryBAccount: program.programId,
         user: userStakingAddress,
       })
       .signers([USER_KEYPAIR])
@@ -8,11 +8,14 @@ import {
 } from "@solana/web3.js";
 import { Farming } from "../../target/types/farming";
 import {
+  getGlobalConfigPda,
   getPoolPda,
+  getPoolRegistryPda,
   getRewardAVaultPda,
   getRewardBVaultPda,
   getStakingVaultPda,
   getUserPda,
+  initializeGlobalConfigIfNeeded,
   REWARD_DURATION,
 } from "./utils";
 import assert from "assert";
@@ -63,6 +66,8 @@ describe("dual-farming", () => {
   let funderRewardBATA: anchor.web3.PublicKey = null;
 
   before(async () => {
+    await initializeGlobalConfigIfNeeded(program);
+
     let sig = await program.provider.connection.requestAirdrop(
       ADMIN_KEYPAIR.publicKey,
       100 * LAMPORTS_PER_SOL
@@ -204,7 +209,9 @@ describe("dual-farming", () => {
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
         base: BASE_KEYPAIR.publicKey,
+        globalConfig: (await getGlobalConfigPda(program))[0],
         pool: farmingPoolAddress,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         rewardAMint,
         rewardBMint,
@@ -304,7 +311,9 @@ describe("dual-farming", () => {
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
         base: BASE_KEYPAIR.publicKey,
+        globalConfig: (await getGlobalConfigPda(program))[0],
         pool: farmingPoolAddress,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         rewardAMint,
         rewardBMint,
@@ -527,10 +536,12 @@ describe("dual-farming", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -614,14 +625,22 @@ describe("dual-farming", () => {
     await program.methods
       .claim()
       .accounts({
+        delegate: program.programId,
+        globalConfig: program.programId,
+        nativeVault: program.programId,
         owner: USER_KEYPAIR.publicKey,
         pool: farmingPoolAddress,
+        referrer: program.programId,
         rewardAAccount: userRewardAATA,
         rewardBAccount: userRewardBATA,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: program.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        treasury: program.programId,
+        treasuryAAccount: program.programId,
+        treasuryBAccount: program.programId,
         user: userStakingAddress,
       })
       .signers([USER_KEYPAIR])
@@ -794,7 +813,15 @@ describe("dual-farming", () => {
     await program.methods
       .claim()
       .accounts({
+        delegate: program.programId,
+        globalConfig: program.programId,
+        nativeVault: program.programId,
         owner: maliciousUser.publicKey,
+        referrer: program.programId,
+        systemProgram: program.programId,
+        treasury: program.programId,
+        treasuryAAccount: program.programId,
+        treasuryBAccount: program.programId,
         user: userStakingAddress,
         pool: farmingPoolAddress,
         rewardAAccount: maliciousUserRewardAATA,
@@ -854,10 +881,12 @@ describe("dual-farming", () => {
         fromA: funderRewardAATA,
         fromB: funderRewardBATA,
         funder: FUNDER_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: beforePoolState.rewardAVault,
         rewardBVault: beforePoolState.rewardBVault,
         stakingVault: beforePoolState.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([FUNDER_KEYPAIR])
@@ -905,10 +934,12 @@ describe("dual-farming", () => {
         fromA: funderRewardAATA,
         fromB: funderRewardBATA,
         funder: FUNDER_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([FUNDER_KEYPAIR])
@@ -1049,14 +1080,22 @@ describe("dual-farming", () => {
     await program.methods
       .claim()
       .accounts({
+        delegate: program.programId,
+        globalConfig: program.programId,
+        nativeVault: program.programId,
         owner: USER_KEYPAIR.publicKey,
         pool: farmingPoolAddress,
+        referrer: program.programId,
         rewardAAccount: userRewardAATA,
         rewardBAccount: userRewardBATA,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: program.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        treasury: program.programId,
+        treasuryAAccount: program.programId,
+        treasuryBAccount: program.programId,
         user: userStakingAddress,
       })
       .signers([USER_KEYPAIR])
@@ -1188,10 +1227,12 @@ describe("dual-farming", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: poolState.rewardAVault,
         rewardBVault: poolState.rewardBVault,
         stakingVault: poolState.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -1281,7 +1322,9 @@ describe("dual-farming", () => {
       .closePool()
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         refundee: ADMIN_KEYPAIR.publicKey,
         rewardARefundee: adminRewardAATA,
         rewardBRefundee: adminRewardBATA,
@@ -1289,6 +1332,7 @@ describe("dual-farming", () => {
         rewardBVault: poolAccount.rewardBVault,
         stakingRefundee: adminStakingATA,
         stakingVault: poolAccount.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -1312,7 +1356,9 @@ describe("dual-farming", () => {
       .closePool()
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         refundee: ADMIN_KEYPAIR.publicKey,
         rewardARefundee: adminRewardAATA,
         rewardBRefundee: adminRewardBATA,
@@ -1320,6 +1366,7 @@ describe("dual-farming", () => {
         rewardBVault: poolAccount.rewardBVault,
         stakingRefundee: adminStakingATA,
         stakingVault: poolAccount.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .preInstructions([
@@ -7,10 +7,13 @@ import assert from "assert";
 import { Farming } from "../../target/types/farming";
 import { getClock } from "../clock";
 import {
+  getGlobalConfigPda,
   getPoolPda,
+  getPoolRegistryPda,
   getRewardAVaultPda,
   getRewardBVaultPda,
   getStakingVaultPda,
+  initializeGlobalConfigIfNeeded,
 } from "./utils";
 import { sleep } from "@project-serum/common";
 
@@ -157,12 +160,20 @@ function usersClaim(
       program.methods
         .claim()
         .accounts({
+          delegate: program.programId,
+          globalConfig: program.programId,
+          nativeVault: program.programId,
           owner: u.keypair.publicKey,
           pool,
+          referrer: program.programId,
           rewardAVault,
           rewardBVault,
           stakingVault,
+          systemProgram: program.programId,
           tokenProgram: TOKEN_PROGRAM_ID,
+          treasury: program.programId,
+          treasuryAAccount: program.programId,
+          treasuryBAccount: program.programId,
           user: u.user,
           rewardAAccount: u.rewardAAccount,
           rewardBAccount: u.rewardBAccount,
@@ -208,6 +219,8 @@ describe("staking reward precision", () => {
   let user: PublicKey = null;
 
   before(async () => {
+    await initializeGlobalConfigIfNeeded(program);
+
     await airDrops([ADMIN_KEYPAIR, USER_KEYPAIR]);
 
     stakingToken = await Token.createMint(
@@ -298,7 +311,9 @@ describe("staking reward precision", () => {
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
         base: BASE_KEYPAIR.publicKey,
+        globalConfig: (await getGlobalConfigPda(program))[0],
         pool,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         rewardAMint,
         rewardBMint,
@@ -339,10 +354,12 @@ describe("staking reward precision", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool,
         rewardAVault,
         rewardBVault,
         stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -411,6 +428,8 @@ describe.only("staking reward when no staker", () => {
   let claimedRewardB = new BN(0);
 
   before(async () => {
+    await initializeGlobalConfigIfNeeded(program);
+
     await airDrops([ADMIN_KEYPAIR, USER_KEYPAIR]);
 
     stakingToken = await Token.createMint(
@@ -501,7 +520,9 @@ describe.only("staking reward when no staker", () => {
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
         base: BASE_KEYPAIR.publicKey,
+        globalConfig: (await getGlobalConfigPda(program))[0],
         pool,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         rewardAMint,
         rewardBMint,
@@ -526,10 +547,12 @@ describe.only("staking reward when no staker", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool,
         rewardAVault,
         rewardBVault,
         stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -689,10 +712,12 @@ describe.only("staking reward when no staker", () => {
         fromA: adminRewardAATA,
         fromB: adminRewardBATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool,
         rewardAVault,
         rewardBVault,
         stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -5,11 +5,13 @@ import { LAMPORTS_PER_SOL } from "@solana/web3.js";
 import assert from "assert";
 import { Farming } from "../../target/types/farming";
 import {
+  getGlobalConfigPda,
   getPoolPda,
+  getPoolRegistryPda,
   getRewardAVaultPda,
-  getRewardBVaultPda,
   getStakingVaultPda,
   getUserPda,
+  initializeGlobalConfigIfNeeded,
   REWARD_DURATION,
 } from "./utils";
 
@@ -45,6 +47,8 @@ describe("dual-farming with single reward", () => {
   let adminRewardATA: anchor.web3.PublicKey = null;
 
   before(async () => {
+    await initializeGlobalConfigIfNeeded(program);
+
     let sig = await program.provider.connection.requestAirdrop(
       ADMIN_KEYPAIR.publicKey,
       100 * LAMPORTS_PER_SOL
@@ -117,24 +121,20 @@ describe("dual-farming with single reward", () => {
       rewardMint,
       BASE_KEYPAIR.publicKey
     );
-    const [rewardBVaultAddress, _rewardBVaultBump] = await getRewardBVaultPda(
-      program,
-      stakingMint,
-      rewardMint,
-      rewardMint,
-      BASE_KEYPAIR.publicKey
-    );
     await program.methods
       .initializePool(REWARD_DURATION)
       .accounts({
         authority: ADMIN_KEYPAIR.publicKey,
         base: BASE_KEYPAIR.publicKey,
+        globalConfig: (await getGlobalConfigPda(program))[0],
         pool: farmingPoolAddress,
+        poolRegistry: (await getPoolRegistryPda(program, stakingMint))[0],
         rent: anchor.web3.SYSVAR_RENT_PUBKEY,
         rewardAMint: rewardMint,
         rewardBMint: rewardMint,
         rewardAVault: rewardAVaultAddress,
-        rewardBVault: rewardBVaultAddress,
+        // Single reward pools reuse the reward A vault
+        rewardBVault: program.programId,
         stakingMint,
         stakingVault: stakingVaultAddress,
         systemProgram: anchor.web3.SystemProgram.programId,
@@ -142,6 +142,12 @@ describe("dual-farming with single reward", () => {
       })
       .signers([BASE_KEYPAIR, ADMIN_KEYPAIR])
       .rpc();
+
+    const poolAccount = await program.account.pool.fetch(farmingPoolAddress);
+    assert.deepStrictEqual(
+      poolAccount.rewardBVault.toBase58(),
+      rewardAVaultAddress.toBase58()
+    );
   });
 
   it("should create new user", async () => {
@@ -236,10 +242,12 @@ describe("dual-farming with single reward", () => {
         fromA: adminRewardATA,
         fromB: adminRewardATA,
         funder: ADMIN_KEYPAIR.publicKey,
+        nativeVault: program.programId,
         pool: farmingPoolAddress,
         rewardAVault: poolAccount.rewardAVault,
         rewardBVault: poolAccount.rewardBVault,
         stakingVault: poolAccount.stakingVault,
+        systemProgram: anchor.web3.SystemProgram.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
       })
       .signers([ADMIN_KEYPAIR])
@@ -275,14 +283,22 @@ describe("dual-farming with single reward", () => {
     await program.methods
       .claim()
       .accounts({
+        delegate: program.programId,
+        globalConfig: program.programId,
+        nativeVault: program.programId,
         owner: USER_KEYPAIR.publicKey,
         pool: farmingPoolAddress,
+        referrer: program.programId,
         rewardAAccount: userRewardATA,
         rewardBAccount: userRewardATA,
         rewardAVault: poolAccount.rewardAVault,
         rewardBVault: poolAccount.rewardBVault,
         stakingVault: poolAccount.stakingVault,
+        systemProgram: program.programId,
         tokenProgram: TOKEN_PROGRAM_ID,
+        treasury: program.programId,
+        treasuryAAccount: program.programId,
+        treasuryBAccount: program.programId,
         user: userStakingAddress,
       })
       .signers([USER_KEYPAIR])
@@ -102,3 +102,46 @@ export async function getUserPda(
     program.programId
   );
 }
+
+export async function getGlobalConfigPda(program: anchor.Program<Farming>) {
+  return anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("global_config")],
+    program.programId
+  );
+}
+
+export async function initializeGlobalConfigIfNeeded(
+  program: anchor.Program<Farming>
+) {
+  const [globalConfig, _] = await getGlobalConfigPda(program);
+  const globalConfigAccount =
+    await program.provider.connection.getAccountInfo(globalConfig);
+  if (globalConfigAccount) {
+    return;
+  }
+  const admin = (program.provider as anchor.AnchorProvider).wallet.publicKey;
+  const [programData, __] = await anchor.web3.PublicKey.findProgramAddress(
+    [program.programId.toBuffer()],
+    new anchor.web3.PublicKey("BPFLoaderUpgradeab1e11111111111111111111111")
+  );
+  await program.methods
+    .initializeGlobalConfig(0, admin)
+    .accounts({
+      admin,
+      globalConfig,
+      program: program.programId,
+      programData,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    })
+    .rpc();
+}
+
+export async function getPoolRegistryPda(
+  program: anchor.Program<Farming>,
+  stakingMint: anchor.web3.PublicKey
+) {
+  return anchor.web3.PublicKey.findProgramAddress(
+    [Buffer.from("pool_registry"), stakingMint.toBuffer()],
+    program.programId
+  );
+}
@@ -8,7 +8,8 @@ export type Farming = {
     {
       "name": "initializePool",
       "docs": [
-        "Initializes a new pool. Able to create pool with single reward by passing the same Mint account for reward_a_mint and reward_a_mint"
+        "Initializes a new pool. Able to create pool with single reward by passing the same Mint account for reward_a_mint and reward_b_mint,",
+        "in which case the reward B vault must be omitted and the reward A vault is recorded as reward B vault."
       ],
       "accounts": [
         {
@@ -63,8 +64,9 @@ export type Farming = {
           "name": "rewardBVault",
           "isMut": true,
           "isSigner": false,
+          "isOptional": true,
           "docs": [
-            "Reward B vault PDA"
+            "Reward B vault PDA. Must be omitted when the reward B mint is the reward A mint"
           ]
         },
         {
@@ -83,6 +85,23 @@ export type Farming = {
             "Base"
           ]
         },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global config. The pool charges no protocol fee when omitted"
+          ]
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registry of the pools of the staking mint"
+          ]
+        },
         {
           "name": "systemProgram",
           "isMut": false,
@@ -115,6 +134,182 @@ export type Farming = {
         }
       ]
     },
+    {
+      "name": "registerPool",
+      "docs": [
+        "Adds a pool created before the registry existed to the registry of its staking mint. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registry of the pools of the staking mint"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the registry rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prunePoolRegistry",
+      "docs": [
+        "Removes a pool from the registry of a staking mint. Only the protocol admin is able to do it.",
+        "The pool stays flagged as registered, so it is not able to register again."
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global config"
+          ]
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registry of the pools of the staking mint"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Protocol admin, receiving the rent excess of the registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pool",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "initializeGlobalConfig",
+      "docs": [
+        "Initializes the program global config. Only the program upgrade authority is able to do it."
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global config"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Program upgrade authority, becoming the protocol admin"
+          ]
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Farming program"
+          ]
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Farming program data"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateGlobalConfig",
+      "docs": [
+        "Update the program global config. Fee changes only apply to pools created afterward."
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global config"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Protocol admin"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "publicKey"
+        },
+        {
+          "name": "protocolFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey"
+        }
+      ]
+    },
     {
       "name": "createUser",
       "docs": [
@@ -331,9 +526,9 @@ export type Farming = {
       ]
     },
     {
-      "name": "authorizeFunder",
+      "name": "setRewardDuration",
       "docs": [
-        "Authorize additional funders for the pool"
+        "Set the reward duration used by the next funding. Only allowed between campaigns."
       ],
       "accounts": [
         {
@@ -355,15 +550,16 @@ export type Farming = {
       ],
       "args": [
         {
-          "name": "funderToAdd",
-          "type": "publicKey"
+          "name": "rewardDuration",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "deauthorizeFunder",
+      "name": "setUnbondingPeriod",
       "docs": [
-        "Deauthorize funders for the pool"
+        "Set the cooldown applied to withdrawals. Zero disables unbonding, allowing direct withdraw.",
+        "A longer period only applies to stake deposited afterwards, a shorter one applies to every user."
       ],
       "accounts": [
         {
@@ -385,15 +581,17 @@ export type Farming = {
       ],
       "args": [
         {
-          "name": "funderToRemove",
-          "type": "publicKey"
+          "name": "unbondingPeriod",
+          "type": "u64"
         }
       ]
     },
     {
-      "name": "fund",
+      "name": "setMinStakeDuration",
       "docs": [
-        "Fund the pool with rewards.  This resets the clock on the end date, pushing it out to the set duration. And, linearly redistributes remaining rewards."
+        "Set the time the stake of a user must stay in the pool before its rewards can be claimed.",
+        "Withdrawing earlier forfeits the pending rewards. Zero disables it.",
+        "A longer duration only applies to stake deposited afterwards, a shorter one applies to every user."
       ],
       "accounts": [
         {
@@ -405,51 +603,65 @@ export type Farming = {
           ]
         },
         {
-          "name": "stakingVault",
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minStakeDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "requestWithdraw",
+      "docs": [
+        "User moves staked tokens to the unbonding balance. They stop earning rewards immediately and can be withdrawn once the unbonding period is over."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "Staking vault PDA"
+            "Global accounts for the deposit/withdraw instance."
           ]
         },
         {
-          "name": "rewardAVault",
+          "name": "stakingVault",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "Reward A Vault PDA"
+            "Staking vault PDA."
           ]
         },
         {
-          "name": "rewardBVault",
+          "name": "user",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "Reward B Vault PDA"
+            "User."
           ]
         },
         {
-          "name": "funder",
+          "name": "owner",
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "Funder"
-          ]
-        },
-        {
-          "name": "fromA",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Funder reward A ATA"
+            "Authority of user"
           ]
         },
         {
-          "name": "fromB",
+          "name": "stakeFromAccount",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "Funder reward B ATA"
+            "User staking ATA"
           ]
         },
         {
@@ -463,19 +675,15 @@ export type Farming = {
       ],
       "args": [
         {
-          "name": "amountA",
-          "type": "u64"
-        },
-        {
-          "name": "amountB",
+          "name": "sptAmount",
           "type": "u64"
         }
       ]
     },
     {
-      "name": "claim",
+      "name": "completeWithdraw",
       "docs": [
-        "User claim rewards"
+        "User withdraws the whole unbonding balance after the unbonding period."
       ],
       "accounts": [
         {
@@ -483,7 +691,7 @@ export type Farming = {
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "Global accounts for the staking instance."
+            "Global accounts for the deposit/withdraw instance."
           ]
         },
         {
@@ -494,22 +702,6 @@ export type Farming = {
             "Staking vault PDA."
           ]
         },
-        {
-          "name": "rewardAVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward A Vault PDA"
-          ]
-        },
-        {
-          "name": "rewardBVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward B Vault PDA"
-          ]
-        },
         {
           "name": "user",
           "isMut": true,
@@ -527,57 +719,66 @@ export type Farming = {
           ]
         },
         {
-          "name": "rewardAAccount",
+          "name": "stakeFromAccount",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "User's Reward A ATA"
+            "User staking ATA"
           ]
         },
         {
-          "name": "rewardBAccount",
-          "isMut": true,
+          "name": "tokenProgram",
+          "isMut": false,
           "isSigner": false,
           "docs": [
-            "User's Reward B ATA"
+            "Misc."
           ]
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
         }
       ],
       "args": []
     },
     {
-      "name": "withdrawExtraToken",
+      "name": "authorizeFunder",
       "docs": [
-        "Withdraw token that mistakenly deposited to staking_vault"
+        "Authorize additional funders for the pool"
       ],
       "accounts": [
         {
           "name": "pool",
-          "isMut": false,
+          "isMut": true,
           "isSigner": false,
           "docs": [
             "Global accounts for the staking instance."
           ]
         },
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
           "docs": [
-            "Staking vault PDA"
+            "Authority of the pool"
           ]
-        },
+        }
+      ],
+      "args": [
         {
-          "name": "withdrawToAccount",
+          "name": "funderToAdd",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "deauthorizeFunder",
+      "docs": [
+        "Deauthorize funders for the pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
           "isMut": true,
           "isSigner": false,
           "docs": [
-            "Token account to receive mistakenly deposited token"
+            "Global accounts for the staking instance."
           ]
         },
         {
@@ -585,1448 +786,10126 @@ export type Farming = {
           "isMut": false,
           "isSigner": true,
           "docs": [
-            "Authority of the staking instance"
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Misc."
+            "Authority of the pool"
           ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "funderToRemove",
+          "type": "publicKey"
+        }
+      ]
     },
     {
-      "name": "closeUser",
+      "name": "enableNativeReward",
       "docs": [
-        "Closes a users stake account. Validation is done to ensure this is only allowed when the user has nothing staked and no rewards pending."
+        "Pays the flagged rewards in native SOL held by the native reward vault instead of the reward vaults.",
+        "The reward mint must be the native mint and the pool must not have been funded yet."
       ],
       "accounts": [
         {
           "name": "pool",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
         },
         {
-          "name": "user",
+          "name": "nativeVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Native reward vault PDA"
+          ]
         },
         {
-          "name": "owner",
+          "name": "authority",
           "isMut": true,
-          "isSigner": true
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool, paying the native reward vault rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "rewardA",
+          "type": "bool"
+        },
+        {
+          "name": "rewardB",
+          "type": "bool"
+        }
+      ]
     },
     {
-      "name": "migrateFarmingRate",
+      "name": "fund",
       "docs": [
-        "anyone can call this"
+        "Fund the pool with rewards.  This resets the clock on the end date, pushing it out to the set duration. And, linearly redistributes remaining rewards."
       ],
       "accounts": [
         {
           "name": "pool",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Funder"
+          ]
+        },
+        {
+          "name": "fromA",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward A ATA. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "fromB",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward B ATA. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
         }
       ],
-      "args": []
+      "args": [
+        {
+          "name": "amountA",
+          "type": "u64"
+        },
+        {
+          "name": "amountB",
+          "type": "u64"
+        }
+      ]
     },
     {
-      "name": "closePool",
+      "name": "fundWithMode",
       "docs": [
-        "Closes a pool account. Only able to be done when there are no users staked."
+        "Fund the pool with rewards using the given [FundMode]."
       ],
       "accounts": [
         {
-          "name": "refundee",
+          "name": "pool",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
         },
         {
-          "name": "stakingRefundee",
+          "name": "stakingVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
         },
         {
-          "name": "rewardARefundee",
+          "name": "rewardAVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
         },
         {
-          "name": "rewardBRefundee",
+          "name": "rewardBVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
         },
         {
-          "name": "pool",
+          "name": "funder",
           "isMut": true,
-          "isSigner": false
+          "isSigner": true,
+          "docs": [
+            "Funder"
+          ]
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "fromA",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward A ATA. Required when reward A is a token"
+          ]
         },
         {
-          "name": "stakingVault",
+          "name": "fromB",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward B ATA. Required when reward B is a token"
+          ]
         },
         {
-          "name": "rewardAVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
         },
         {
-          "name": "rewardBVault",
+          "name": "nativeVault",
           "isMut": true,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
         },
         {
-          "name": "tokenProgram",
+          "name": "systemProgram",
           "isMut": false,
-          "isSigner": false
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
         }
       ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "FundMode"
+          }
+        }
+      ]
+    },
     {
-      "name": "pool",
+      "name": "syncRewards",
       "docs": [
-        "Pool account wrapper"
+        "Fund the pool with the rewards held above the reward obligations, such as tokens sent directly to the reward vaults,",
+        "as a [FundMode::Reset] funding of those amounts."
       ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "docs": [
-              "Privileged account."
-            ],
-            "type": "publicKey"
-          },
-          {
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Funder"
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claim",
+      "docs": [
+        "User claim rewards"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B Vault PDA. Required when reward B is a token of a dual reward pool"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner of user. Signs unless the claim delegate does. Receives native rewards"
+          ]
+        },
+        {
+          "name": "rewardAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "User's Reward A ATA. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "rewardBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "User's Reward B ATA. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global config. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward A ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward B ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury wallet. Required when the pool charges a protocol fee on a native reward"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Claim delegate registered by the user. Required when the owner does not sign"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer of the user. Required when the user has one and the pool pays a referral share"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimMany",
+      "docs": [
+        "Claim pending rewards from many pools at once.",
+        "Remaining accounts hold one group of [ClaimReward](/dual_farming/instruction/struct.ClaimReward.html)",
+        "accounts per pool, ordered as for the claim instruction, with the program id for absent optional accounts.",
+        "Every group is claimed by the caller, as the owner or as the claim delegate.",
+        "All or nothing: when the claim of any group fails, the instruction fails and no pool is claimed."
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Owner or claim delegate of every claimed user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferPosition",
+      "docs": [
+        "Transfer the whole staked balance to another owner. Pending rewards are paid to the current owner first."
+      ],
+      "accounts": [
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "New owner of the position"
+          ]
+        },
+        {
+          "name": "recipientUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Recipient user, created if needed."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the recipient user rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "claim",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Global accounts for the staking instance."
+              ]
+            },
+            {
+              "name": "stakingVault",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Staking vault PDA."
+              ]
+            },
+            {
+              "name": "rewardAVault",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Reward A Vault PDA"
+              ]
+            },
+            {
+              "name": "rewardBVault",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Reward B Vault PDA. Required when reward B is a token of a dual reward pool"
+              ]
+            },
+            {
+              "name": "user",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "User."
+              ]
+            },
+            {
+              "name": "owner",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Owner of user. Signs unless the claim delegate does. Receives native rewards"
+              ]
+            },
+            {
+              "name": "rewardAAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "User's Reward A ATA. Required when reward A is a token"
+              ]
+            },
+            {
+              "name": "rewardBAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "User's Reward B ATA. Required when reward B is a token"
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Misc."
+              ]
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Global config. Required when the pool charges a protocol fee"
+              ]
+            },
+            {
+              "name": "treasuryAAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Treasury Reward A ATA. Required when the pool charges a protocol fee"
+              ]
+            },
+            {
+              "name": "treasuryBAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Treasury Reward B ATA. Required when the pool charges a protocol fee"
+              ]
+            },
+            {
+              "name": "nativeVault",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Native reward vault PDA. Required when a reward is native SOL"
+              ]
+            },
+            {
+              "name": "treasury",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Treasury wallet. Required when the pool charges a protocol fee on a native reward"
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "System program. Required when a reward is native SOL"
+              ]
+            },
+            {
+              "name": "delegate",
+              "isMut": false,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Claim delegate registered by the user. Required when the owner does not sign"
+              ]
+            },
+            {
+              "name": "referrer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Referrer of the user. Required when the user has one and the pool pays a referral share"
+              ]
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setCompoundKeeper",
+      "docs": [
+        "Set the keeper allowed to compound rewards on behalf of the user. Pass the default pubkey to revoke."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "keeper",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setClaimDelegate",
+      "docs": [
+        "Set the delegate allowed to claim rewards on behalf of the user and the token accounts rewards are paid to.",
+        "Pass the default pubkey to revoke the delegate or to unset a destination. Native rewards are always paid to the owner."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "rewardADestination",
+          "type": "publicKey"
+        },
+        {
+          "name": "rewardBDestination",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setReferralFee",
+      "docs": [
+        "Set the share of the claimed rewards of referred users accrued to their referrer, in basis points.",
+        "A raised fee only applies to users depositing afterwards, a lowered fee applies to every user."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "referralFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setEmissionSchedule",
+      "docs": [
+        "Set the shape of the emission of the next fundings. Only allowed between campaigns.",
+        "Scheduled pools can only be funded with [FundMode::Reset]."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "schedule",
+          "type": {
+            "defined": "EmissionSchedule"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createReferrer",
+      "docs": [
+        "Create the account accruing the referral rewards of a referrer in a pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Referrer"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet of the referrer"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setReferrer",
+      "docs": [
+        "User records the referrer it was referred by. It can only be set once, the referral outlives the user account."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Referrer of the user in the pool"
+          ]
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Referral of the user, kept when the user is closed"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "restoreReferrer",
+      "docs": [
+        "Restores the referrer recorded by the referral on a recreated user. Permissionless."
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "referral",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Referral of the user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralRewards",
+      "docs": [
+        "Referrer claims the referral rewards accrued in a pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B Vault PDA. Required when reward B is a token of a dual reward pool"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Referrer"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet of the referrer. Receives native rewards"
+          ]
+        },
+        {
+          "name": "rewardAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer's Reward A account. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "rewardBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer's Reward B account. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setBoostConfig",
+      "docs": [
+        "Configure the boost given to holders of a mint or of a verified collection. A zero multiplier disables it."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "boostMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "boostCollection",
+          "type": "publicKey"
+        },
+        {
+          "name": "boostMultiplierBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "refreshBoost",
+      "docs": [
+        "User proves it holds the boost mint or a token of the boost collection and gets the pool boost on its stake."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "boostTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Token account of the owner holding the boost mint or a token of the boost collection"
+          ]
+        },
+        {
+          "name": "boostMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Metadata of the boost token mint. Required for collection boosts"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "stripBoost",
+      "docs": [
+        "Remove the boost of a user who no longer holds a token of the current boost mint or collection,",
+        "or when the pool boost is disabled. Re-syncs the boost of a holder to a changed pool multiplier. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "boostTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Token account recorded when the boost was refreshed"
+          ]
+        },
+        {
+          "name": "boostMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Metadata of the boost token mint. Required for collection boosts while the token account exists"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createStakeCheckpoint",
+      "docs": [
+        "Record the current stake seconds of the user and the pool in a checkpoint owned by the authority.",
+        "The checkpoint is the start of a window read by [snapshot_stake]."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "checkpoint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake checkpoint"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the checkpoint, usually a PDA of the program reading the snapshots"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the checkpoint rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "checkpointId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeStakeCheckpoint",
+      "docs": [
+        "Closes a stake checkpoint. Only the authority of the checkpoint is able to do it."
+      ],
+      "accounts": [
+        {
+          "name": "checkpoint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake checkpoint"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the checkpoint"
+          ]
+        },
+        {
+          "name": "refundee",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Rent receiver"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "snapshotStake",
+      "docs": [
+        "Bring the stake seconds of the user and the pool up to date and return them, with the",
+        "average balances since a stake checkpoint of the user. Other programs can read the result by CPI."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "checkpoint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Stake checkpoint starting the window. Without it the averages are the current balances"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "StakeSnapshot"
+      }
+    },
+    {
+      "name": "createRegistrar",
+      "docs": [
+        "Bind an spl-governance realm and governing token mint to the pool whose stake counts as voting power.",
+        "Only the realm authority is able to do it."
+      ],
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Voter weight registrar of the realm and governing token mint"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-governance realm"
+          ]
+        },
+        {
+          "name": "governanceProgramId",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-governance program owning the realm"
+          ]
+        },
+        {
+          "name": "governingTokenMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Community or council mint of the realm"
+          ]
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the realm"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Pool whose stake counts as voting power"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the registrar account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voteLockDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createVoterWeightRecord",
+      "docs": [
+        "Create the voter weight record of a governing token owner"
+      ],
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Voter weight registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Voter weight record of the governing token owner"
+          ]
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Owner of the stake"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the voter weight record account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateVoterWeightRecord",
+      "docs": [
+        "Set the voter weight of the owner to its staked balance in the registrar pool. The weight",
+        "expires after the current slot, so it must be updated in the same transaction as the",
+        "governance instruction using it. The stake is locked for the vote lock duration of the",
+        "registrar, so the same tokens cannot be moved to another voter while the vote is running."
+      ],
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Voter weight registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Voter weight record of the governing token owner"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User stake account of the governing token owner in the registrar pool"
+          ]
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Governing token owner, whose stake gets locked"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "compound",
+      "docs": [
+        "Restake pending rewards which have the same mint as the staking mint. Callable by the user owner or its keeper."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Owner of user or its compound keeper"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global config. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward A ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward B ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer of the user. Required when the user has one and the pool pays a referral share"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawExtraToken",
+      "docs": [
+        "Withdraw token that mistakenly deposited to staking_vault"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "withdrawToAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Token account to receive mistakenly deposited token"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the staking instance"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawExtraReward",
+      "docs": [
+        "Withdraw reward tokens above the outstanding reward obligations, such as tokens sent directly to the reward vaults",
+        "or left over after campaigns"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "withdrawAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive the reward A vault surplus. Required when there is one"
+          ]
+        },
+        {
+          "name": "withdrawBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive the reward B vault surplus. Required when there is one"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the staking instance. Receives the native reward vault surplus"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "endCampaign",
+      "docs": [
+        "End the running campaign now and refund the rewards not yet emitted. Rewards already accrued by users are kept in the vaults."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "refundAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive unemitted reward A. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "refundBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive unemitted reward B. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the staking instance. Receives unemitted native rewards"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeUser",
+      "docs": [
+        "Closes a users stake account. Validation is done to ensure this is only allowed when the user has nothing staked and no rewards pending."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeDormantUser",
+      "docs": [
+        "Closes an abandoned user account with nothing staked and no rewards pending once the campaign is finished,",
+        "so that it does not block closing the pool. Rent goes back to the user owner. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner of the user, receiving the rent"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateFarmingRate",
+      "docs": [
+        "anyone can call this"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUser",
+      "docs": [
+        "Grow a user account created with a smaller size to the current user size. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User to migrate"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the additional rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "docs": [
+        "Grow a pool account created with a smaller size to the current pool size. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool to migrate"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the additional rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePool",
+      "docs": [
+        "Closes a pool account. Only able to be done when there are no users staked."
+      ],
+      "accounts": [
+        {
+          "name": "refundee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakingRefundee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardARefundee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBRefundee",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive the reward B vault balance. Required when the pool has a reward B vault"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B vault. Required when the pool has one separate from the reward A vault"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Registry of the pools of the staking mint. Required when the pool is registered"
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeStakeHub",
+      "docs": [
+        "Create a stake hub holding the staked tokens once for every reward pool attached to it."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "stakingMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Staking mint"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "base",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Base"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "SPL Token program"
+          ]
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Rent"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "attachPool",
+      "docs": [
+        "Attach a reward pool to a stake hub. The pool then distributes rewards on the hub balances",
+        "of the users who joined it instead of its own staking vault. Only allowed while the pool holds no stake."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool to attach"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Staking vault of the pool"
+          ]
+        },
+        {
+          "name": "poolAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createHubUser",
+      "docs": [
+        "User enables staking in a stake hub"
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of hub user account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "joinHubPool",
+      "docs": [
+        "User starts earning the rewards of a pool attached to the stake hub with its hub balance.",
+        "The user account of the pool must exist."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool attached to the stake hub"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User of the pool"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "leaveHubPool",
+      "docs": [
+        "User stops earning the rewards of a pool attached to the stake hub, such as after its campaign ended."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool attached to the stake hub"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User of the pool"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeHubPoolMember",
+      "docs": [
+        "Stake hub authority removes a user from a pool whose campaign ended, so that the pool can be detached.",
+        "The rewards earned by the user stay claimable."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user to remove"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool attached to the stake hub"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User of the pool"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "detachPool",
+      "docs": [
+        "Detach a pool from its stake hub, freeing its slot. The pool must be closed,",
+        "or have its campaign ended and no hub balance mirrored anymore.",
+        "An open pool can then take deposits of its own again."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool to detach"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "hubDeposit",
+      "docs": [
+        "User deposits tokens in the stake hub.",
+        "Remaining accounts hold the pool and user accounts of every joined pool, in slot order."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The user token account to deposit from or withdraw to"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "hubWithdraw",
+      "docs": [
+        "User withdraws tokens from the stake hub.",
+        "Remaining accounts hold the pool and user accounts of every joined pool, in slot order."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The user token account to deposit from or withdraw to"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "pool",
+      "docs": [
+        "Pool account wrapper"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Privileged account."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "paused",
+            "docs": [
+              "Paused state of the program"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "stakingMint",
+            "docs": [
+              "Mint of the token that can be staked."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingVault",
+            "docs": [
+              "Vault to store staked tokens."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAMint",
+            "docs": [
+              "Mint of the reward A token."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAVault",
+            "docs": [
+              "Vault to store reward A tokens."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardBMint",
+            "docs": [
+              "Mint of the reward B token."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardBVault",
+            "docs": [
+              "Vault to store reward B tokens."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseKey",
+            "docs": [
+              "Base key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardDuration",
+            "docs": [
+              "The period which rewards are linearly distributed by the next funding."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardDurationEnd",
+            "docs": [
+              "The timestamp at which the current reward period ends."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdateTime",
+            "docs": [
+              "The last time reward states were updated."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardARate",
+            "docs": [
+              "deprecated field"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBRate",
+            "docs": [
+              "deprecated field"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardAPerTokenStored",
+            "docs": [
+              "Last calculated reward A per pool token."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBPerTokenStored",
+            "docs": [
+              "Last calculated reward B per pool token."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "userStakeCount",
+            "docs": [
+              "Users staked"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "funders",
+            "docs": [
+              "authorized funders",
+              "[] because short size, fixed account size, and ease of use on",
+              "client due to auto generated account size property"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "rewardARateU128",
+            "docs": [
+              "reward_a_rate in u128 form"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBRateU128",
+            "docs": [
+              "reward_b_rate in u128 form"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "poolBump",
+            "docs": [
+              "Pool bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalStaked",
+            "docs": [
+              "Total staked amount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriod",
+            "docs": [
+              "Cooldown before unbonding tokens can be withdrawn. Zero allows direct withdraw."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalUnbonding",
+            "docs": [
+              "Total amount waiting for the unbonding period to end"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "seedRewardDuration",
+            "docs": [
+              "Reward duration used in the pool PDA seeds. Zero for pools created before it was stored."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "protocolFeeBps",
+            "docs": [
+              "Protocol fee on claimed rewards in basis points, snapshotted from the global config at creation"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "registered",
+            "docs": [
+              "Whether the pool is listed in the registry of its staking mint"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "precision",
+            "docs": [
+              "Scale of reward rates and reward per token, from the mint decimals. Zero for pools created before it was stored."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardAPerTokenRemainder",
+            "docs": [
+              "Emitted reward A, scaled by precision, not yet assigned to reward A per token"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBPerTokenRemainder",
+            "docs": [
+              "Emitted reward B, scaled by precision, not yet assigned to reward B per token"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardARateRemainder",
+            "docs": [
+              "Funded reward A, scaled by precision, not covered by the reward A rate. Carried into the next funding."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBRateRemainder",
+            "docs": [
+              "Funded reward B, scaled by precision, not covered by the reward B rate. Carried into the next funding."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "boostMint",
+            "docs": [
+              "Mint whose holders get the boost. Default pubkey when unused."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "boostCollection",
+            "docs": [
+              "Verified collection whose holders get the boost. Default pubkey when unused."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "boostMultiplierBps",
+            "docs": [
+              "Multiplier of the stake of boosted users in basis points. Zero disables the boost."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalBoostStake",
+            "docs": [
+              "Extra stake of boosted users, counted in rewards but not in the staking vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakeSeconds",
+            "docs": [
+              "Cumulative total staked multiplied by the seconds it was staked"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "stakeSecondsUpdatedAt",
+            "docs": [
+              "The last time stake seconds were updated. Zero before the first update."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardANative",
+            "docs": [
+              "Whether reward A is paid in native SOL from the native reward vault"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rewardBNative",
+            "docs": [
+              "Whether reward B is paid in native SOL from the native reward vault"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "nativeVaultBump",
+            "docs": [
+              "Native reward vault bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rewardAObligation",
+            "docs": [
+              "Reward A owed by the pool, funded and neither refunded, claimed nor emitted while nothing was staked.",
+              "Reward A above it is surplus."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBObligation",
+            "docs": [
+              "Reward B owed by the pool, funded and neither refunded, claimed nor emitted while nothing was staked.",
+              "Reward B above it is surplus."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardObligationsTracked",
+            "docs": [
+              "Whether reward obligations are tracked. False for pools created before they were tracked."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rewardMode",
+            "docs": [
+              "Whether the pool distributes one or two rewards. Unknown for pools created before it was stored."
+            ],
+            "type": {
+              "defined": "RewardMode"
+            }
+          },
+          {
+            "name": "stakeHub",
+            "docs": [
+              "Stake hub holding the stake the pool distributes rewards on. Default pubkey when the pool holds its own stake."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "minStakeDuration",
+            "docs": [
+              "Seconds the stake of a user must stay in the pool before its rewards can be claimed. Zero disables it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referralFeeBps",
+            "docs": [
+              "Share of the claimed rewards of referred users accrued to their referrer, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "emissionCurve",
+            "docs": [
+              "Shape of the emission over the reward period. Flat for pools created before it was stored."
+            ],
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "decayStartWeight",
+            "docs": [
+              "Relative emission rate at the start of the reward period of a linear decay"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "decayEndWeight",
+            "docs": [
+              "Relative emission rate at the end of the reward period of a linear decay"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "emissionSteps",
+            "docs": [
+              "Relative emission rates of a stepwise schedule. Unused steps are zeroed."
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "EmissionStep"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "rewardPeriodStart",
+            "docs": [
+              "The timestamp at which the current reward period started. Zero for periods started before it was stored."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardAScheduled",
+            "docs": [
+              "Reward A, scaled by precision, emitted by the emission schedule over the current reward period"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBScheduled",
+            "docs": [
+              "Reward B, scaled by precision, emitted by the emission schedule over the current reward period"
+            ],
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalConfig",
+      "docs": [
+        "Program-wide configuration"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Protocol admin"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeeBps",
+            "docs": [
+              "Protocol fee on claimed rewards in basis points, applied to pools created afterward"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Owner of the token accounts receiving the protocol fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Global config bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolRegistry",
+      "docs": [
+        "Pools sharing the same staking mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakingMint",
+            "docs": [
+              "Staking mint of the pools"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Pool registry bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pools",
+            "docs": [
+              "Pools staking the mint"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeHub",
+      "docs": [
+        "Staked tokens shared by the reward pools attached to it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Authority allowed to attach and detach pools"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingMint",
+            "docs": [
+              "Mint of the staked tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingVault",
+            "docs": [
+              "Vault holding the staked tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseKey",
+            "docs": [
+              "Base used to derive the stake hub address"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Stake hub bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalStaked",
+            "docs": [
+              "Total staked in the hub"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pools",
+            "docs": [
+              "Attached pools. Default pubkey for free slots."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeCheckpoint",
+      "docs": [
+        "Stake seconds of a user and its pool recorded on chain, starting a stake snapshot window"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "docs": [
+              "User the checkpoint belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority of the checkpoint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "checkpointId",
+            "docs": [
+              "Checkpoint id, chosen by the authority"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Time of the checkpoint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakeSeconds",
+            "docs": [
+              "Cumulative stake seconds of the user"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "totalStakeSeconds",
+            "docs": [
+              "Cumulative stake seconds of the pool"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Stake checkpoint bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "hubUser",
+      "docs": [
+        "Stake of a user in a stake hub"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakeHub",
+            "docs": [
+              "Stake hub the user belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "The owner of this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balanceStaked",
+            "docs": [
+              "The amount staked in the hub"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "joinedPools",
+            "docs": [
+              "Pool the user earns the rewards of in each hub slot. Default pubkey when none."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Hub user bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "docs": [
+        "Referral rewards accrued by a referrer in a pool"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the referrer belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Wallet of the referrer"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAAccrued",
+            "docs": [
+              "Reward A accrued and not claimed yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBAccrued",
+            "docs": [
+              "Reward B accrued and not claimed yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Referrer bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referral",
+      "docs": [
+        "Referrer recorded by a user in a pool. Not closed with the user, so the referrer survives its recreation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the user belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "Wallet of the user"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Wallet of the referrer"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Referral bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "user",
+      "docs": [
+        "Farming user account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the this user belongs to."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "The owner of this account."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAPerTokenComplete",
+            "docs": [
+              "The amount of token A claimed."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBPerTokenComplete",
+            "docs": [
+              "The amount of token B claimed."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardAPerTokenPending",
+            "docs": [
+              "The amount of token A pending claim."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBPerTokenPending",
+            "docs": [
+              "The amount of token B pending claim."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "balanceStaked",
+            "docs": [
+              "The amount staked."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Signer nonce."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "compoundKeeper",
+            "docs": [
+              "Keeper allowed to compound rewards on behalf of the owner."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balanceUnbonding",
+            "docs": [
+              "The amount waiting for the unbonding period to end."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingEnd",
+            "docs": [
+              "The timestamp at which the unbonding balance can be withdrawn."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardAEarnedRemainder",
+            "docs": [
+              "Earned reward A, scaled by precision, below one token unit. Carried into the next update."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBEarnedRemainder",
+            "docs": [
+              "Earned reward B, scaled by precision, below one token unit. Carried into the next update."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "boostMultiplierBps",
+            "docs": [
+              "Boost multiplier of the user in basis points, snapshotted from the pool at refresh. Zero when not boosted."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "boostStake",
+            "docs": [
+              "Extra stake given by the boost."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "boostTokenAccount",
+            "docs": [
+              "Token account proving the boost holding."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakeSeconds",
+            "docs": [
+              "Cumulative balance staked multiplied by the seconds it was staked."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "stakeSecondsUpdatedAt",
+            "docs": [
+              "The last time stake seconds were updated. Zero before the first update."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "claimDelegate",
+            "docs": [
+              "Delegate allowed to claim rewards on behalf of the owner."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardADestination",
+            "docs": [
+              "Token account reward A must be paid to. Default pubkey when unset."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardBDestination",
+            "docs": [
+              "Token account reward B must be paid to. Default pubkey when unset."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "lastDepositAt",
+            "docs": [
+              "The last time the user added stake. Zero before the first deposit."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referrer",
+            "docs": [
+              "Wallet of the referrer of the user. Default pubkey when unset."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteLockedUntil",
+            "docs": [
+              "The stake cannot be withdrawn or transferred before this time, since it was used as voter weight."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriod",
+            "docs": [
+              "Unbonding period of the pool at the last deposit of the user."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minStakeDuration",
+            "docs": [
+              "Minimum stake duration of the pool at the last deposit of the user."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referralFeeBps",
+            "docs": [
+              "Referral fee of the pool at the last deposit of the user, in basis points."
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "registrar",
+      "docs": [
+        "Binding of an spl-governance realm and governing token mint to the pool whose stake is voting power"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "governanceProgramId",
+            "docs": [
+              "spl-governance program owning the realm"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "realm",
+            "docs": [
+              "Realm using the farm stake as voter weight"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Community or council mint of the realm"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "pool",
+            "docs": [
+              "Pool whose stake counts as voting power"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteLockDuration",
+            "docs": [
+              "Time the stake of a voter stays locked after its voter weight is updated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Registrar bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterWeightRecord",
+      "docs": [
+        "spl-governance voter weight record. The layout and discriminator match the spl-governance addin api."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "realm",
+            "docs": [
+              "Realm the record belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Governing token mint the record belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenOwner",
+            "docs": [
+              "Owner of the voting power"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voterWeight",
+            "docs": [
+              "Voter weight"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "voterWeightExpiry",
+            "docs": [
+              "Slot after which the voter weight is stale"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "weightAction",
+            "docs": [
+              "Governance action the voter weight is valid for. Any action when none."
+            ],
+            "type": {
+              "option": {
+                "defined": "VoterWeightAction"
+              }
+            }
+          },
+          {
+            "name": "weightActionTarget",
+            "docs": [
+              "Target of the governance action. Any target when none."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved space"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "StakeSnapshot",
+      "docs": [
+        "Stake seconds of a user and its pool at a point in time"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
+            "docs": [
+              "Time of the snapshot"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakeSeconds",
+            "docs": [
+              "Cumulative stake seconds of the user"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "totalStakeSeconds",
+            "docs": [
+              "Cumulative stake seconds of the pool"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "averageBalance",
+            "docs": [
+              "Average balance staked by the user since the checkpoint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "averageTotalStaked",
+            "docs": [
+              "Average total staked in the pool since the checkpoint"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionStep",
+      "docs": [
+        "Step of a stepwise emission schedule"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startOffset",
+            "docs": [
+              "Seconds from the start of the reward period at which the step starts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Emission rate of the step, relative to the other steps"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
+    },
+    {
+      "name": "VoterWeightAction",
+      "docs": [
+        "spl-governance action a voter weight is used for"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CastVote"
+          },
+          {
+            "name": "CommentProposal"
+          },
+          {
+            "name": "CreateGovernance"
+          },
+          {
+            "name": "CreateProposal"
+          },
+          {
+            "name": "SignOffProposal"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RewardMode",
+      "docs": [
+        "Number of rewards distributed by a pool"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unknown"
+          },
+          {
+            "name": "Dual"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionCurve",
+      "docs": [
+        "Shape of the emission over the reward period"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Flat"
+          },
+          {
+            "name": "LinearDecay"
+          },
+          {
+            "name": "Stepwise"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionSchedule",
+      "docs": [
+        "Emission schedule of the fundings of a pool. Weights are relative rates, the funded amount is spread over the reward period in their proportions."
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Flat"
+          },
+          {
+            "name": "LinearDecay",
+            "fields": [
+              {
+                "name": "start_weight",
+                "docs": [
+                  "Relative rate at the start of the reward period"
+                ],
+                "type": "u16"
+              },
+              {
+                "name": "end_weight",
+                "docs": [
+                  "Relative rate at the end of the reward period"
+                ],
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "Stepwise",
+            "fields": [
+              {
+                "name": "steps",
+                "docs": [
+                  "Steps in increasing start offsets, the first one starting at offset zero"
+                ],
+                "type": {
+                  "vec": {
+                    "defined": "EmissionStep"
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
+    },
+    {
+      "name": "FundMode",
+      "docs": [
+        "How funding affects the reward rate and the end of the reward period"
+      ],
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Reset",
+            "fields": [
+              {
+                "name": "amount_a",
+                "docs": [
+                  "Amount of reward A to fund"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "amount_b",
+                "docs": [
+                  "Amount of reward B to fund"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "TopUp",
+            "fields": [
+              {
+                "name": "amount_a",
+                "docs": [
+                  "Amount of reward A to fund"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "amount_b",
+                "docs": [
+                  "Amount of reward B to fund"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Extend",
+            "fields": [
+              {
+                "name": "seconds",
+                "docs": [
+                  "Seconds added to the reward period"
+                ],
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "EventDeposit",
+      "fields": [
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventWithdraw",
+      "fields": [
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventRequestWithdraw",
+      "fields": [
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "unbondingEnd",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCompleteWithdraw",
+      "fields": [
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventFund",
+      "fields": [
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "undistributableA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "undistributableB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventEndCampaign",
+      "fields": [
+        {
+          "name": "refundA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "refundB",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "endTime",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetRewardDuration",
+      "fields": [
+        {
+          "name": "rewardDuration",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventClaim",
+      "fields": [
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventClaimManyResult",
+      "fields": [
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventAttachPool",
+      "fields": [
+        {
+          "name": "stakeHub",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventDetachPool",
+      "fields": [
+        {
+          "name": "stakeHub",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventJoinHubPool",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventLeaveHubPool",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventHubDeposit",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventHubWithdraw",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventForfeitRewards",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetReferralFee",
+      "fields": [
+        {
+          "name": "referralFeeBps",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetEmissionSchedule",
+      "fields": [
+        {
+          "name": "schedule",
+          "type": {
+            "defined": "EmissionSchedule"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetReferrer",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventReferralAccrued",
+      "fields": [
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventClaimReferralRewards",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCompound",
+      "fields": [
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventPositionSent",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventPositionReceived",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetCompoundKeeper",
+      "fields": [
+        {
+          "name": "keeper",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetClaimDelegate",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardADestination",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "rewardBDestination",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetBoostConfig",
+      "fields": [
+        {
+          "name": "boostMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "boostCollection",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "boostMultiplierBps",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventWithdrawExtraReward",
+      "fields": [
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountNative",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCloseDormantUser",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventEnableNativeReward",
+      "fields": [
+        {
+          "name": "rewardA",
+          "type": "bool",
+          "index": false
+        },
+        {
+          "name": "rewardB",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventRefreshBoost",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "boostMultiplierBps",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "boostStake",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventStripBoost",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCreateStakeCheckpoint",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "checkpointId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSnapshotStake",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "snapshot",
+          "type": {
+            "defined": "StakeSnapshot"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCreateRegistrar",
+      "fields": [
+        {
+          "name": "realm",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteLockDuration",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventUpdateVoterWeight",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voterWeight",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "voteLockedUntil",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventProtocolFee",
+      "fields": [
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventUpdateGlobalConfig",
+      "fields": [
+        {
+          "name": "admin",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "protocolFeeBps",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventPrunePoolRegistry",
+      "fields": [
+        {
+          "name": "stakingMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventAuthorizeFunder",
+      "fields": [
+        {
+          "name": "newFunder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventUnauthorizeFunder",
+      "fields": [
+        {
+          "name": "funder",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    }
+  ],
+  "errors": [
+    {
+      "code": 6000,
+      "name": "InsufficientFundWithdraw",
+      "msg": "Insufficient funds to withdraw."
+    },
+    {
+      "code": 6001,
+      "name": "AmountMustBeGreaterThanZero",
+      "msg": "Amount must be greater than zero."
+    },
+    {
+      "code": 6002,
+      "name": "SingleDepositTokenBCannotBeFunded",
+      "msg": "Reward B cannot be funded - pool is single deposit."
+    },
+    {
+      "code": 6003,
+      "name": "PoolPaused",
+      "msg": "Pool is paused."
+    },
+    {
+      "code": 6004,
+      "name": "DurationTooShort",
+      "msg": "Duration cannot be shorter than one day."
+    },
+    {
+      "code": 6005,
+      "name": "FunderAlreadyAuthorized",
+      "msg": "Provided funder is already authorized to fund."
+    },
+    {
+      "code": 6006,
+      "name": "MaxFunders",
+      "msg": "Maximum funders already authorized."
+    },
+    {
+      "code": 6007,
+      "name": "CannotDeauthorizePoolAuthority",
+      "msg": "Cannot deauthorize the primary pool authority."
+    },
+    {
+      "code": 6008,
+      "name": "CannotDeauthorizeMissingAuthority",
+      "msg": "Authority not found for deauthorization."
+    },
+    {
+      "code": 6009,
+      "name": "MathOverflow",
+      "msg": "Math operation overflow"
+    },
+    {
+      "code": 6010,
+      "name": "NoCompoundableReward",
+      "msg": "Pool has no reward with the same mint as the staking mint."
+    },
+    {
+      "code": 6011,
+      "name": "InvalidCompoundAuthority",
+      "msg": "Signer is neither the user owner nor its compound keeper."
+    },
+    {
+      "code": 6012,
+      "name": "UnbondingRequired",
+      "msg": "Pool has an unbonding period, withdraw must be requested first."
+    },
+    {
+      "code": 6013,
+      "name": "UnbondingNotFinished",
+      "msg": "Unbonding period is not finished."
+    },
+    {
+      "code": 6014,
+      "name": "CampaignNotActive",
+      "msg": "Campaign is not running."
+    },
+    {
+      "code": 6015,
+      "name": "CampaignActive",
+      "msg": "Campaign is still running."
+    },
+    {
+      "code": 6016,
+      "name": "InvalidProtocolFee",
+      "msg": "Protocol fee exceeds the maximum."
+    },
+    {
+      "code": 6017,
+      "name": "InvalidProtocolAdmin",
+      "msg": "Signer is not the protocol admin."
+    },
+    {
+      "code": 6018,
+      "name": "MissingTreasuryAccount",
+      "msg": "Treasury account is required to pay the protocol fee."
+    },
+    {
+      "code": 6019,
+      "name": "InvalidTreasuryAccount",
+      "msg": "Treasury account does not belong to the treasury or has the wrong mint."
+    },
+    {
+      "code": 6020,
+      "name": "InvalidPoolAccount",
+      "msg": "Account is not a pool."
+    },
+    {
+      "code": 6021,
+      "name": "MissingPoolRegistry",
+      "msg": "Pool registry is required to close a registered pool."
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPositionRecipient",
+      "msg": "Position cannot be transferred to its current owner."
+    },
+    {
+      "code": 6023,
+      "name": "FundingTooSmall",
+      "msg": "Funding is too small to emit any reward over the reward period."
+    },
+    {
+      "code": 6024,
+      "name": "InvalidUserAccount",
+      "msg": "Account is not a user account."
+    },
+    {
+      "code": 6025,
+      "name": "InvalidBoostConfig",
+      "msg": "Boost multiplier must be above 1x and at most the maximum, with a mint or a collection."
+    },
+    {
+      "code": 6026,
+      "name": "BoostNotConfigured",
+      "msg": "Pool has no boost configured."
+    },
+    {
+      "code": 6027,
+      "name": "InvalidBoostToken",
+      "msg": "Token account does not hold the boost mint or a token of the boost collection."
+    },
+    {
+      "code": 6028,
+      "name": "BoostNotActive",
+      "msg": "User has no boost."
+    },
+    {
+      "code": 6029,
+      "name": "BoostStillHeld",
+      "msg": "Boost token is still held."
+    },
+    {
+      "code": 6030,
+      "name": "InvalidStakeWindow",
+      "msg": "Stake checkpoint is not an earlier checkpoint of this user."
+    },
+    {
+      "code": 6031,
+      "name": "InvalidRealm",
+      "msg": "Account is not a realm of the governance program or does not use the governing token mint."
+    },
+    {
+      "code": 6032,
+      "name": "InvalidRealmAuthority",
+      "msg": "Signer is not the realm authority."
+    },
+    {
+      "code": 6033,
+      "name": "InvalidVoterUser",
+      "msg": "User account does not belong to the registrar pool and the voter."
+    },
+    {
+      "code": 6034,
+      "name": "InvalidNativeReward",
+      "msg": "Native reward requires the native mint and a pool not funded yet."
+    },
+    {
+      "code": 6035,
+      "name": "MissingNativeRewardAccounts",
+      "msg": "Native reward vault and system program are required for a native reward."
+    },
+    {
+      "code": 6036,
+      "name": "NativeRewardAccountNotWritable",
+      "msg": "Account sending or receiving a native reward must be writable."
+    },
+    {
+      "code": 6037,
+      "name": "MissingRewardTokenAccount",
+      "msg": "Token account is required for a token reward."
+    },
+    {
+      "code": 6038,
+      "name": "RewardObligationsUntracked",
+      "msg": "Pool was created before reward obligations were tracked."
+    },
+    {
+      "code": 6039,
+      "name": "NoRewardSurplus",
+      "msg": "Reward vaults hold nothing above the reward obligations."
+    },
+    {
+      "code": 6040,
+      "name": "MissingRewardVault",
+      "msg": "Reward B vault is required for a dual reward pool."
+    },
+    {
+      "code": 6041,
+      "name": "InvalidClaimAuthority",
+      "msg": "Signer is neither the user owner nor its claim delegate."
+    },
+    {
+      "code": 6042,
+      "name": "InvalidRewardDestination",
+      "msg": "Reward destination is not the one registered by the user."
+    },
+    {
+      "code": 6043,
+      "name": "InvalidClaimManyAccounts",
+      "msg": "Claim many accounts are empty or hold too many pools."
+    },
+    {
+      "code": 6044,
+      "name": "PoolAttachedToHub",
+      "msg": "Pool stake is held by a stake hub."
+    },
+    {
+      "code": 6045,
+      "name": "PoolNotAttachedToHub",
+      "msg": "Pool is not attached to the stake hub."
+    },
+    {
+      "code": 6046,
+      "name": "PoolHasStake",
+      "msg": "Pool still holds stake."
+    },
+    {
+      "code": 6047,
+      "name": "StakeHubFull",
+      "msg": "Stake hub has no free pool slot."
+    },
+    {
+      "code": 6048,
+      "name": "InvalidStakeHubMint",
+      "msg": "Pool staking mint differs from the stake hub staking mint."
+    },
+    {
+      "code": 6049,
+      "name": "HubPoolAlreadyJoined",
+      "msg": "User already earns from the hub pool."
+    },
+    {
+      "code": 6050,
+      "name": "HubPoolNotJoined",
+      "msg": "User does not earn from the hub pool."
+    },
+    {
+      "code": 6051,
+      "name": "InvalidHubPoolAccounts",
+      "msg": "Hub pool accounts do not match the pools joined by the user."
+    },
+    {
+      "code": 6052,
+      "name": "MinStakeDurationNotReached",
+      "msg": "Stake has not been in the pool for the minimum stake duration."
+    },
+    {
+      "code": 6053,
+      "name": "InvalidReferralFee",
+      "msg": "Referral fee is above the maximum."
+    },
+    {
+      "code": 6054,
+      "name": "ReferrerAlreadySet",
+      "msg": "Referrer is already set."
+    },
+    {
+      "code": 6055,
+      "name": "InvalidReferrer",
+      "msg": "User cannot refer itself."
+    },
+    {
+      "code": 6056,
+      "name": "MissingReferrerAccount",
+      "msg": "Referrer account is required to claim."
+    },
+    {
+      "code": 6057,
+      "name": "InvalidEmissionSchedule",
+      "msg": "Emission schedule is invalid."
+    },
+    {
+      "code": 6058,
+      "name": "EmissionScheduleRequiresReset",
+      "msg": "Pools with an emission schedule can only be funded over a new reward period."
+    },
+    {
+      "code": 6059,
+      "name": "PoolRegistryFull",
+      "msg": "Pool registry is full."
+    },
+    {
+      "code": 6060,
+      "name": "PoolAlreadyRegistered",
+      "msg": "Pool is already registered."
+    },
+    {
+      "code": 6061,
+      "name": "PoolNotInRegistry",
+      "msg": "Pool is not in the registry."
+    },
+    {
+      "code": 6062,
+      "name": "StakeVoteLocked",
+      "msg": "Stake is locked until the vote it was used for ends."
+    },
+    {
+      "code": 6063,
+      "name": "MissingBoostMetadata",
+      "msg": "Metadata of the boost token mint is required to check a collection boost."
+    },
+    {
+      "code": 6064,
+      "name": "UnbondingPeriodTooLong",
+      "msg": "Unbonding period is longer than the maximum."
+    },
+    {
+      "code": 6065,
+      "name": "MinStakeDurationTooLong",
+      "msg": "Minimum stake duration is longer than the maximum."
+    },
+    {
+      "code": 6066,
+      "name": "UnexpectedRewardVault",
+      "msg": "Reward B vault must not be passed for a single reward pool."
+    }
+  ]
+};
+
+export const IDL: Farming = {
+  "version": "0.2.2",
+  "name": "farming",
+  "docs": [
+    "Dual farming program"
+  ],
+  "instructions": [
+    {
+      "name": "initializePool",
+      "docs": [
+        "Initializes a new pool. Able to create pool with single reward by passing the same Mint account for reward_a_mint and reward_b_mint,",
+        "in which case the reward B vault must be omitted and the reward A vault is recorded as reward B vault."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Staking mint"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "rewardAMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reward A mint"
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reward B mint"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B vault PDA. Must be omitted when the reward B mint is the reward A mint"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        },
+        {
+          "name": "base",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Base"
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global config. The pool charges no protocol fee when omitted"
+          ]
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registry of the pools of the staking mint"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "SPL Token program"
+          ]
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Rent"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "rewardDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "registerPool",
+      "docs": [
+        "Adds a pool created before the registry existed to the registry of its staking mint. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registry of the pools of the staking mint"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the registry rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "prunePoolRegistry",
+      "docs": [
+        "Removes a pool from the registry of a staking mint. Only the protocol admin is able to do it.",
+        "The pool stays flagged as registered, so it is not able to register again."
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global config"
+          ]
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Registry of the pools of the staking mint"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Protocol admin, receiving the rent excess of the registry"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "pool",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "initializeGlobalConfig",
+      "docs": [
+        "Initializes the program global config. Only the program upgrade authority is able to do it."
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global config"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Program upgrade authority, becoming the protocol admin"
+          ]
+        },
+        {
+          "name": "program",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Farming program"
+          ]
+        },
+        {
+          "name": "programData",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Farming program data"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "protocolFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "updateGlobalConfig",
+      "docs": [
+        "Update the program global config. Fee changes only apply to pools created afterward."
+      ],
+      "accounts": [
+        {
+          "name": "globalConfig",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global config"
+          ]
+        },
+        {
+          "name": "admin",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Protocol admin"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "admin",
+          "type": "publicKey"
+        },
+        {
+          "name": "protocolFeeBps",
+          "type": "u16"
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "createUser",
+      "docs": [
+        "Initialize a user staking account"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of user account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "pause",
+      "docs": [
+        "Pause the pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "unpause",
+      "docs": [
+        "Unpauses a previously paused pool. Allowing for funding."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "deposit",
+      "docs": [
+        "User deposit tokens in the pool."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the deposit/withdraw instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User staking ATA"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "withdraw",
+      "docs": [
+        "User withdraw tokens in the pool."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the deposit/withdraw instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User staking ATA"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sptAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setRewardDuration",
+      "docs": [
+        "Set the reward duration used by the next funding. Only allowed between campaigns."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "rewardDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setUnbondingPeriod",
+      "docs": [
+        "Set the cooldown applied to withdrawals. Zero disables unbonding, allowing direct withdraw.",
+        "A longer period only applies to stake deposited afterwards, a shorter one applies to every user."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "unbondingPeriod",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "setMinStakeDuration",
+      "docs": [
+        "Set the time the stake of a user must stay in the pool before its rewards can be claimed.",
+        "Withdrawing earlier forfeits the pending rewards. Zero disables it.",
+        "A longer duration only applies to stake deposited afterwards, a shorter one applies to every user."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "minStakeDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "requestWithdraw",
+      "docs": [
+        "User moves staked tokens to the unbonding balance. They stop earning rewards immediately and can be withdrawn once the unbonding period is over."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the deposit/withdraw instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User staking ATA"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "sptAmount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "completeWithdraw",
+      "docs": [
+        "User withdraws the whole unbonding balance after the unbonding period."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the deposit/withdraw instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User staking ATA"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "authorizeFunder",
+      "docs": [
+        "Authorize additional funders for the pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "funderToAdd",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "deauthorizeFunder",
+      "docs": [
+        "Deauthorize funders for the pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "funderToRemove",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "enableNativeReward",
+      "docs": [
+        "Pays the flagged rewards in native SOL held by the native reward vault instead of the reward vaults.",
+        "The reward mint must be the native mint and the pool must not have been funded yet."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Native reward vault PDA"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool, paying the native reward vault rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "rewardA",
+          "type": "bool"
+        },
+        {
+          "name": "rewardB",
+          "type": "bool"
+        }
+      ]
+    },
+    {
+      "name": "fund",
+      "docs": [
+        "Fund the pool with rewards.  This resets the clock on the end date, pushing it out to the set duration. And, linearly redistributes remaining rewards."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Funder"
+          ]
+        },
+        {
+          "name": "fromA",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward A ATA. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "fromB",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward B ATA. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amountA",
+          "type": "u64"
+        },
+        {
+          "name": "amountB",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "fundWithMode",
+      "docs": [
+        "Fund the pool with rewards using the given [FundMode]."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Funder"
+          ]
+        },
+        {
+          "name": "fromA",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward A ATA. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "fromB",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Funder reward B ATA. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "mode",
+          "type": {
+            "defined": "FundMode"
+          }
+        }
+      ]
+    },
+    {
+      "name": "syncRewards",
+      "docs": [
+        "Fund the pool with the rewards held above the reward obligations, such as tokens sent directly to the reward vaults,",
+        "as a [FundMode::Reset] funding of those amounts."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "funder",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Funder"
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claim",
+      "docs": [
+        "User claim rewards"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B Vault PDA. Required when reward B is a token of a dual reward pool"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner of user. Signs unless the claim delegate does. Receives native rewards"
+          ]
+        },
+        {
+          "name": "rewardAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "User's Reward A ATA. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "rewardBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "User's Reward B ATA. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global config. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward A ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward B ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "treasury",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury wallet. Required when the pool charges a protocol fee on a native reward"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "delegate",
+          "isMut": false,
+          "isSigner": true,
+          "isOptional": true,
+          "docs": [
+            "Claim delegate registered by the user. Required when the owner does not sign"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer of the user. Required when the user has one and the pool pays a referral share"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimMany",
+      "docs": [
+        "Claim pending rewards from many pools at once.",
+        "Remaining accounts hold one group of [ClaimReward](/dual_farming/instruction/struct.ClaimReward.html)",
+        "accounts per pool, ordered as for the claim instruction, with the program id for absent optional accounts.",
+        "Every group is claimed by the caller, as the owner or as the claim delegate.",
+        "All or nothing: when the claim of any group fails, the instruction fails and no pool is claimed."
+      ],
+      "accounts": [
+        {
+          "name": "caller",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Owner or claim delegate of every claimed user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "transferPosition",
+      "docs": [
+        "Transfer the whole staked balance to another owner. Pending rewards are paid to the current owner first."
+      ],
+      "accounts": [
+        {
+          "name": "recipient",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "New owner of the position"
+          ]
+        },
+        {
+          "name": "recipientUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Recipient user, created if needed."
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the recipient user rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "claim",
+          "accounts": [
+            {
+              "name": "pool",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Global accounts for the staking instance."
+              ]
+            },
+            {
+              "name": "stakingVault",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Staking vault PDA."
+              ]
+            },
+            {
+              "name": "rewardAVault",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Reward A Vault PDA"
+              ]
+            },
+            {
+              "name": "rewardBVault",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Reward B Vault PDA. Required when reward B is a token of a dual reward pool"
+              ]
+            },
+            {
+              "name": "user",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "User."
+              ]
+            },
+            {
+              "name": "owner",
+              "isMut": true,
+              "isSigner": false,
+              "docs": [
+                "Owner of user. Signs unless the claim delegate does. Receives native rewards"
+              ]
+            },
+            {
+              "name": "rewardAAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "User's Reward A ATA. Required when reward A is a token"
+              ]
+            },
+            {
+              "name": "rewardBAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "User's Reward B ATA. Required when reward B is a token"
+              ]
+            },
+            {
+              "name": "tokenProgram",
+              "isMut": false,
+              "isSigner": false,
+              "docs": [
+                "Misc."
+              ]
+            },
+            {
+              "name": "globalConfig",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Global config. Required when the pool charges a protocol fee"
+              ]
+            },
+            {
+              "name": "treasuryAAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Treasury Reward A ATA. Required when the pool charges a protocol fee"
+              ]
+            },
+            {
+              "name": "treasuryBAccount",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Treasury Reward B ATA. Required when the pool charges a protocol fee"
+              ]
+            },
+            {
+              "name": "nativeVault",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Native reward vault PDA. Required when a reward is native SOL"
+              ]
+            },
+            {
+              "name": "treasury",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Treasury wallet. Required when the pool charges a protocol fee on a native reward"
+              ]
+            },
+            {
+              "name": "systemProgram",
+              "isMut": false,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "System program. Required when a reward is native SOL"
+              ]
+            },
+            {
+              "name": "delegate",
+              "isMut": false,
+              "isSigner": true,
+              "isOptional": true,
+              "docs": [
+                "Claim delegate registered by the user. Required when the owner does not sign"
+              ]
+            },
+            {
+              "name": "referrer",
+              "isMut": true,
+              "isSigner": false,
+              "isOptional": true,
+              "docs": [
+                "Referrer of the user. Required when the user has one and the pool pays a referral share"
+              ]
+            }
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setCompoundKeeper",
+      "docs": [
+        "Set the keeper allowed to compound rewards on behalf of the user. Pass the default pubkey to revoke."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "keeper",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setClaimDelegate",
+      "docs": [
+        "Set the delegate allowed to claim rewards on behalf of the user and the token accounts rewards are paid to.",
+        "Pass the default pubkey to revoke the delegate or to unset a destination. Native rewards are always paid to the owner."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "delegate",
+          "type": "publicKey"
+        },
+        {
+          "name": "rewardADestination",
+          "type": "publicKey"
+        },
+        {
+          "name": "rewardBDestination",
+          "type": "publicKey"
+        }
+      ]
+    },
+    {
+      "name": "setReferralFee",
+      "docs": [
+        "Set the share of the claimed rewards of referred users accrued to their referrer, in basis points.",
+        "A raised fee only applies to users depositing afterwards, a lowered fee applies to every user."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "referralFeeBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "setEmissionSchedule",
+      "docs": [
+        "Set the shape of the emission of the next fundings. Only allowed between campaigns.",
+        "Scheduled pools can only be funded with [FundMode::Reset]."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "schedule",
+          "type": {
+            "defined": "EmissionSchedule"
+          }
+        }
+      ]
+    },
+    {
+      "name": "createReferrer",
+      "docs": [
+        "Create the account accruing the referral rewards of a referrer in a pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Referrer"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet of the referrer"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setReferrer",
+      "docs": [
+        "User records the referrer it was referred by. It can only be set once, the referral outlives the user account."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Referrer of the user in the pool"
+          ]
+        },
+        {
+          "name": "referral",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Referral of the user, kept when the user is closed"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "restoreReferrer",
+      "docs": [
+        "Restores the referrer recorded by the referral on a recreated user. Permissionless."
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "referral",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Referral of the user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "claimReferralRewards",
+      "docs": [
+        "Referrer claims the referral rewards accrued in a pool"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B Vault PDA. Required when reward B is a token of a dual reward pool"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Referrer"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Wallet of the referrer. Receives native rewards"
+          ]
+        },
+        {
+          "name": "rewardAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer's Reward A account. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "rewardBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer's Reward B account. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "setBoostConfig",
+      "docs": [
+        "Configure the boost given to holders of a mint or of a verified collection. A zero multiplier disables it."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "boostMint",
+          "type": "publicKey"
+        },
+        {
+          "name": "boostCollection",
+          "type": "publicKey"
+        },
+        {
+          "name": "boostMultiplierBps",
+          "type": "u16"
+        }
+      ]
+    },
+    {
+      "name": "refreshBoost",
+      "docs": [
+        "User proves it holds the boost mint or a token of the boost collection and gets the pool boost on its stake."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "boostTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Token account of the owner holding the boost mint or a token of the boost collection"
+          ]
+        },
+        {
+          "name": "boostMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Metadata of the boost token mint. Required for collection boosts"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "stripBoost",
+      "docs": [
+        "Remove the boost of a user who no longer holds a token of the current boost mint or collection,",
+        "or when the pool boost is disabled. Re-syncs the boost of a holder to a changed pool multiplier. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "boostTokenAccount",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Token account recorded when the boost was refreshed"
+          ]
+        },
+        {
+          "name": "boostMetadata",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Metadata of the boost token mint. Required for collection boosts while the token account exists"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createStakeCheckpoint",
+      "docs": [
+        "Record the current stake seconds of the user and the pool in a checkpoint owned by the authority.",
+        "The checkpoint is the start of a window read by [snapshot_stake]."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "checkpoint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake checkpoint"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the checkpoint, usually a PDA of the program reading the snapshots"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the checkpoint rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "checkpointId",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "closeStakeCheckpoint",
+      "docs": [
+        "Closes a stake checkpoint. Only the authority of the checkpoint is able to do it."
+      ],
+      "accounts": [
+        {
+          "name": "checkpoint",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake checkpoint"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the checkpoint"
+          ]
+        },
+        {
+          "name": "refundee",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Rent receiver"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "snapshotStake",
+      "docs": [
+        "Bring the stake seconds of the user and the pool up to date and return them, with the",
+        "average balances since a stake checkpoint of the user. Other programs can read the result by CPI."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "checkpoint",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Stake checkpoint starting the window. Without it the averages are the current balances"
+          ]
+        }
+      ],
+      "args": [],
+      "returns": {
+        "defined": "StakeSnapshot"
+      }
+    },
+    {
+      "name": "createRegistrar",
+      "docs": [
+        "Bind an spl-governance realm and governing token mint to the pool whose stake counts as voting power.",
+        "Only the realm authority is able to do it."
+      ],
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Voter weight registrar of the realm and governing token mint"
+          ]
+        },
+        {
+          "name": "realm",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-governance realm"
+          ]
+        },
+        {
+          "name": "governanceProgramId",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "spl-governance program owning the realm"
+          ]
+        },
+        {
+          "name": "governingTokenMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Community or council mint of the realm"
+          ]
+        },
+        {
+          "name": "realmAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the realm"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Pool whose stake counts as voting power"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the registrar account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "voteLockDuration",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "createVoterWeightRecord",
+      "docs": [
+        "Create the voter weight record of a governing token owner"
+      ],
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Voter weight registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Voter weight record of the governing token owner"
+          ]
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Owner of the stake"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the voter weight record account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "updateVoterWeightRecord",
+      "docs": [
+        "Set the voter weight of the owner to its staked balance in the registrar pool. The weight",
+        "expires after the current slot, so it must be updated in the same transaction as the",
+        "governance instruction using it. The stake is locked for the vote lock duration of the",
+        "registrar, so the same tokens cannot be moved to another voter while the vote is running."
+      ],
+      "accounts": [
+        {
+          "name": "registrar",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Voter weight registrar"
+          ]
+        },
+        {
+          "name": "voterWeightRecord",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Voter weight record of the governing token owner"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User stake account of the governing token owner in the registrar pool"
+          ]
+        },
+        {
+          "name": "governingTokenOwner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Governing token owner, whose stake gets locked"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "compound",
+      "docs": [
+        "Restake pending rewards which have the same mint as the staking mint. Callable by the user owner or its keeper."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Owner of user or its compound keeper"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "globalConfig",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Global config. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward A ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "treasuryBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Treasury Reward B ATA. Required when the pool charges a protocol fee"
+          ]
+        },
+        {
+          "name": "referrer",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Referrer of the user. Required when the user has one and the pool pays a referral share"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawExtraToken",
+      "docs": [
+        "Withdraw token that mistakenly deposited to staking_vault"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "withdrawToAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Token account to receive mistakenly deposited token"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the staking instance"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "withdrawExtraReward",
+      "docs": [
+        "Withdraw reward tokens above the outstanding reward obligations, such as tokens sent directly to the reward vaults",
+        "or left over after campaigns"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "withdrawAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive the reward A vault surplus. Required when there is one"
+          ]
+        },
+        {
+          "name": "withdrawBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive the reward B vault surplus. Required when there is one"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the staking instance. Receives the native reward vault surplus"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "endCampaign",
+      "docs": [
+        "End the running campaign now and refund the rewards not yet emitted. Rewards already accrued by users are kept in the vaults."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward A Vault PDA"
+          ]
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Reward B Vault PDA"
+          ]
+        },
+        {
+          "name": "refundAAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive unemitted reward A. Required when reward A is a token"
+          ]
+        },
+        {
+          "name": "refundBAccount",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive unemitted reward B. Required when reward B is a token"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the staking instance. Receives unemitted native rewards"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeUser",
+      "docs": [
+        "Closes a users stake account. Validation is done to ensure this is only allowed when the user has nothing staked and no rewards pending."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closeDormantUser",
+      "docs": [
+        "Closes an abandoned user account with nothing staked and no rewards pending once the campaign is finished,",
+        "so that it does not block closing the pool. Rent goes back to the user owner. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Global accounts for the staking instance."
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User."
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Owner of the user, receiving the rent"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateFarmingRate",
+      "docs": [
+        "anyone can call this"
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migrateUser",
+      "docs": [
+        "Grow a user account created with a smaller size to the current user size. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User to migrate"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the additional rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "migratePool",
+      "docs": [
+        "Grow a pool account created with a smaller size to the current pool size. Anyone can call this."
+      ],
+      "accounts": [
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool to migrate"
+          ]
+        },
+        {
+          "name": "payer",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Payer of the additional rent"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "closePool",
+      "docs": [
+        "Closes a pool account. Only able to be done when there are no users staked."
+      ],
+      "accounts": [
+        {
+          "name": "refundee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "stakingRefundee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardARefundee",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBRefundee",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Token account to receive the reward B vault balance. Required when the pool has a reward B vault"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardAVault",
+          "isMut": true,
+          "isSigner": false
+        },
+        {
+          "name": "rewardBVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Reward B vault. Required when the pool has one separate from the reward A vault"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false
+        },
+        {
+          "name": "poolRegistry",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Registry of the pools of the staking mint. Required when the pool is registered"
+          ]
+        },
+        {
+          "name": "nativeVault",
+          "isMut": true,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "Native reward vault PDA. Required when a reward is native SOL"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "isOptional": true,
+          "docs": [
+            "System program. Required when a reward is native SOL"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "initializeStakeHub",
+      "docs": [
+        "Create a stake hub holding the staked tokens once for every reward pool attached to it."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "stakingMint",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Staking mint"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "base",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Base"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "System program"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "SPL Token program"
+          ]
+        },
+        {
+          "name": "rent",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Rent"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "attachPool",
+      "docs": [
+        "Attach a reward pool to a stake hub. The pool then distributes rewards on the hub balances",
+        "of the users who joined it instead of its own staking vault. Only allowed while the pool holds no stake."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool to attach"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Staking vault of the pool"
+          ]
+        },
+        {
+          "name": "poolAuthority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the pool"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "createHubUser",
+      "docs": [
+        "User enables staking in a stake hub"
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": true,
+          "isSigner": true,
+          "docs": [
+            "Authority of hub user account"
+          ]
+        },
+        {
+          "name": "systemProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "joinHubPool",
+      "docs": [
+        "User starts earning the rewards of a pool attached to the stake hub with its hub balance.",
+        "The user account of the pool must exist."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool attached to the stake hub"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User of the pool"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "leaveHubPool",
+      "docs": [
+        "User stops earning the rewards of a pool attached to the stake hub, such as after its campaign ended."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool attached to the stake hub"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User of the pool"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "removeHubPoolMember",
+      "docs": [
+        "Stake hub authority removes a user from a pool whose campaign ended, so that the pool can be detached.",
+        "The rewards earned by the user stay claimable."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user to remove"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool attached to the stake hub"
+          ]
+        },
+        {
+          "name": "user",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "User of the pool"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "detachPool",
+      "docs": [
+        "Detach a pool from its stake hub, freeing its slot. The pool must be closed,",
+        "or have its campaign ended and no hub balance mirrored anymore.",
+        "An open pool can then take deposits of its own again."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "authority",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of the stake hub"
+          ]
+        },
+        {
+          "name": "pool",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Pool to detach"
+          ]
+        }
+      ],
+      "args": []
+    },
+    {
+      "name": "hubDeposit",
+      "docs": [
+        "User deposits tokens in the stake hub.",
+        "Remaining accounts hold the pool and user accounts of every joined pool, in slot order."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The user token account to deposit from or withdraw to"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    },
+    {
+      "name": "hubWithdraw",
+      "docs": [
+        "User withdraws tokens from the stake hub.",
+        "Remaining accounts hold the pool and user accounts of every joined pool, in slot order."
+      ],
+      "accounts": [
+        {
+          "name": "stakeHub",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Stake hub"
+          ]
+        },
+        {
+          "name": "stakingVault",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Staking vault PDA"
+          ]
+        },
+        {
+          "name": "hubUser",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "Hub user"
+          ]
+        },
+        {
+          "name": "owner",
+          "isMut": false,
+          "isSigner": true,
+          "docs": [
+            "Authority of user"
+          ]
+        },
+        {
+          "name": "stakeFromAccount",
+          "isMut": true,
+          "isSigner": false,
+          "docs": [
+            "The user token account to deposit from or withdraw to"
+          ]
+        },
+        {
+          "name": "tokenProgram",
+          "isMut": false,
+          "isSigner": false,
+          "docs": [
+            "Misc."
+          ]
+        }
+      ],
+      "args": [
+        {
+          "name": "amount",
+          "type": "u64"
+        }
+      ]
+    }
+  ],
+  "accounts": [
+    {
+      "name": "pool",
+      "docs": [
+        "Pool account wrapper"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Privileged account."
+            ],
+            "type": "publicKey"
+          },
+          {
             "name": "paused",
             "docs": [
-              "Paused state of the program"
+              "Paused state of the program"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "stakingMint",
+            "docs": [
+              "Mint of the token that can be staked."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingVault",
+            "docs": [
+              "Vault to store staked tokens."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAMint",
+            "docs": [
+              "Mint of the reward A token."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardAVault",
+            "docs": [
+              "Vault to store reward A tokens."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardBMint",
+            "docs": [
+              "Mint of the reward B token."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardBVault",
+            "docs": [
+              "Vault to store reward B tokens."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseKey",
+            "docs": [
+              "Base key"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "rewardDuration",
+            "docs": [
+              "The period which rewards are linearly distributed by the next funding."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardDurationEnd",
+            "docs": [
+              "The timestamp at which the current reward period ends."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "lastUpdateTime",
+            "docs": [
+              "The last time reward states were updated."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardARate",
+            "docs": [
+              "deprecated field"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBRate",
+            "docs": [
+              "deprecated field"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardAPerTokenStored",
+            "docs": [
+              "Last calculated reward A per pool token."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBPerTokenStored",
+            "docs": [
+              "Last calculated reward B per pool token."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "userStakeCount",
+            "docs": [
+              "Users staked"
+            ],
+            "type": "u32"
+          },
+          {
+            "name": "funders",
+            "docs": [
+              "authorized funders",
+              "[] because short size, fixed account size, and ease of use on",
+              "client due to auto generated account size property"
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                3
+              ]
+            }
+          },
+          {
+            "name": "rewardARateU128",
+            "docs": [
+              "reward_a_rate in u128 form"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBRateU128",
+            "docs": [
+              "reward_b_rate in u128 form"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "poolBump",
+            "docs": [
+              "Pool bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalStaked",
+            "docs": [
+              "Total staked amount"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriod",
+            "docs": [
+              "Cooldown before unbonding tokens can be withdrawn. Zero allows direct withdraw."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "totalUnbonding",
+            "docs": [
+              "Total amount waiting for the unbonding period to end"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "seedRewardDuration",
+            "docs": [
+              "Reward duration used in the pool PDA seeds. Zero for pools created before it was stored."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "protocolFeeBps",
+            "docs": [
+              "Protocol fee on claimed rewards in basis points, snapshotted from the global config at creation"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "registered",
+            "docs": [
+              "Whether the pool is listed in the registry of its staking mint"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "precision",
+            "docs": [
+              "Scale of reward rates and reward per token, from the mint decimals. Zero for pools created before it was stored."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardAPerTokenRemainder",
+            "docs": [
+              "Emitted reward A, scaled by precision, not yet assigned to reward A per token"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBPerTokenRemainder",
+            "docs": [
+              "Emitted reward B, scaled by precision, not yet assigned to reward B per token"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardARateRemainder",
+            "docs": [
+              "Funded reward A, scaled by precision, not covered by the reward A rate. Carried into the next funding."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBRateRemainder",
+            "docs": [
+              "Funded reward B, scaled by precision, not covered by the reward B rate. Carried into the next funding."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "boostMint",
+            "docs": [
+              "Mint whose holders get the boost. Default pubkey when unused."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "boostCollection",
+            "docs": [
+              "Verified collection whose holders get the boost. Default pubkey when unused."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "boostMultiplierBps",
+            "docs": [
+              "Multiplier of the stake of boosted users in basis points. Zero disables the boost."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "totalBoostStake",
+            "docs": [
+              "Extra stake of boosted users, counted in rewards but not in the staking vault"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakeSeconds",
+            "docs": [
+              "Cumulative total staked multiplied by the seconds it was staked"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "stakeSecondsUpdatedAt",
+            "docs": [
+              "The last time stake seconds were updated. Zero before the first update."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardANative",
+            "docs": [
+              "Whether reward A is paid in native SOL from the native reward vault"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rewardBNative",
+            "docs": [
+              "Whether reward B is paid in native SOL from the native reward vault"
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "nativeVaultBump",
+            "docs": [
+              "Native reward vault bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "rewardAObligation",
+            "docs": [
+              "Reward A owed by the pool, funded and neither refunded, claimed nor emitted while nothing was staked.",
+              "Reward A above it is surplus."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBObligation",
+            "docs": [
+              "Reward B owed by the pool, funded and neither refunded, claimed nor emitted while nothing was staked.",
+              "Reward B above it is surplus."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardObligationsTracked",
+            "docs": [
+              "Whether reward obligations are tracked. False for pools created before they were tracked."
+            ],
+            "type": "bool"
+          },
+          {
+            "name": "rewardMode",
+            "docs": [
+              "Whether the pool distributes one or two rewards. Unknown for pools created before it was stored."
+            ],
+            "type": {
+              "defined": "RewardMode"
+            }
+          },
+          {
+            "name": "stakeHub",
+            "docs": [
+              "Stake hub holding the stake the pool distributes rewards on. Default pubkey when the pool holds its own stake."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "minStakeDuration",
+            "docs": [
+              "Seconds the stake of a user must stay in the pool before its rewards can be claimed. Zero disables it."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "referralFeeBps",
+            "docs": [
+              "Share of the claimed rewards of referred users accrued to their referrer, in basis points"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "emissionCurve",
+            "docs": [
+              "Shape of the emission over the reward period. Flat for pools created before it was stored."
+            ],
+            "type": {
+              "defined": "EmissionCurve"
+            }
+          },
+          {
+            "name": "decayStartWeight",
+            "docs": [
+              "Relative emission rate at the start of the reward period of a linear decay"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "decayEndWeight",
+            "docs": [
+              "Relative emission rate at the end of the reward period of a linear decay"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "emissionSteps",
+            "docs": [
+              "Relative emission rates of a stepwise schedule. Unused steps are zeroed."
+            ],
+            "type": {
+              "array": [
+                {
+                  "defined": "EmissionStep"
+                },
+                8
+              ]
+            }
+          },
+          {
+            "name": "rewardPeriodStart",
+            "docs": [
+              "The timestamp at which the current reward period started. Zero for periods started before it was stored."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardAScheduled",
+            "docs": [
+              "Reward A, scaled by precision, emitted by the emission schedule over the current reward period"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBScheduled",
+            "docs": [
+              "Reward B, scaled by precision, emitted by the emission schedule over the current reward period"
+            ],
+            "type": "u128"
+          }
+        ]
+      }
+    },
+    {
+      "name": "globalConfig",
+      "docs": [
+        "Program-wide configuration"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "admin",
+            "docs": [
+              "Protocol admin"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "protocolFeeBps",
+            "docs": [
+              "Protocol fee on claimed rewards in basis points, applied to pools created afterward"
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "treasury",
+            "docs": [
+              "Owner of the token accounts receiving the protocol fee"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Global config bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "poolRegistry",
+      "docs": [
+        "Pools sharing the same staking mint"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakingMint",
+            "docs": [
+              "Staking mint of the pools"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Pool registry bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "pools",
+            "docs": [
+              "Pools staking the mint"
+            ],
+            "type": {
+              "vec": "publicKey"
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeHub",
+      "docs": [
+        "Staked tokens shared by the reward pools attached to it"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "authority",
+            "docs": [
+              "Authority allowed to attach and detach pools"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingMint",
+            "docs": [
+              "Mint of the staked tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "stakingVault",
+            "docs": [
+              "Vault holding the staked tokens"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "baseKey",
+            "docs": [
+              "Base used to derive the stake hub address"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Stake hub bump"
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "totalStaked",
+            "docs": [
+              "Total staked in the hub"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "pools",
+            "docs": [
+              "Attached pools. Default pubkey for free slots."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          }
+        ]
+      }
+    },
+    {
+      "name": "stakeCheckpoint",
+      "docs": [
+        "Stake seconds of a user and its pool recorded on chain, starting a stake snapshot window"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "user",
+            "docs": [
+              "User the checkpoint belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "authority",
+            "docs": [
+              "Authority of the checkpoint"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "checkpointId",
+            "docs": [
+              "Checkpoint id, chosen by the authority"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "timestamp",
+            "docs": [
+              "Time of the checkpoint"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "stakeSeconds",
+            "docs": [
+              "Cumulative stake seconds of the user"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "totalStakeSeconds",
+            "docs": [
+              "Cumulative stake seconds of the pool"
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Stake checkpoint bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "hubUser",
+      "docs": [
+        "Stake of a user in a stake hub"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "stakeHub",
+            "docs": [
+              "Stake hub the user belongs to"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "owner",
+            "docs": [
+              "The owner of this account"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "balanceStaked",
+            "docs": [
+              "The amount staked in the hub"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "joinedPools",
+            "docs": [
+              "Pool the user earns the rewards of in each hub slot. Default pubkey when none."
+            ],
+            "type": {
+              "array": [
+                "publicKey",
+                4
+              ]
+            }
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Hub user bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referrer",
+      "docs": [
+        "Referral rewards accrued by a referrer in a pool"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the referrer belongs to"
             ],
-            "type": "bool"
+            "type": "publicKey"
           },
           {
-            "name": "stakingMint",
+            "name": "owner",
             "docs": [
-              "Mint of the token that can be staked."
+              "Wallet of the referrer"
             ],
             "type": "publicKey"
           },
           {
-            "name": "stakingVault",
+            "name": "rewardAAccrued",
             "docs": [
-              "Vault to store staked tokens."
+              "Reward A accrued and not claimed yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBAccrued",
+            "docs": [
+              "Reward B accrued and not claimed yet"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Referrer bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "referral",
+      "docs": [
+        "Referrer recorded by a user in a pool. Not closed with the user, so the referrer survives its recreation"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the user belongs to"
             ],
             "type": "publicKey"
           },
           {
-            "name": "rewardAMint",
+            "name": "owner",
             "docs": [
-              "Mint of the reward A token."
+              "Wallet of the user"
             ],
             "type": "publicKey"
           },
           {
-            "name": "rewardAVault",
+            "name": "referrer",
             "docs": [
-              "Vault to store reward A tokens."
+              "Wallet of the referrer"
             ],
             "type": "publicKey"
           },
           {
-            "name": "rewardBMint",
+            "name": "bump",
             "docs": [
-              "Mint of the reward B token."
+              "Referral bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "user",
+      "docs": [
+        "Farming user account"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "pool",
+            "docs": [
+              "Pool the this user belongs to."
             ],
             "type": "publicKey"
           },
           {
-            "name": "rewardBVault",
+            "name": "owner",
             "docs": [
-              "Vault to store reward B tokens."
+              "The owner of this account."
             ],
             "type": "publicKey"
           },
           {
-            "name": "baseKey",
+            "name": "rewardAPerTokenComplete",
             "docs": [
-              "Base key"
+              "The amount of token A claimed."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardBPerTokenComplete",
+            "docs": [
+              "The amount of token B claimed."
+            ],
+            "type": "u128"
+          },
+          {
+            "name": "rewardAPerTokenPending",
+            "docs": [
+              "The amount of token A pending claim."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "rewardBPerTokenPending",
+            "docs": [
+              "The amount of token B pending claim."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "balanceStaked",
+            "docs": [
+              "The amount staked."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "nonce",
+            "docs": [
+              "Signer nonce."
+            ],
+            "type": "u8"
+          },
+          {
+            "name": "compoundKeeper",
+            "docs": [
+              "Keeper allowed to compound rewards on behalf of the owner."
             ],
             "type": "publicKey"
           },
           {
-            "name": "rewardDuration",
+            "name": "balanceUnbonding",
             "docs": [
-              "The period which rewards are linearly distributed."
+              "The amount waiting for the unbonding period to end."
             ],
             "type": "u64"
           },
           {
-            "name": "rewardDurationEnd",
+            "name": "unbondingEnd",
             "docs": [
-              "The timestamp at which the current reward period ends."
+              "The timestamp at which the unbonding balance can be withdrawn."
             ],
             "type": "u64"
           },
           {
-            "name": "lastUpdateTime",
+            "name": "rewardAEarnedRemainder",
             "docs": [
-              "The last time reward states were updated."
+              "Earned reward A, scaled by precision, below one token unit. Carried into the next update."
             ],
             "type": "u64"
           },
           {
-            "name": "rewardARate",
+            "name": "rewardBEarnedRemainder",
             "docs": [
-              "deprecated field"
+              "Earned reward B, scaled by precision, below one token unit. Carried into the next update."
             ],
             "type": "u64"
           },
           {
-            "name": "rewardBRate",
+            "name": "boostMultiplierBps",
             "docs": [
-              "deprecated field"
+              "Boost multiplier of the user in basis points, snapshotted from the pool at refresh. Zero when not boosted."
+            ],
+            "type": "u16"
+          },
+          {
+            "name": "boostStake",
+            "docs": [
+              "Extra stake given by the boost."
             ],
             "type": "u64"
           },
           {
-            "name": "rewardAPerTokenStored",
+            "name": "boostTokenAccount",
             "docs": [
-              "Last calculated reward A per pool token."
+              "Token account proving the boost holding."
             ],
-            "type": "u128"
+            "type": "publicKey"
           },
           {
-            "name": "rewardBPerTokenStored",
+            "name": "stakeSeconds",
             "docs": [
-              "Last calculated reward B per pool token."
+              "Cumulative balance staked multiplied by the seconds it was staked."
             ],
             "type": "u128"
           },
           {
-            "name": "userStakeCount",
+            "name": "stakeSecondsUpdatedAt",
             "docs": [
-              "Users staked"
+              "The last time stake seconds were updated. Zero before the first update."
             ],
-            "type": "u32"
+            "type": "u64"
           },
           {
-            "name": "funders",
+            "name": "claimDelegate",
             "docs": [
-              "authorized funders",
-              "[] because short size, fixed account size, and ease of use on",
-              "client due to auto generated account size property"
+              "Delegate allowed to claim rewards on behalf of the owner."
             ],
-            "type": {
-              "array": [
-                "publicKey",
-                3
-              ]
-            }
+            "type": "publicKey"
           },
           {
-            "name": "rewardARateU128",
+            "name": "rewardADestination",
             "docs": [
-              "reward_a_rate in u128 form"
+              "Token account reward A must be paid to. Default pubkey when unset."
             ],
-            "type": "u128"
+            "type": "publicKey"
           },
           {
-            "name": "rewardBRateU128",
+            "name": "rewardBDestination",
             "docs": [
-              "reward_b_rate in u128 form"
+              "Token account reward B must be paid to. Default pubkey when unset."
             ],
-            "type": "u128"
+            "type": "publicKey"
           },
           {
-            "name": "poolBump",
+            "name": "lastDepositAt",
             "docs": [
-              "Pool bump"
+              "The last time the user added stake. Zero before the first deposit."
             ],
-            "type": "u8"
+            "type": "u64"
           },
           {
-            "name": "totalStaked",
+            "name": "referrer",
             "docs": [
-              "Total staked amount"
+              "Wallet of the referrer of the user. Default pubkey when unset."
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "voteLockedUntil",
+            "docs": [
+              "The stake cannot be withdrawn or transferred before this time, since it was used as voter weight."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "unbondingPeriod",
+            "docs": [
+              "Unbonding period of the pool at the last deposit of the user."
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "minStakeDuration",
+            "docs": [
+              "Minimum stake duration of the pool at the last deposit of the user."
             ],
             "type": "u64"
+          },
+          {
+            "name": "referralFeeBps",
+            "docs": [
+              "Referral fee of the pool at the last deposit of the user, in basis points."
+            ],
+            "type": "u16"
           }
         ]
       }
     },
     {
-      "name": "user",
+      "name": "registrar",
       "docs": [
-        "Farming user account"
+        "Binding of an spl-governance realm and governing token mint to the pool whose stake is voting power"
       ],
       "type": {
         "kind": "struct",
         "fields": [
+          {
+            "name": "governanceProgramId",
+            "docs": [
+              "spl-governance program owning the realm"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "realm",
+            "docs": [
+              "Realm using the farm stake as voter weight"
+            ],
+            "type": "publicKey"
+          },
+          {
+            "name": "governingTokenMint",
+            "docs": [
+              "Community or council mint of the realm"
+            ],
+            "type": "publicKey"
+          },
           {
             "name": "pool",
             "docs": [
-              "Pool the this user belongs to."
+              "Pool whose stake counts as voting power"
             ],
             "type": "publicKey"
           },
           {
-            "name": "owner",
+            "name": "voteLockDuration",
             "docs": [
-              "The owner of this account."
+              "Time the stake of a voter stays locked after its voter weight is updated"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "bump",
+            "docs": [
+              "Registrar bump"
+            ],
+            "type": "u8"
+          }
+        ]
+      }
+    },
+    {
+      "name": "voterWeightRecord",
+      "docs": [
+        "spl-governance voter weight record. The layout and discriminator match the spl-governance addin api."
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "realm",
+            "docs": [
+              "Realm the record belongs to"
             ],
             "type": "publicKey"
           },
           {
-            "name": "rewardAPerTokenComplete",
+            "name": "governingTokenMint",
             "docs": [
-              "The amount of token A claimed."
+              "Governing token mint the record belongs to"
             ],
-            "type": "u128"
+            "type": "publicKey"
           },
           {
-            "name": "rewardBPerTokenComplete",
+            "name": "governingTokenOwner",
             "docs": [
-              "The amount of token B claimed."
+              "Owner of the voting power"
             ],
-            "type": "u128"
+            "type": "publicKey"
+          },
+          {
+            "name": "voterWeight",
+            "docs": [
+              "Voter weight"
+            ],
+            "type": "u64"
           },
           {
-            "name": "rewardAPerTokenPending",
+            "name": "voterWeightExpiry",
+            "docs": [
+              "Slot after which the voter weight is stale"
+            ],
+            "type": {
+              "option": "u64"
+            }
+          },
+          {
+            "name": "weightAction",
+            "docs": [
+              "Governance action the voter weight is valid for. Any action when none."
+            ],
+            "type": {
+              "option": {
+                "defined": "VoterWeightAction"
+              }
+            }
+          },
+          {
+            "name": "weightActionTarget",
+            "docs": [
+              "Target of the governance action. Any target when none."
+            ],
+            "type": {
+              "option": "publicKey"
+            }
+          },
+          {
+            "name": "reserved",
+            "docs": [
+              "Reserved space"
+            ],
+            "type": {
+              "array": [
+                "u8",
+                8
+              ]
+            }
+          }
+        ]
+      }
+    }
+  ],
+  "types": [
+    {
+      "name": "StakeSnapshot",
+      "docs": [
+        "Stake seconds of a user and its pool at a point in time"
+      ],
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "timestamp",
             "docs": [
-              "The amount of token A pending claim."
+              "Time of the snapshot"
             ],
             "type": "u64"
           },
           {
-            "name": "rewardBPerTokenPending",
+            "name": "stakeSeconds",
             "docs": [
-              "The amount of token B pending claim."
+              "Cumulative stake seconds of the user"
             ],
-            "type": "u64"
+            "type": "u128"
           },
           {
-            "name": "balanceStaked",
+            "name": "totalStakeSeconds",
             "docs": [
-              "The amount staked."
+              "Cumulative stake seconds of the pool"
             ],
-            "type": "u64"
+            "type": "u128"
           },
           {
-            "name": "nonce",
+            "name": "averageBalance",
             "docs": [
-              "Signer nonce."
+              "Average balance staked by the user since the checkpoint"
             ],
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
-    {
-      "name": "EventDeposit",
-      "fields": [
-        {
-          "name": "amount",
-          "type": "u64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "EventWithdraw",
-      "fields": [
-        {
-          "name": "amount",
-          "type": "u64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "EventFund",
-      "fields": [
-        {
-          "name": "amountA",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "amountB",
-          "type": "u64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "EventClaim",
-      "fields": [
-        {
-          "name": "amountA",
-          "type": "u64",
-          "index": false
-        },
-        {
-          "name": "amountB",
-          "type": "u64",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "EventAuthorizeFunder",
-      "fields": [
-        {
-          "name": "newFunder",
-          "type": "publicKey",
-          "index": false
-        }
-      ]
-    },
-    {
-      "name": "EventUnauthorizeFunder",
-      "fields": [
-        {
-          "name": "funder",
-          "type": "publicKey",
-          "index": false
-        }
-      ]
-    }
-  ],
-  "errors": [
-    {
-      "code": 6000,
-      "name": "InsufficientFundWithdraw",
-      "msg": "Insufficient funds to withdraw."
-    },
-    {
-      "code": 6001,
-      "name": "AmountMustBeGreaterThanZero",
-      "msg": "Amount must be greater than zero."
-    },
-    {
-      "code": 6002,
-      "name": "SingleDepositTokenBCannotBeFunded",
-      "msg": "Reward B cannot be funded - pool is single deposit."
-    },
-    {
-      "code": 6003,
-      "name": "PoolPaused",
-      "msg": "Pool is paused."
-    },
-    {
-      "code": 6004,
-      "name": "DurationTooShort",
-      "msg": "Duration cannot be shorter than one day."
-    },
-    {
-      "code": 6005,
-      "name": "FunderAlreadyAuthorized",
-      "msg": "Provided funder is already authorized to fund."
-    },
-    {
-      "code": 6006,
-      "name": "MaxFunders",
-      "msg": "Maximum funders already authorized."
-    },
-    {
-      "code": 6007,
-      "name": "CannotDeauthorizePoolAuthority",
-      "msg": "Cannot deauthorize the primary pool authority."
-    },
-    {
-      "code": 6008,
-      "name": "CannotDeauthorizeMissingAuthority",
-      "msg": "Authority not found for deauthorization."
-    },
-    {
-      "code": 6009,
-      "name": "MathOverflow",
-      "msg": "Math operation overflow"
-    }
-  ]
-};
-
-export const IDL: Farming = {
-  "version": "0.2.2",
-  "name": "farming",
-  "docs": [
-    "Dual farming program"
-  ],
-  "instructions": [
-    {
-      "name": "initializePool",
-      "docs": [
-        "Initializes a new pool. Able to create pool with single reward by passing the same Mint account for reward_a_mint and reward_a_mint"
-      ],
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
-        },
-        {
-          "name": "stakingMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Staking mint"
-          ]
-        },
-        {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Staking vault PDA"
-          ]
-        },
-        {
-          "name": "rewardAMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Reward A mint"
-          ]
-        },
-        {
-          "name": "rewardAVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward A vault PDA"
-          ]
-        },
-        {
-          "name": "rewardBMint",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Reward B mint"
-          ]
-        },
-        {
-          "name": "rewardBVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward B vault PDA"
-          ]
-        },
-        {
-          "name": "authority",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Authority of the pool"
-          ]
-        },
-        {
-          "name": "base",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Base"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "System program"
-          ]
-        },
-        {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "SPL Token program"
-          ]
-        },
-        {
-          "name": "rent",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Rent"
-          ]
-        }
+            "type": "u64"
+          },
+          {
+            "name": "averageTotalStaked",
+            "docs": [
+              "Average total staked in the pool since the checkpoint"
+            ],
+            "type": "u64"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionStep",
+      "docs": [
+        "Step of a stepwise emission schedule"
       ],
-      "args": [
-        {
-          "name": "rewardDuration",
-          "type": "u64"
-        }
-      ]
+      "type": {
+        "kind": "struct",
+        "fields": [
+          {
+            "name": "startOffset",
+            "docs": [
+              "Seconds from the start of the reward period at which the step starts"
+            ],
+            "type": "u64"
+          },
+          {
+            "name": "weight",
+            "docs": [
+              "Emission rate of the step, relative to the other steps"
+            ],
+            "type": "u16"
+          }
+        ]
+      }
     },
     {
-      "name": "createUser",
+      "name": "VoterWeightAction",
       "docs": [
-        "Initialize a user staking account"
+        "spl-governance action a voter weight is used for"
       ],
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
-        },
-        {
-          "name": "user",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User"
-          ]
-        },
-        {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true,
-          "docs": [
-            "Authority of user account"
-          ]
-        },
-        {
-          "name": "systemProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Misc."
-          ]
-        }
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "CastVote"
+          },
+          {
+            "name": "CommentProposal"
+          },
+          {
+            "name": "CreateGovernance"
+          },
+          {
+            "name": "CreateProposal"
+          },
+          {
+            "name": "SignOffProposal"
+          }
+        ]
+      }
+    },
+    {
+      "name": "RewardMode",
+      "docs": [
+        "Number of rewards distributed by a pool"
       ],
-      "args": []
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Unknown"
+          },
+          {
+            "name": "Dual"
+          },
+          {
+            "name": "Single"
+          }
+        ]
+      }
     },
     {
-      "name": "pause",
+      "name": "EmissionCurve",
       "docs": [
-        "Pause the pool"
+        "Shape of the emission over the reward period"
       ],
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
-        },
-        {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of the pool"
-          ]
-        }
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Flat"
+          },
+          {
+            "name": "LinearDecay"
+          },
+          {
+            "name": "Stepwise"
+          }
+        ]
+      }
+    },
+    {
+      "name": "EmissionSchedule",
+      "docs": [
+        "Emission schedule of the fundings of a pool. Weights are relative rates, the funded amount is spread over the reward period in their proportions."
       ],
-      "args": []
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Flat"
+          },
+          {
+            "name": "LinearDecay",
+            "fields": [
+              {
+                "name": "start_weight",
+                "docs": [
+                  "Relative rate at the start of the reward period"
+                ],
+                "type": "u16"
+              },
+              {
+                "name": "end_weight",
+                "docs": [
+                  "Relative rate at the end of the reward period"
+                ],
+                "type": "u16"
+              }
+            ]
+          },
+          {
+            "name": "Stepwise",
+            "fields": [
+              {
+                "name": "steps",
+                "docs": [
+                  "Steps in increasing start offsets, the first one starting at offset zero"
+                ],
+                "type": {
+                  "vec": {
+                    "defined": "EmissionStep"
+                  }
+                }
+              }
+            ]
+          }
+        ]
+      }
     },
     {
-      "name": "unpause",
+      "name": "FundMode",
       "docs": [
-        "Unpauses a previously paused pool. Allowing for funding."
+        "How funding affects the reward rate and the end of the reward period"
       ],
-      "accounts": [
+      "type": {
+        "kind": "enum",
+        "variants": [
+          {
+            "name": "Reset",
+            "fields": [
+              {
+                "name": "amount_a",
+                "docs": [
+                  "Amount of reward A to fund"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "amount_b",
+                "docs": [
+                  "Amount of reward B to fund"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "TopUp",
+            "fields": [
+              {
+                "name": "amount_a",
+                "docs": [
+                  "Amount of reward A to fund"
+                ],
+                "type": "u64"
+              },
+              {
+                "name": "amount_b",
+                "docs": [
+                  "Amount of reward B to fund"
+                ],
+                "type": "u64"
+              }
+            ]
+          },
+          {
+            "name": "Extend",
+            "fields": [
+              {
+                "name": "seconds",
+                "docs": [
+                  "Seconds added to the reward period"
+                ],
+                "type": "u64"
+              }
+            ]
+          }
+        ]
+      }
+    }
+  ],
+  "events": [
+    {
+      "name": "EventDeposit",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventWithdraw",
+      "fields": [
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventRequestWithdraw",
+      "fields": [
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of the pool"
-          ]
+          "name": "unbondingEnd",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "deposit",
-      "docs": [
-        "User deposit tokens in the pool."
-      ],
-      "accounts": [
+      "name": "EventCompleteWithdraw",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the deposit/withdraw instance."
-          ]
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventFund",
+      "fields": [
+        {
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Staking vault PDA."
-          ]
+          "name": "amountB",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "user",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User."
-          ]
+          "name": "undistributableA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of user"
-          ]
+          "name": "undistributableB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventEndCampaign",
+      "fields": [
+        {
+          "name": "refundA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "stakeFromAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User staking ATA"
-          ]
+          "name": "refundB",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Misc."
-          ]
+          "name": "endTime",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "EventSetRewardDuration",
+      "fields": [
         {
-          "name": "amount",
-          "type": "u64"
+          "name": "rewardDuration",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "withdraw",
-      "docs": [
-        "User withdraw tokens in the pool."
-      ],
-      "accounts": [
+      "name": "EventClaim",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the deposit/withdraw instance."
-          ]
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Staking vault PDA."
-          ]
-        },
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventClaimManyResult",
+      "fields": [
         {
-          "name": "user",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User."
-          ]
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         },
         {
           "name": "owner",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of user"
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "stakeFromAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User staking ATA"
-          ]
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Misc."
-          ]
+          "name": "amountB",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "EventAttachPool",
+      "fields": [
         {
-          "name": "sptAmount",
-          "type": "u64"
+          "name": "stakeHub",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         }
       ]
     },
     {
-      "name": "authorizeFunder",
-      "docs": [
-        "Authorize additional funders for the pool"
-      ],
-      "accounts": [
+      "name": "EventDetachPool",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
+          "name": "stakeHub",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of the pool"
-          ]
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "EventJoinHubPool",
+      "fields": [
         {
-          "name": "funderToAdd",
-          "type": "publicKey"
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "deauthorizeFunder",
-      "docs": [
-        "Deauthorize funders for the pool"
-      ],
-      "accounts": [
+      "name": "EventLeaveHubPool",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of the pool"
-          ]
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "EventHubDeposit",
+      "fields": [
         {
-          "name": "funderToRemove",
-          "type": "publicKey"
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "fund",
-      "docs": [
-        "Fund the pool with rewards.  This resets the clock on the end date, pushing it out to the set duration. And, linearly redistributes remaining rewards."
-      ],
-      "accounts": [
+      "name": "EventHubWithdraw",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Staking vault PDA"
-          ]
-        },
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventForfeitRewards",
+      "fields": [
         {
-          "name": "rewardAVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward A Vault PDA"
-          ]
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rewardBVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward B Vault PDA"
-          ]
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "funder",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Funder"
-          ]
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetReferralFee",
+      "fields": [
+        {
+          "name": "referralFeeBps",
+          "type": "u16",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetEmissionSchedule",
+      "fields": [
+        {
+          "name": "schedule",
+          "type": {
+            "defined": "EmissionSchedule"
+          },
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetReferrer",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "fromA",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Funder reward A ATA"
-          ]
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventReferralAccrued",
+      "fields": [
+        {
+          "name": "referrer",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "fromB",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Funder reward B ATA"
-          ]
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Misc."
-          ]
+          "name": "amountB",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": [
+      ]
+    },
+    {
+      "name": "EventClaimReferralRewards",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
         {
           "name": "amountA",
-          "type": "u64"
+          "type": "u64",
+          "index": false
         },
         {
           "name": "amountB",
-          "type": "u64"
+          "type": "u64",
+          "index": false
         }
       ]
     },
     {
-      "name": "claim",
-      "docs": [
-        "User claim rewards"
-      ],
-      "accounts": [
+      "name": "EventCompound",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Staking vault PDA."
-          ]
-        },
+          "name": "amountB",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventPositionSent",
+      "fields": [
         {
-          "name": "rewardAVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward A Vault PDA"
-          ]
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rewardBVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Reward B Vault PDA"
-          ]
+          "name": "recipient",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "user",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User."
-          ]
-        },
+          "name": "amount",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventPositionReceived",
+      "fields": [
         {
           "name": "owner",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of user"
-          ]
-        },
-        {
-          "name": "rewardAAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User's Reward A ATA"
-          ]
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rewardBAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "User's Reward B ATA"
-          ]
+          "name": "sender",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "amount",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "withdrawExtraToken",
-      "docs": [
-        "Withdraw token that mistakenly deposited to staking_vault"
-      ],
-      "accounts": [
+      "name": "EventSetCompoundKeeper",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Global accounts for the staking instance."
-          ]
-        },
+          "name": "keeper",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventSetClaimDelegate",
+      "fields": [
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Staking vault PDA"
-          ]
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "withdrawToAccount",
-          "isMut": true,
-          "isSigner": false,
-          "docs": [
-            "Token account to receive mistakenly deposited token"
-          ]
+          "name": "delegate",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true,
-          "docs": [
-            "Authority of the staking instance"
-          ]
+          "name": "rewardADestination",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false,
-          "docs": [
-            "Misc."
-          ]
+          "name": "rewardBDestination",
+          "type": "publicKey",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "closeUser",
-      "docs": [
-        "Closes a users stake account. Validation is done to ensure this is only allowed when the user has nothing staked and no rewards pending."
-      ],
-      "accounts": [
+      "name": "EventSetBoostConfig",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "name": "boostMint",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "user",
-          "isMut": true,
-          "isSigner": false
+          "name": "boostCollection",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "owner",
-          "isMut": true,
-          "isSigner": true
-        }
-      ],
-      "args": []
-    },
-    {
-      "name": "migrateFarmingRate",
-      "docs": [
-        "anyone can call this"
-      ],
-      "accounts": [
-        {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
+          "name": "boostMultiplierBps",
+          "type": "u16",
+          "index": false
         }
-      ],
-      "args": []
+      ]
     },
     {
-      "name": "closePool",
-      "docs": [
-        "Closes a pool account. Only able to be done when there are no users staked."
-      ],
-      "accounts": [
-        {
-          "name": "refundee",
-          "isMut": true,
-          "isSigner": false
-        },
+      "name": "EventWithdrawExtraReward",
+      "fields": [
         {
-          "name": "stakingRefundee",
-          "isMut": true,
-          "isSigner": false
+          "name": "amountA",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "rewardARefundee",
-          "isMut": true,
-          "isSigner": false
+          "name": "amountB",
+          "type": "u64",
+          "index": false
         },
         {
-          "name": "rewardBRefundee",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "amountNative",
+          "type": "u64",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCloseDormantUser",
+      "fields": [
         {
-          "name": "pool",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventEnableNativeReward",
+      "fields": [
         {
-          "name": "authority",
-          "isMut": false,
-          "isSigner": true
+          "name": "rewardA",
+          "type": "bool",
+          "index": false
         },
         {
-          "name": "stakingVault",
-          "isMut": true,
-          "isSigner": false
-        },
+          "name": "rewardB",
+          "type": "bool",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventRefreshBoost",
+      "fields": [
         {
-          "name": "rewardAVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
         },
         {
-          "name": "rewardBVault",
-          "isMut": true,
-          "isSigner": false
+          "name": "boostMultiplierBps",
+          "type": "u16",
+          "index": false
         },
         {
-          "name": "tokenProgram",
-          "isMut": false,
-          "isSigner": false
+          "name": "boostStake",
+          "type": "u64",
+          "index": false
         }
-      ],
-      "args": []
-    }
-  ],
-  "accounts": [
+      ]
+    },
     {
-      "name": "pool",
-      "docs": [
-        "Pool account wrapper"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "authority",
-            "docs": [
-              "Privileged account."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "paused",
-            "docs": [
-              "Paused state of the program"
-            ],
-            "type": "bool"
-          },
-          {
-            "name": "stakingMint",
-            "docs": [
-              "Mint of the token that can be staked."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "stakingVault",
-            "docs": [
-              "Vault to store staked tokens."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardAMint",
-            "docs": [
-              "Mint of the reward A token."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardAVault",
-            "docs": [
-              "Vault to store reward A tokens."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardBMint",
-            "docs": [
-              "Mint of the reward B token."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardBVault",
-            "docs": [
-              "Vault to store reward B tokens."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "baseKey",
-            "docs": [
-              "Base key"
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardDuration",
-            "docs": [
-              "The period which rewards are linearly distributed."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rewardDurationEnd",
-            "docs": [
-              "The timestamp at which the current reward period ends."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "lastUpdateTime",
-            "docs": [
-              "The last time reward states were updated."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rewardARate",
-            "docs": [
-              "deprecated field"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rewardBRate",
-            "docs": [
-              "deprecated field"
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rewardAPerTokenStored",
-            "docs": [
-              "Last calculated reward A per pool token."
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "rewardBPerTokenStored",
-            "docs": [
-              "Last calculated reward B per pool token."
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "userStakeCount",
-            "docs": [
-              "Users staked"
-            ],
-            "type": "u32"
-          },
-          {
-            "name": "funders",
-            "docs": [
-              "authorized funders",
-              "[] because short size, fixed account size, and ease of use on",
-              "client due to auto generated account size property"
-            ],
-            "type": {
-              "array": [
-                "publicKey",
-                3
-              ]
-            }
-          },
-          {
-            "name": "rewardARateU128",
-            "docs": [
-              "reward_a_rate in u128 form"
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "rewardBRateU128",
-            "docs": [
-              "reward_b_rate in u128 form"
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "poolBump",
-            "docs": [
-              "Pool bump"
-            ],
-            "type": "u8"
-          },
-          {
-            "name": "totalStaked",
-            "docs": [
-              "Total staked amount"
-            ],
-            "type": "u64"
-          }
-        ]
-      }
+      "name": "EventStripBoost",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventCreateStakeCheckpoint",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "authority",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "checkpointId",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "timestamp",
+          "type": "u64",
+          "index": false
+        }
+      ]
     },
     {
-      "name": "user",
-      "docs": [
-        "Farming user account"
-      ],
-      "type": {
-        "kind": "struct",
-        "fields": [
-          {
-            "name": "pool",
-            "docs": [
-              "Pool the this user belongs to."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "owner",
-            "docs": [
-              "The owner of this account."
-            ],
-            "type": "publicKey"
-          },
-          {
-            "name": "rewardAPerTokenComplete",
-            "docs": [
-              "The amount of token A claimed."
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "rewardBPerTokenComplete",
-            "docs": [
-              "The amount of token B claimed."
-            ],
-            "type": "u128"
-          },
-          {
-            "name": "rewardAPerTokenPending",
-            "docs": [
-              "The amount of token A pending claim."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "rewardBPerTokenPending",
-            "docs": [
-              "The amount of token B pending claim."
-            ],
-            "type": "u64"
-          },
-          {
-            "name": "balanceStaked",
-            "docs": [
-              "The amount staked."
-            ],
-            "type": "u64"
+      "name": "EventSnapshotStake",
+      "fields": [
+        {
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "snapshot",
+          "type": {
+            "defined": "StakeSnapshot"
           },
-          {
-            "name": "nonce",
-            "docs": [
-              "Signer nonce."
-            ],
-            "type": "u8"
-          }
-        ]
-      }
-    }
-  ],
-  "events": [
+          "index": false
+        }
+      ]
+    },
     {
-      "name": "EventDeposit",
+      "name": "EventCreateRegistrar",
       "fields": [
         {
-          "name": "amount",
+          "name": "realm",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "governingTokenMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voteLockDuration",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "EventWithdraw",
+      "name": "EventUpdateVoterWeight",
       "fields": [
         {
-          "name": "amount",
+          "name": "owner",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "voterWeight",
+          "type": "u64",
+          "index": false
+        },
+        {
+          "name": "voteLockedUntil",
           "type": "u64",
           "index": false
         }
       ]
     },
     {
-      "name": "EventFund",
+      "name": "EventProtocolFee",
       "fields": [
         {
           "name": "amountA",
@@ -2041,16 +10920,36 @@ export const IDL: Farming = {
       ]
     },
     {
-      "name": "EventClaim",
+      "name": "EventUpdateGlobalConfig",
       "fields": [
         {
-          "name": "amountA",
-          "type": "u64",
+          "name": "admin",
+          "type": "publicKey",
           "index": false
         },
         {
-          "name": "amountB",
-          "type": "u64",
+          "name": "protocolFeeBps",
+          "type": "u16",
+          "index": false
+        },
+        {
+          "name": "treasury",
+          "type": "publicKey",
+          "index": false
+        }
+      ]
+    },
+    {
+      "name": "EventPrunePoolRegistry",
+      "fields": [
+        {
+          "name": "stakingMint",
+          "type": "publicKey",
+          "index": false
+        },
+        {
+          "name": "pool",
+          "type": "publicKey",
           "index": false
         }
       ]
@@ -2126,6 +11025,291 @@ export const IDL: Farming = {
       "code": 6009,
       "name": "MathOverflow",
       "msg": "Math operation overflow"
+    },
+    {
+      "code": 6010,
+      "name": "NoCompoundableReward",
+      "msg": "Pool has no reward with the same mint as the staking mint."
+    },
+    {
+      "code": 6011,
+      "name": "InvalidCompoundAuthority",
+      "msg": "Signer is neither the user owner nor its compound keeper."
+    },
+    {
+      "code": 6012,
+      "name": "UnbondingRequired",
+      "msg": "Pool has an unbonding period, withdraw must be requested first."
+    },
+    {
+      "code": 6013,
+      "name": "UnbondingNotFinished",
+      "msg": "Unbonding period is not finished."
+    },
+    {
+      "code": 6014,
+      "name": "CampaignNotActive",
+      "msg": "Campaign is not running."
+    },
+    {
+      "code": 6015,
+      "name": "CampaignActive",
+      "msg": "Campaign is still running."
+    },
+    {
+      "code": 6016,
+      "name": "InvalidProtocolFee",
+      "msg": "Protocol fee exceeds the maximum."
+    },
+    {
+      "code": 6017,
+      "name": "InvalidProtocolAdmin",
+      "msg": "Signer is not the protocol admin."
+    },
+    {
+      "code": 6018,
+      "name": "MissingTreasuryAccount",
+      "msg": "Treasury account is required to pay the protocol fee."
+    },
+    {
+      "code": 6019,
+      "name": "InvalidTreasuryAccount",
+      "msg": "Treasury account does not belong to the treasury or has the wrong mint."
+    },
+    {
+      "code": 6020,
+      "name": "InvalidPoolAccount",
+      "msg": "Account is not a pool."
+    },
+    {
+      "code": 6021,
+      "name": "MissingPoolRegistry",
+      "msg": "Pool registry is required to close a registered pool."
+    },
+    {
+      "code": 6022,
+      "name": "InvalidPositionRecipient",
+      "msg": "Position cannot be transferred to its current owner."
+    },
+    {
+      "code": 6023,
+      "name": "FundingTooSmall",
+      "msg": "Funding is too small to emit any reward over the reward period."
+    },
+    {
+      "code": 6024,
+      "name": "InvalidUserAccount",
+      "msg": "Account is not a user account."
+    },
+    {
+      "code": 6025,
+      "name": "InvalidBoostConfig",
+      "msg": "Boost multiplier must be above 1x and at most the maximum, with a mint or a collection."
+    },
+    {
+      "code": 6026,
+      "name": "BoostNotConfigured",
+      "msg": "Pool has no boost configured."
+    },
+    {
+      "code": 6027,
+      "name": "InvalidBoostToken",
+      "msg": "Token account does not hold the boost mint or a token of the boost collection."
+    },
+    {
+      "code": 6028,
+      "name": "BoostNotActive",
+      "msg": "User has no boost."
+    },
+    {
+      "code": 6029,
+      "name": "BoostStillHeld",
+      "msg": "Boost token is still held."
+    },
+    {
+      "code": 6030,
+      "name": "InvalidStakeWindow",
+      "msg": "Stake checkpoint is not an earlier checkpoint of this user."
+    },
+    {
+      "code": 6031,
+      "name": "InvalidRealm",
+      "msg": "Account is not a realm of the governance program or does not use the governing token mint."
+    },
+    {
+      "code": 6032,
+      "name": "InvalidRealmAuthority",
+      "msg": "Signer is not the realm authority."
+    },
+    {
+      "code": 6033,
+      "name": "InvalidVoterUser",
+      "msg": "User account does not belong to the registrar pool and the voter."
+    },
+    {
+      "code": 6034,
+      "name": "InvalidNativeReward",
+      "msg": "Native reward requires the native mint and a pool not funded yet."
+    },
+    {
+      "code": 6035,
+      "name": "MissingNativeRewardAccounts",
+      "msg": "Native reward vault and system program are required for a native reward."
+    },
+    {
+      "code": 6036,
+      "name": "NativeRewardAccountNotWritable",
+      "msg": "Account sending or receiving a native reward must be writable."
+    },
+    {
+      "code": 6037,
+      "name": "MissingRewardTokenAccount",
+      "msg": "Token account is required for a token reward."
+    },
+    {
+      "code": 6038,
+      "name": "RewardObligationsUntracked",
+      "msg": "Pool was created before reward obligations were tracked."
+    },
+    {
+      "code": 6039,
+      "name": "NoRewardSurplus",
+      "msg": "Reward vaults hold nothing above the reward obligations."
+    },
+    {
+      "code": 6040,
+      "name": "MissingRewardVault",
+      "msg": "Reward B vault is required for a dual reward pool."
+    },
+    {
+      "code": 6041,
+      "name": "InvalidClaimAuthority",
+      "msg": "Signer is neither the user owner nor its claim delegate."
+    },
+    {
+      "code": 6042,
+      "name": "InvalidRewardDestination",
+      "msg": "Reward destination is not the one registered by the user."
+    },
+    {
+      "code": 6043,
+      "name": "InvalidClaimManyAccounts",
+      "msg": "Claim many accounts are empty or hold too many pools."
+    },
+    {
+      "code": 6044,
+      "name": "PoolAttachedToHub",
+      "msg": "Pool stake is held by a stake hub."
+    },
+    {
+      "code": 6045,
+      "name": "PoolNotAttachedToHub",
+      "msg": "Pool is not attached to the stake hub."
+    },
+    {
+      "code": 6046,
+      "name": "PoolHasStake",
+      "msg": "Pool still holds stake."
+    },
+    {
+      "code": 6047,
+      "name": "StakeHubFull",
+      "msg": "Stake hub has no free pool slot."
+    },
+    {
+      "code": 6048,
+      "name": "InvalidStakeHubMint",
+      "msg": "Pool staking mint differs from the stake hub staking mint."
+    },
+    {
+      "code": 6049,
+      "name": "HubPoolAlreadyJoined",
+      "msg": "User already earns from the hub pool."
+    },
+    {
+      "code": 6050,
+      "name": "HubPoolNotJoined",
+      "msg": "User does not earn from the hub pool."
+    },
+    {
+      "code": 6051,
+      "name": "InvalidHubPoolAccounts",
+      "msg": "Hub pool accounts do not match the pools joined by the user."
+    },
+    {
+      "code": 6052,
+      "name": "MinStakeDurationNotReached",
+      "msg": "Stake has not been in the pool for the minimum stake duration."
+    },
+    {
+      "code": 6053,
+      "name": "InvalidReferralFee",
+      "msg": "Referral fee is above the maximum."
+    },
+    {
+      "code": 6054,
+      "name": "ReferrerAlreadySet",
+      "msg": "Referrer is already set."
+    },
+    {
+      "code": 6055,
+      "name": "InvalidReferrer",
+      "msg": "User cannot refer itself."
+    },
+    {
+      "code": 6056,
+      "name": "MissingReferrerAccount",
+      "msg": "Referrer account is required to claim."
+    },
+    {
+      "code": 6057,
+      "name": "InvalidEmissionSchedule",
+      "msg": "Emission schedule is invalid."
+    },
+    {
+      "code": 6058,
+      "name": "EmissionScheduleRequiresReset",
+      "msg": "Pools with an emission schedule can only be funded over a new reward period."
+    },
+    {
+      "code": 6059,
+      "name": "PoolRegistryFull",
+      "msg": "Pool registry is full."
+    },
+    {
+      "code": 6060,
+      "name": "PoolAlreadyRegistered",
+      "msg": "Pool is already registered."
+    },
+    {
+      "code": 6061,
+      "name": "PoolNotInRegistry",
+      "msg": "Pool is not in the registry."
+    },
+    {
+      "code": 6062,
+      "name": "StakeVoteLocked",
+      "msg": "Stake is locked until the vote it was used for ends."
+    },
+    {
+      "code": 6063,
+      "name": "MissingBoostMetadata",
+      "msg": "Metadata of the boost token mint is required to check a collection boost."
+    },
+    {
+      "code": 6064,
+      "name": "UnbondingPeriodTooLong",
+      "msg": "Unbonding period is longer than the maximum."
+    },
+    {
+      "code": 6065,
+      "name": "MinStakeDurationTooLong",
+      "msg": "Minimum stake duration is longer than the maximum."
+    },
+    {
+      "code": 6066,
+      "name": "UnexpectedRewardVault",
+      "msg": "Reward B vault must not be passed for a single reward pool."
     }
   ]
 };